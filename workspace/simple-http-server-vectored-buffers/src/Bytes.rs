@@ -2,11 +2,16 @@
 // Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
 
 
-struct Bytes
+/// Bytes received for a request parser.
+///
+/// Not intended for use outside of the request parser in `simple-http-server`.
+#[doc(hidden)]
+pub struct Bytes
 {
 	vectored_buffer: VectoredBuffer,
 	current_position: VectoredBufferOffset,
 	previous_position: VectoredBufferOffset,
+	ends_at: VectoredBufferOffset,
 
 	current_pointer: NonNull<u8>,
 	current_slice_end_pointer: NonNull<u8>,
@@ -20,14 +25,16 @@ impl Bytes
 
 	pub fn recycle_oldest_buffer(&mut self) -> Option<BufferIndex>;
 
+	/// Creates a new instance.
 	#[inline(always)]
-	pub(crate) fn new(vectored_buffer: VectoredBuffer) -> Self
+	pub fn new(vectored_buffer: VectoredBuffer) -> Self
 	{
 		let mut this = Self
 		{
 			vectored_buffer,
 			current_position: VectoredBufferOffset::new(BufferIndex::Minimum, 0),
 			previous_position: VectoredBufferOffset::new(BufferIndex::Minimum, 0),
+			ends_at: VectoredBufferOffset::new(BufferIndex::Minimum, 0),
 
 			current_pointer: unsafe { uninitialized() },
 			current_slice_end_pointer: unsafe { uninitialized() },
@@ -37,8 +44,100 @@ impl Bytes
 		this
 	}
 
+	/// Reads more bytes into the `VectoredBuffer` after those already received.
+	///
+	/// Returns the number of bytes read; this will be zero if the `VectoredBuffer` is full.
 	#[inline(always)]
-	pub(crate) fn reset(&mut self, reset_to_position: VectoredBufferOffset)
+	pub fn read_into(&mut self, read: &mut impl Read) -> io::Result<usize>
+	{
+		let (bytes_read, next_offset) = self.vectored_buffer.read_into(read, self.ends_at)?;
+
+		self.ends_at = match next_offset
+		{
+			Some(next_offset) => next_offset,
+
+			None => self.final_position(),
+		};
+
+		self.set();
+		Ok(bytes_read)
+	}
+
+	/// Are there received bytes which have not yet been parsed?
+	#[inline(always)]
+	pub fn has_unparsed_bytes(&self) -> bool
+	{
+		self.current_position != self.ends_at
+	}
+
+	/// Discards all received bytes so that the space in the `VectoredBuffer` can be re-used.
+	///
+	/// Only valid when all received bytes have been parsed.
+	#[inline(always)]
+	pub fn empty(&mut self)
+	{
+		debug_assert!(!self.has_unparsed_bytes(), "Not all received bytes have been parsed");
+
+		let starts_at = VectoredBufferOffset::new(BufferIndex::Minimum, 0);
+		self.ends_at = starts_at;
+		self.reset(starts_at)
+	}
+
+	/// Is the `VectoredBuffer` full, ie will `read_into()` only ever read zero bytes?
+	#[inline(always)]
+	pub fn is_full(&self) -> bool
+	{
+		self.ends_at == self.final_position()
+	}
+
+	/// Moves the received bytes which have not yet been parsed to the start of the `VectoredBuffer`, so that the space taken by those already parsed can be re-used.
+	///
+	/// Only valid when no positions before the current position are still needed (eg between pipelined requests); the current position becomes the start of the `VectoredBuffer`.
+	#[inline(always)]
+	pub fn compact(&mut self)
+	{
+		let current_position = self.current_position;
+		self.release(VectoredBufferOffset::new(BufferIndex::Minimum, 0), current_position)
+	}
+
+	/// Moves the received bytes from `released_ends_at_exclusive` onwards back to `released_starts_at_inclusive`, so that the space taken by the bytes between them can be re-used (eg once request body bytes have been used).
+	///
+	/// Only valid when no positions from `released_starts_at_inclusive` onwards are still needed and `released_ends_at_exclusive` is not after the current position; the current position becomes `released_starts_at_inclusive`.
+	pub fn release(&mut self, released_starts_at_inclusive: VectoredBufferOffset, released_ends_at_exclusive: VectoredBufferOffset)
+	{
+		debug_assert!(released_starts_at_inclusive <= released_ends_at_exclusive, "released_starts_at_inclusive is after released_ends_at_exclusive");
+		debug_assert!(released_ends_at_exclusive <= self.current_position, "released_ends_at_exclusive is after the current position");
+
+		let mut from = released_ends_at_exclusive;
+		let mut to = released_starts_at_inclusive;
+		if unlikely!(from == to)
+		{
+			return self.reset(to)
+		}
+
+		while from != self.ends_at
+		{
+			let byte = self.vectored_buffer.get_immutable_buffer(from.buffer_index)[from.offset];
+			self.vectored_buffer.get_mutable_buffer(to.buffer_index)[to.offset] = byte;
+
+			from = self.next_position(from);
+			to = self.next_position(to);
+		}
+
+		self.ends_at = to;
+		self.reset(released_starts_at_inclusive)
+	}
+
+	/// The underlying `VectoredBuffer`, eg to examine previously parsed ranges.
+	#[inline(always)]
+	pub fn vectored_buffer(&self) -> &VectoredBuffer
+	{
+		&self.vectored_buffer
+	}
+
+	/// Resets the current position to `reset_to_position`, eg when re-entering a parser.
+	#[inline(always)]
+	pub fn reset(&mut self, reset_to_position: VectoredBufferOffset)
 	{
 		self.current_position = reset_to_position;
 
@@ -70,20 +169,45 @@ impl Bytes
 		self.current_slice_end_pointer = unsafe { current_pointer.add(current_slice.len()) };
 	}
 
+	/// The position of the byte most recently consumed.
 	#[inline(always)]
-	pub(crate) fn previous_position(&self) -> VectoredBufferOffset
+	pub fn previous_position(&self) -> VectoredBufferOffset
 	{
 		self.previous_position
 	}
 
+	/// The position of the next byte to be consumed.
 	#[inline(always)]
-	pub(crate) fn current_position(&self) -> VectoredBufferOffset
+	pub fn current_position(&self) -> VectoredBufferOffset
 	{
 		self.current_position
 	}
 
+	/// The number of bytes between `starts_at_inclusive` and the current position, even if they span several buffers.
 	#[inline(always)]
-	pub(crate) fn is_slice<ReentryPoint, InvalidReason, R>(&mut self, compare_to: &[u8], reentry_point: ReentryPoint, invalid_reason: InvalidReason, result: R) -> Result<R, Status<ReentryPoint, InvalidReason>>
+	pub fn number_of_bytes_parsed_since(&self, starts_at_inclusive: VectoredBufferOffset) -> usize
+	{
+		let current_position = self.current_position;
+
+		if likely!(starts_at_inclusive.buffer_index == current_position.buffer_index)
+		{
+			return current_position.offset - starts_at_inclusive.offset
+		}
+
+		let mut buffer_index = starts_at_inclusive.buffer_index;
+		let mut number_of_bytes = self.vectored_buffer.get_immutable_buffer(buffer_index).len() - starts_at_inclusive.offset;
+		buffer_index.increment_unchecked();
+		while buffer_index != current_position.buffer_index
+		{
+			number_of_bytes += self.vectored_buffer.get_immutable_buffer(buffer_index).len();
+			buffer_index.increment_unchecked();
+		}
+		number_of_bytes + current_position.offset
+	}
+
+	/// Consumes bytes if they match `compare_to`, returning `result`; otherwise returns `invalid_reason`.
+	#[inline(always)]
+	pub fn is_slice<ReentryPoint, InvalidReason, R>(&mut self, compare_to: &[u8], reentry_point: ReentryPoint, invalid_reason: InvalidReason, result: R) -> Result<R, Status<ReentryPoint, InvalidReason>>
 	{
 		trait NonNullExt<T>
 		{
@@ -166,8 +290,9 @@ impl Bytes
 		}
 	}
 
+	/// Consumes and returns the next byte, if any.
 	#[inline(always)]
-	pub(crate) fn if_has_more_return_current_value_and_increment<ReentryPoint, InvalidReason>(&mut self, reentry_point: ReentryPoint) -> Result<u8, Status<ReentryPoint, InvalidReason>>
+	pub fn if_has_more_return_current_value_and_increment<ReentryPoint, InvalidReason>(&mut self, reentry_point: ReentryPoint) -> Result<u8, Status<ReentryPoint, InvalidReason>>
 	{
 		if self.current_pointer == self.current_slice_end_pointer
 		{
@@ -184,7 +309,7 @@ impl Bytes
 		let would_be_end_pointer = next_pointer == self.current_slice_end_pointer;
 		if unlikely!(would_be_end_pointer)
 		{
-			let is_final_buffer_index = current_position.buffer_index.0 == self.vectored_buffer.number_of_buffers() - 1;
+			let is_final_buffer_index = current_position.buffer_index == self.ends_at.buffer_index;
 			if unlikely!(is_final_buffer_index)
 			{
 				self.current_position = current_position.increment_offset();
@@ -205,15 +330,137 @@ impl Bytes
 		Ok(current_value)
 	}
 
+	/// Consumes up to `maximum` bytes without copying them, returning the range consumed as `(starts_at_inclusive, ends_at_exclusive)`.
+	///
+	/// The range never crosses a buffer boundary; call repeatedly to consume bytes from subsequent buffers.
+	#[inline(always)]
+	pub fn if_has_more_consume_up_to<ReentryPoint, InvalidReason>(&mut self, maximum: usize, reentry_point: ReentryPoint) -> Result<(VectoredBufferOffset, VectoredBufferOffset), Status<ReentryPoint, InvalidReason>>
+	{
+		debug_assert_ne!(maximum, 0, "maximum must not be zero");
+
+		let available = self.available_in_current_slice();
+		if available == 0
+		{
+			return Err(Status::RanOutOfBytes(reentry_point))
+		}
+
+		let length = min(available, maximum);
+
+		let starts_at_inclusive = self.current_position;
+		let ends_at_exclusive = self.advance_within_current_slice(length, available);
+
+		Ok((starts_at_inclusive, ends_at_exclusive))
+	}
+
+	/// Skips bytes in the current slice (only) that `scanner` accepts, eg using SIMD instructions.
+	///
+	/// `scanner` is given the received bytes remaining in the current slice and returns how many of them, from the start, to skip.
+	///
+	/// Returns the number of bytes skipped; call repeatedly (interleaved with examining the byte at which `scanner` stopped) to skip bytes in subsequent buffers.
+	#[inline(always)]
+	pub fn skip(&mut self, scanner: impl FnOnce(&[u8]) -> usize) -> usize
+	{
+		let available = self.available_in_current_slice();
+		if unlikely!(available == 0)
+		{
+			return 0
+		}
+
+		let length = scanner(unsafe { from_raw_parts(self.current_pointer.as_ptr(), available) });
+		debug_assert!(length <= available, "scanner skipped more bytes than are available");
+
+		if length != 0
+		{
+			self.advance_within_current_slice(length, available);
+		}
+		length
+	}
+
+	#[inline(always)]
+	fn available_in_current_slice(&self) -> usize
+	{
+		(self.current_slice_end_pointer.as_ptr() as usize) - (self.current_pointer.as_ptr() as usize)
+	}
+
+	/// `length` must not be zero or exceed `available`.
+	///
+	/// Returns the position after the bytes advanced over (which is not necessarily the current position, as that moves to the next buffer if the current slice is exhausted).
+	#[inline(always)]
+	fn advance_within_current_slice(&mut self, length: usize, available: usize) -> VectoredBufferOffset
+	{
+		let starts_at_inclusive = self.current_position;
+		let ends_at_exclusive = starts_at_inclusive.increment_offset_by(length);
+		self.previous_position = starts_at_inclusive.increment_offset_by(length - 1);
+
+		let would_be_end_pointer = length == available;
+		if unlikely!(would_be_end_pointer)
+		{
+			let is_final_buffer_index = starts_at_inclusive.buffer_index == self.ends_at.buffer_index;
+			if unlikely!(is_final_buffer_index)
+			{
+				self.current_position = ends_at_exclusive;
+				self.current_pointer = self.current_slice_end_pointer;
+			}
+			else
+			{
+				self.current_position = starts_at_inclusive.next();
+				self.set()
+			}
+		}
+		else
+		{
+			self.current_position = ends_at_exclusive;
+			self.current_pointer = unsafe { self.current_pointer.add(length) };
+		}
+
+		ends_at_exclusive
+	}
+
+	/// The position after the last byte of the final buffer.
+	#[inline(always)]
+	fn final_position(&self) -> VectoredBufferOffset
+	{
+		let final_buffer_index = BufferIndex(self.vectored_buffer.number_of_buffers() - 1);
+		VectoredBufferOffset::new(final_buffer_index, self.vectored_buffer.get_immutable_buffer(final_buffer_index).len())
+	}
+
+	/// The position after `position`, moving to the next buffer if `position` is the last byte of a buffer other than the final one.
+	#[inline(always)]
+	fn next_position(&self, position: VectoredBufferOffset) -> VectoredBufferOffset
+	{
+		let next_position = position.increment_offset();
+
+		let is_end_of_buffer = next_position.offset == self.vectored_buffer.get_immutable_buffer(position.buffer_index).len();
+		if unlikely!(is_end_of_buffer && next_position != self.final_position())
+		{
+			position.next()
+		}
+		else
+		{
+			next_position
+		}
+	}
+
 	#[inline(always)]
 	fn current_value(&self) -> u8
 	{
 		unsafe { *self.current_pointer.as_ptr() }
 	}
 
+	/// Only includes bytes which have been received.
 	#[inline(always)]
 	fn get_current_slice<'b>(&'b self) -> Ref<'b, [u8]>
 	{
-		self.vectored_buffer.get_immutable_buffer_with_offset(self.current_position.buffer_index, self.current_position.offset)
+		let current_slice = self.vectored_buffer.get_immutable_buffer_with_offset(self.current_position.buffer_index, self.current_position.offset);
+
+		if self.current_position.buffer_index == self.ends_at.buffer_index
+		{
+			let length = self.ends_at.offset - self.current_position.offset;
+			Ref::map(current_slice, |current_slice| &current_slice[ .. length])
+		}
+		else
+		{
+			current_slice
+		}
 	}
 }
@@ -13,6 +13,92 @@ pub struct ImmutableVectoredBufferSlice<'vectored_buffer>
 
 impl<'vectored_buffer> ImmutableVectoredBufferSlice<'vectored_buffer>
 {
+	/// Creates a new instance for the bytes from `starts_at_inclusive` up to `ends_at_exclusive`.
+	#[inline(always)]
+	pub fn new(vectored_buffer: &'vectored_buffer VectoredBuffer, starts_at_inclusive: VectoredBufferOffset, ends_at_exclusive: VectoredBufferOffset) -> Self
+	{
+		Self
+		{
+			vectored_buffer,
+			is_at: (starts_at_inclusive.buffer_index, starts_at_inclusive.offset),
+			ends_at: (ends_at_exclusive.buffer_index, ends_at_exclusive.offset),
+		}
+	}
+
+	/// An iterator over the Rust immutable slices that make up this slice.
+	#[inline(always)]
+	pub fn iter(&'vectored_buffer self) -> ImmutableVectoredBufferSliceIterator<'vectored_buffer>
+	{
+		ImmutableVectoredBufferSliceIterator
+		{
+			parent: self,
+			is_at: Some(self.is_at),
+		}
+	}
+
+	/// An iterator over the bytes in this slice.
+	#[inline(always)]
+	pub fn bytes(&'vectored_buffer self) -> impl Iterator<Item=u8> + 'vectored_buffer
+	{
+		self.iter().flat_map(|slice| slice.iter().cloned())
+	}
+
+	/// An iterator over the bytes in this slice, each with its offset in the `VectoredBuffer`.
+	#[inline(always)]
+	pub fn offsets_and_bytes(&'vectored_buffer self) -> impl Iterator<Item=(VectoredBufferOffset, u8)> + 'vectored_buffer
+	{
+		let (is_at_buffer_index, is_at_inclusive_from_offset) = self.is_at;
+
+		self.iter().enumerate().flat_map(move |(index, slice)|
+		{
+			let buffer_index = BufferIndex(is_at_buffer_index.0 + index);
+			let starts_at_offset = if index == 0
+			{
+				is_at_inclusive_from_offset
+			}
+			else
+			{
+				0
+			};
+
+			slice.iter().enumerate().map(move |(offset, &byte)| (VectoredBufferOffset::new(buffer_index, starts_at_offset + offset), byte))
+		})
+	}
+
+	/// Offset of the start of this slice (inclusive) in the `VectoredBuffer`.
+	#[inline(always)]
+	pub fn starts_at_inclusive(&self) -> VectoredBufferOffset
+	{
+		VectoredBufferOffset::new(self.is_at.0, self.is_at.1)
+	}
+
+	/// Offset of the end of this slice (exclusive) in the `VectoredBuffer`.
+	#[inline(always)]
+	pub fn ends_at_exclusive(&self) -> VectoredBufferOffset
+	{
+		VectoredBufferOffset::new(self.ends_at.0, self.ends_at.1)
+	}
+
+	/// Is this slice equal to `other`, ignoring ASCII case?
+	#[inline(always)]
+	pub fn eq_ignore_ascii_case(&'vectored_buffer self, other: &[u8]) -> bool
+	{
+		let mut other = other.iter();
+		for byte in self.bytes()
+		{
+			match other.next()
+			{
+				None => return false,
+
+				Some(other_byte) => if !byte.eq_ignore_ascii_case(other_byte)
+				{
+					return false
+				}
+			}
+		}
+		other.next().is_none()
+	}
+
 	#[inline(always)]
 	fn next_like(&'vectored_buffer self, (is_at_buffer_index, is_at_inclusive_from_offset): (BufferIndex, InclusiveFromOffset)) -> (&'vectored_buffer [u8], Option<(BufferIndex, InclusiveFromOffset)>)
 	{
@@ -6,7 +6,9 @@
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Status<ReentryPoint, InvalidReason>
 {
+	/// More bytes are needed; parsing should resume from the reentry point once they have been read.
 	RanOutOfBytes(ReentryPoint),
 
+	/// The bytes are invalid.
 	Invalid(InvalidReason),
 }
@@ -24,6 +24,7 @@ use ::std::alloc::System;
 use ::std::borrow::Borrow;
 use ::std::borrow::BorrowMut;
 use ::std::cell::*;
+use ::std::cmp::min;
 use ::std::convert::AsMut;
 use ::std::convert::AsRef;
 use ::std::io;
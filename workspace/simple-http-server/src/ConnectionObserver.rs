@@ -13,13 +13,15 @@ pub(crate) struct ConnectionObserver<SCCUF: ServedClientConnectionUserFactory>
 
 	internet_protocol_version_6_access_control_list: IpLookupTable<Ipv6Addr, ()>,
 
+	request_parser_limits: RequestParserLimits,
+
 	served_client_connection_user_factory: SCCUF,
 }
 
 impl<SCCUF: ServedClientConnectionUserFactory> ConnectionObserver<SCCUF>
 {
 	#[inline(always)]
-	pub(crate) fn new(maximum_connections: usize, permitted_internet_protocol_version_4_addresses: &HashSet<PermittedInternetProtocolVersionAddresses<Ipv4Addr>>, permitted_internet_protocol_version_6_addresses: &HashSet<PermittedInternetProtocolVersionAddresses<Ipv6Addr>>, served_client_connection_user_factory: SCCUF) -> Self
+	pub(crate) fn new(maximum_connections: usize, permitted_internet_protocol_version_4_addresses: &HashSet<PermittedInternetProtocolVersionAddresses<Ipv4Addr>>, permitted_internet_protocol_version_6_addresses: &HashSet<PermittedInternetProtocolVersionAddresses<Ipv6Addr>>, request_parser_limits: RequestParserLimits, served_client_connection_user_factory: SCCUF) -> Self
 	{
 		// NOTE: At this point in time `A` in `IpLookupTable` is a private type so we can't de-deduplicate this logic.
 		let mut internet_protocol_version_4_access_control_list = IpLookupTable::with_capacity();
@@ -39,6 +41,7 @@ impl<SCCUF: ServedClientConnectionUserFactory> ConnectionObserver<SCCUF>
 			current_connections: AtomicUsize::new(0),
 			internet_protocol_version_4_access_control_list,
 			internet_protocol_version_6_access_control_list,
+			request_parser_limits,
 			served_client_connection_user_factory,
 		}
 	}
@@ -74,7 +77,7 @@ impl<SCCUF: ServedClientConnectionUserFactory> ConnectionObserver<SCCUF>
 			}
 		}
 
-		self.served_client_connection_user_factory.connect(remote_address).map_err(|error|
+		self.served_client_connection_user_factory.connect(remote_address, self.request_parser_limits).map_err(|error|
 		{
 			disconnect(remote_address);
 
@@ -75,6 +75,8 @@ impl<'a, SCCUF: ServedClientConnectionsUserFactory> SimpleHttpsServer<'a, SCCUF>
 				}
 			}
 
+			DateHeaderResponseBuffer::refresh_cached();
+
 			for event in events.iter()
 			{
 				TokenKind::handle_event(event.token(), event.ready(), &mut drop_token_when_all_events_handled)
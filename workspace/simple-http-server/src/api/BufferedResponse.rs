@@ -0,0 +1,172 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A response assembled by a `ResponseBuilder`: an ordered list of response buffers, the status line first and the body last.
+///
+/// Remembers how far it has been written, so that writing can resume where it stopped, eg after a partial write.
+pub struct BufferedResponse
+{
+	response_buffers: Vec<Box<HeaderResponseBuffer>>,
+	end_of_headers_index: Option<usize>,
+
+	response_buffer_index: usize,
+	buffer_index: usize,
+	from_offset: usize,
+}
+
+impl Response for BufferedResponse
+{
+	#[inline(always)]
+	fn provide_data(&mut self) -> Option<Result<(&[u8], ResponseDataBufferIdentifier), ()>>
+	{
+		let response_buffer_index = self.response_buffer_index;
+		let buffer_index = self.buffer_index;
+		let from_offset = self.from_offset;
+		self.response_buffers.get(response_buffer_index).map(|response_buffer| Ok((response_buffer.report_buffer(buffer_index, from_offset), response_buffer_index)))
+	}
+
+	#[inline(always)]
+	fn data_written(&mut self, bytes_written: usize, data_buffer_identifier: ResponseDataBufferIdentifier)
+	{
+		debug_assert_eq!(data_buffer_identifier, self.response_buffer_index, "data_buffer_identifier `{}` was not the one last provided", data_buffer_identifier);
+
+		let bytes_written_beyond_last_response_buffer = self.advance(bytes_written);
+		debug_assert_eq!(bytes_written_beyond_last_response_buffer, 0, "more bytes written than were provided");
+	}
+
+	#[inline(always)]
+	fn provide_vectored_data<'a>(&'a mut self, data_buffers: &mut ResponseDataBuffers<'a>) -> Result<(), ()>
+	{
+		let mut response_buffer_index = self.response_buffer_index;
+		let mut buffer_index = self.buffer_index;
+		let mut from_offset = self.from_offset;
+
+		while let Some(response_buffer) = self.response_buffers.get(response_buffer_index)
+		{
+			if data_buffers.is_full()
+			{
+				break
+			}
+
+			if response_buffer.buffer_length(buffer_index) != 0
+			{
+				data_buffers.push(response_buffer.report_buffer(buffer_index, from_offset));
+			}
+
+			from_offset = 0;
+			buffer_index += 1;
+			if buffer_index == response_buffer.number_of_buffers()
+			{
+				buffer_index = 0;
+				response_buffer_index += 1;
+			}
+		}
+
+		Ok(())
+	}
+
+	#[inline(always)]
+	fn vectored_data_written(&mut self, bytes_written: usize)
+	{
+		let bytes_written_beyond_last_response_buffer = self.advance(bytes_written);
+		debug_assert_eq!(bytes_written_beyond_last_response_buffer, 0, "more bytes written than were provided");
+	}
+
+	#[inline(always)]
+	fn insert_header_response_buffer(&mut self, header_response_buffer: Box<HeaderResponseBuffer>)
+	{
+		debug_assert!(self.response_buffer_index == 0 && self.buffer_index == 0 && self.from_offset == 0, "data has already been provided");
+
+		if let Some(end_of_headers_index) = self.end_of_headers_index
+		{
+			self.response_buffers.insert(end_of_headers_index, header_response_buffer);
+			self.end_of_headers_index = Some(end_of_headers_index + 1);
+		}
+	}
+}
+
+impl BufferedResponse
+{
+	/// `end_of_headers_index` is that of the `EndOfHeadersHeaderResponseBuffer`, if headers can be inserted before it.
+	#[inline(always)]
+	pub(crate) fn new(response_buffers: Vec<Box<HeaderResponseBuffer>>, end_of_headers_index: Option<usize>) -> Self
+	{
+		let mut this = Self
+		{
+			response_buffers,
+			end_of_headers_index,
+
+			response_buffer_index: 0,
+			buffer_index: 0,
+			from_offset: 0,
+		};
+		this.skip_empty_buffers();
+		this
+	}
+
+	/// Have all the response buffers been written?
+	#[inline(always)]
+	pub(crate) fn has_been_written(&self) -> bool
+	{
+		self.response_buffer_index == self.response_buffers.len()
+	}
+
+	/// Moves the position written to forward by `bytes_written`, which may span several buffers.
+	///
+	/// Returns the number of bytes written beyond the last response buffer, which will be zero unless this response was written out as part of a larger one.
+	pub(crate) fn advance(&mut self, mut bytes_written: usize) -> usize
+	{
+		while bytes_written != 0
+		{
+			let (remaining, number_of_buffers) = match self.response_buffers.get(self.response_buffer_index)
+			{
+				None => break,
+
+				Some(response_buffer) => (response_buffer.report_buffer(self.buffer_index, self.from_offset).len(), response_buffer.number_of_buffers()),
+			};
+
+			if bytes_written < remaining
+			{
+				self.from_offset += bytes_written;
+				return 0
+			}
+
+			bytes_written -= remaining;
+
+			self.next_buffer(number_of_buffers);
+			self.skip_empty_buffers();
+		}
+
+		bytes_written
+	}
+
+	// An empty buffer, eg an empty entity tag, can not be reported from its first byte and so is never the current position.
+	#[inline(always)]
+	fn skip_empty_buffers(&mut self)
+	{
+		debug_assert_eq!(self.from_offset, 0, "from_offset is not at the start of a buffer");
+
+		while let Some((buffer_length, number_of_buffers)) = self.response_buffers.get(self.response_buffer_index).map(|response_buffer| (response_buffer.buffer_length(self.buffer_index), response_buffer.number_of_buffers()))
+		{
+			if buffer_length != 0
+			{
+				break
+			}
+
+			self.next_buffer(number_of_buffers);
+		}
+	}
+
+	#[inline(always)]
+	fn next_buffer(&mut self, number_of_buffers: usize)
+	{
+		self.from_offset = 0;
+		self.buffer_index += 1;
+		if self.buffer_index == number_of_buffers
+		{
+			self.buffer_index = 0;
+			self.response_buffer_index += 1;
+		}
+	}
+}
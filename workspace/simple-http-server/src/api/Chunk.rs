@@ -0,0 +1,15 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A chunk produced by a `ChunkProducer`.
+pub enum Chunk<D: AsRef<[u8]>>
+{
+	/// Chunk data; empty chunk data is ignored, as a chunk size of zero is the last chunk.
+	Data(D),
+
+	/// The last chunk, with any trailer fields, eg an `ETag` calculated over the body.
+	///
+	/// Trailer fields should be announced with a `Trailer` header, and must not be fields needed to frame or route the message, eg `Content-Length` or `Host` (RFC 7230, Section 4.1.2).
+	Last(Vec<Box<HeaderResponseBuffer>>),
+}
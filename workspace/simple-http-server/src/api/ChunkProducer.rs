@@ -0,0 +1,31 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Produces the chunks of a chunked body, for a body of unknown length, eg one generated incrementally.
+///
+/// Chunks are produced one at a time, once the previous chunk has been written.
+pub trait ChunkProducer
+{
+	/// Chunk data.
+	type Data: AsRef<[u8]> + 'static;
+
+	/// Produce the next chunk.
+	///
+	/// Return `Err(())` if the next chunk is not yet available; this will be called again the next time the connection is serviced.
+	///
+	/// Must not be called again once `Chunk::Last` has been produced.
+	fn produce_chunk(&mut self) -> Result<Chunk<Self::Data>, ()>;
+
+	/// A source which becomes readable when the next chunk may be available, eg one woken from another thread; it will be registered, edge-triggered, with the connection's `Poll` under the connection's token.
+	///
+	/// Called each time the connection is serviced until `None` is returned; return `Some` only once for each source.
+	/// The source must remove itself from the `Poll` when dropped, as a `Registration` or an `EventFileDescriptorWakeUp` does.
+	///
+	/// Without one, a chunk which is not yet available is only produced the next time the socket is ready.
+	#[inline(always)]
+	fn wake_up_source_to_register(&mut self) -> Option<&Evented>
+	{
+		None
+	}
+}
@@ -0,0 +1,170 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A response with a chunked body (RFC 7230, Section 4.1), created with `ResponseBuilder::chunked()`.
+///
+/// Each chunk is written out in turn as it is produced, across as many writes as need be, until the last chunk and any trailer fields have been written.
+pub struct ChunkedResponse<CP: ChunkProducer>
+{
+	head: BufferedResponse,
+	chunk_producer: CP,
+	chunk: Option<BufferedResponse>,
+	produced_last_chunk: bool,
+}
+
+impl<CP: ChunkProducer> Response for ChunkedResponse<CP>
+{
+	#[inline(always)]
+	fn provide_data(&mut self) -> Option<Result<(&[u8], ResponseDataBufferIdentifier), ()>>
+	{
+		if !self.head.has_been_written()
+		{
+			return self.head.provide_data()
+		}
+
+		if let Err(()) = self.produce_chunk_if_needed()
+		{
+			return Some(Err(()))
+		}
+
+		match self.chunk
+		{
+			None => None,
+
+			Some(ref mut chunk) => chunk.provide_data(),
+		}
+	}
+
+	#[inline(always)]
+	fn data_written(&mut self, bytes_written: usize, data_buffer_identifier: ResponseDataBufferIdentifier)
+	{
+		if !self.head.has_been_written()
+		{
+			self.head.data_written(bytes_written, data_buffer_identifier)
+		}
+		else if let Some(ref mut chunk) = self.chunk
+		{
+			chunk.data_written(bytes_written, data_buffer_identifier)
+		}
+		else
+		{
+			debug_assert!(false, "data written when no chunk was provided")
+		}
+	}
+
+	#[inline(always)]
+	fn provide_vectored_data<'a>(&'a mut self, data_buffers: &mut ResponseDataBuffers<'a>) -> Result<(), ()>
+	{
+		let chunk_produced = self.produce_chunk_if_needed();
+
+		self.head.provide_vectored_data(data_buffers)?;
+
+		if let Some(ref mut chunk) = self.chunk
+		{
+			chunk.provide_vectored_data(data_buffers)?;
+		}
+
+		// The head is written whilst waiting for the first chunk.
+		if data_buffers.is_empty()
+		{
+			chunk_produced
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+
+	#[inline(always)]
+	fn insert_header_response_buffer(&mut self, header_response_buffer: Box<HeaderResponseBuffer>)
+	{
+		self.head.insert_header_response_buffer(header_response_buffer)
+	}
+
+	#[inline(always)]
+	fn vectored_data_written(&mut self, bytes_written: usize)
+	{
+		let bytes_written_beyond_head = self.head.advance(bytes_written);
+
+		if bytes_written_beyond_head == 0
+		{
+			return
+		}
+
+		match self.chunk
+		{
+			None => debug_assert!(false, "more bytes written than were provided"),
+
+			Some(ref mut chunk) =>
+			{
+				let bytes_written_beyond_chunk = chunk.advance(bytes_written_beyond_head);
+				debug_assert_eq!(bytes_written_beyond_chunk, 0, "more bytes written than were provided");
+			}
+		}
+	}
+
+	#[inline(always)]
+	fn wake_up_source_to_register(&mut self) -> Option<&Evented>
+	{
+		self.chunk_producer.wake_up_source_to_register()
+	}
+}
+
+impl<CP: ChunkProducer> ChunkedResponse<CP>
+{
+	#[inline(always)]
+	pub(crate) fn new(head: BufferedResponse, chunk_producer: CP) -> Self
+	{
+		Self
+		{
+			head,
+			chunk_producer,
+			chunk: None,
+			produced_last_chunk: false,
+		}
+	}
+
+	// Replaces the current chunk with the next one once it has been written, until the last chunk has been produced.
+	fn produce_chunk_if_needed(&mut self) -> Result<(), ()>
+	{
+		if self.produced_last_chunk
+		{
+			return Ok(())
+		}
+
+		if let Some(ref chunk) = self.chunk
+		{
+			if !chunk.has_been_written()
+			{
+				return Ok(())
+			}
+		}
+
+		let response_buffers: Vec<Box<HeaderResponseBuffer>> = loop
+		{
+			match self.chunk_producer.produce_chunk()?
+			{
+				Chunk::Data(chunk_data) => if !chunk_data.as_ref().is_empty()
+				{
+					let chunk: Box<HeaderResponseBuffer> = Box::new(ChunkBodyResponseBuffer::new(chunk_data));
+					break vec![chunk]
+				},
+
+				Chunk::Last(trailer_fields) =>
+				{
+					self.produced_last_chunk = true;
+
+					let mut response_buffers: Vec<Box<HeaderResponseBuffer>> = Vec::with_capacity(trailer_fields.len() + 2);
+					response_buffers.push(Box::new(LastChunkBodyResponseBuffer));
+					response_buffers.extend(trailer_fields);
+					response_buffers.push(Box::new(EndOfHeadersHeaderResponseBuffer));
+					break response_buffers
+				}
+			}
+		};
+
+		self.chunk = Some(BufferedResponse::new(response_buffers, None));
+		Ok(())
+	}
+}
@@ -0,0 +1,263 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Wraps a `RequestHandler` to record and validate the headers which affect the connection as a whole.
+///
+/// The `RequestHandler` is only known once a `VirtualHost` has been selected after the TLS handshake.
+#[derive(Debug)]
+pub(crate) struct ConnectionRequestUser<RH: RequestHandler>
+{
+	virtual_host: Option<Rc<VirtualHost<RH>>>,
+	request_handler: Option<RH>,
+	connection_header_values: ArrayVec<[(VectoredBufferOffset, VectoredBufferOffset); 8]>,
+	host_header_value: Option<(VectoredBufferOffset, VectoredBufferOffset)>,
+	send_continue: bool,
+	connection_error: Option<SimpleHttpServedClientConnectionUserError>,
+}
+
+impl<RH: RequestHandler> RequestUser for ConnectionRequestUser<RH>
+{
+	#[inline(always)]
+	fn method(&mut self, request_method: RequestMethod) -> Result<(), InvalidReason>
+	{
+		self.request_handler().method(request_method)
+	}
+
+	#[inline(always)]
+	fn target_uri_segment(&mut self, segment_starts_at_inclusive: VectoredBufferOffset, segment_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		self.request_handler().target_uri_segment(segment_starts_at_inclusive, segment_ends_at_exclusive)
+	}
+
+	#[inline(always)]
+	fn target_uri_query(&mut self, query_starts_at_inclusive: VectoredBufferOffset, query_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		self.request_handler().target_uri_query(query_starts_at_inclusive, query_ends_at_exclusive)
+	}
+
+	#[inline(always)]
+	fn target_uri_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		self.request_handler().target_uri_finished()
+	}
+
+	#[inline(always)]
+	fn status_line_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		self.request_handler().status_line_finished()
+	}
+
+	#[inline(always)]
+	fn header_field(&mut self, known_header: KnownHeader, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		match known_header
+		{
+			KnownHeader::Connection => if self.connection_header_values.try_push((value_starts_at_inclusive, value_ends_at_exclusive)).is_err()
+			{
+				return Err(RequestHeaderFieldsTooLarge)
+			},
+
+			// RFC 7230, Section 5.4: "A server MUST respond with a 400 (Bad Request) status code to any HTTP/1.1 request message that lacks a Host header field and to any request message that contains more than one Host header field or a Host header field with an invalid field-value".
+			KnownHeader::Host => if self.host_header_value.is_some()
+			{
+				return Err(BadRequest("More than one Host header"))
+			}
+			else
+			{
+				self.host_header_value = Some((value_starts_at_inclusive, value_ends_at_exclusive))
+			},
+
+			_ => (),
+		}
+
+		self.request_handler().header_field(known_header, name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, value_ends_at_exclusive)
+	}
+
+	#[inline(always)]
+	fn headers_finished(&mut self, vectored_buffer: &VectoredBuffer, request_body_length: RequestBodyLength, expects_continue: bool) -> Result<(), InvalidReason>
+	{
+		self.validate_host_header(vectored_buffer)?;
+
+		self.request_handler().headers_finished(vectored_buffer, request_body_length, expects_continue)?;
+
+		// RFC 7231, Section 5.1.1: a server MAY omit sending a `100 Continue` if there is no request body.
+		self.send_continue = expects_continue && match request_body_length
+		{
+			RequestBodyLength::None | RequestBodyLength::ContentLength(0) => false,
+			_ => true,
+		};
+		Ok(())
+	}
+
+	#[inline(always)]
+	fn body_chunk(&mut self, vectored_buffer: &VectoredBuffer, chunk_starts_at_inclusive: VectoredBufferOffset, chunk_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		self.request_handler().body_chunk(vectored_buffer, chunk_starts_at_inclusive, chunk_ends_at_exclusive)
+	}
+
+	#[inline(always)]
+	fn trailer_field(&mut self, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		self.request_handler().trailer_field(name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, value_ends_at_exclusive)
+	}
+
+	#[inline(always)]
+	fn body_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		self.request_handler().body_finished()
+	}
+}
+
+impl<RH: RequestHandler> ConnectionRequestUser<RH>
+{
+	#[inline(always)]
+	pub(crate) fn new() -> Self
+	{
+		Self
+		{
+			virtual_host: None,
+			request_handler: None,
+			connection_header_values: ArrayVec::new(),
+			host_header_value: None,
+			send_continue: false,
+			connection_error: None,
+		}
+	}
+
+	/// Must be called before any request is parsed.
+	#[inline(always)]
+	pub(crate) fn select_virtual_host(&mut self, virtual_host: Rc<VirtualHost<RH>>)
+	{
+		self.request_handler = Some(virtual_host.new_request_handler());
+		self.virtual_host = Some(virtual_host);
+	}
+
+	/// Returns whether a virtual host has been selected.
+	#[inline(always)]
+	pub(crate) fn has_selected_virtual_host(&self) -> bool
+	{
+		self.virtual_host.is_some()
+	}
+
+	/// Request methods for the `Allow` header of a `405 Method Not Allowed` response.
+	#[inline(always)]
+	pub(crate) fn allowed_request_methods(&self) -> BTreeSet<RequestMethod>
+	{
+		match self.request_handler
+		{
+			None => BTreeSet::new(),
+
+			Some(ref request_handler) => request_handler.allowed_request_methods(),
+		}
+	}
+
+	/// Returns a more detailed error than the `InvalidReason`, if one was recorded when a request was rejected.
+	#[inline(always)]
+	pub(crate) fn take_connection_error(&mut self) -> Option<SimpleHttpServedClientConnectionUserError>
+	{
+		self.connection_error.take()
+	}
+
+	/// Returns true once if a `100 Continue` interim response should be sent before the request body is read.
+	#[inline(always)]
+	pub(crate) fn take_send_continue(&mut self) -> bool
+	{
+		let send_continue = self.send_continue;
+		self.send_continue = false;
+		send_continue
+	}
+
+	/// Returns a tuple of the response and whether the connection should be closed after the response has been sent (ie the request contained `Connection: close`).
+	#[inline(always)]
+	pub(crate) fn request_finished(&mut self, vectored_buffer: &VectoredBuffer) -> (Box<Response>, bool)
+	{
+		let response = self.request_handler().request_finished(vectored_buffer);
+
+		self.host_header_value = None;
+
+		let mut close = false;
+		for (value_starts_at_inclusive, value_ends_at_exclusive) in self.connection_header_values.drain(..)
+		{
+			close |= Self::has_close_connection_option(&ImmutableVectoredBufferSlice::new(vectored_buffer, value_starts_at_inclusive, value_ends_at_exclusive));
+		}
+
+		(response, close)
+	}
+
+	#[inline(always)]
+	fn request_handler(&mut self) -> &mut RH
+	{
+		self.request_handler.as_mut().expect("select_virtual_host() was not called")
+	}
+
+	#[inline(always)]
+	fn validate_host_header(&mut self, vectored_buffer: &VectoredBuffer) -> Result<(), InvalidReason>
+	{
+		use self::SimpleHttpServedClientConnectionUserError::*;
+
+		let result = match self.host_header_value
+		{
+			None => Err((Http11MissingHostHeader, BadRequest("Missing Host header"))),
+
+			Some((value_starts_at_inclusive, value_ends_at_exclusive)) =>
+			{
+				let virtual_host = self.virtual_host.as_ref().expect("select_virtual_host() was not called");
+				virtual_host.validate_host_header(&ImmutableVectoredBufferSlice::new(vectored_buffer, value_starts_at_inclusive, value_ends_at_exclusive)).map_err(|error| (error, MisdirectedRequest))
+			}
+		};
+
+		result.map_err(|(connection_error, invalid_reason)|
+		{
+			self.connection_error = Some(connection_error);
+			invalid_reason
+		})
+	}
+
+	// RFC 7230, Section 6.1, Connection:-
+	// ```
+	// Connection        = 1#connection-option
+	// connection-option = token
+	// ```
+	//
+	// Connection options are case-insensitive.
+	#[inline(always)]
+	fn has_close_connection_option(value: &ImmutableVectoredBufferSlice) -> bool
+	{
+		const Close: &'static [u8] = b"close";
+
+		let mut matched = 0;
+		let mut is_candidate = true;
+		for byte in value.bytes()
+		{
+			match byte
+			{
+				b',' =>
+				{
+					if is_candidate && matched == Close.len()
+					{
+						return true
+					}
+					matched = 0;
+					is_candidate = true;
+				}
+
+				b' ' | b'\t' => if matched != 0
+				{
+					is_candidate = is_candidate && matched == Close.len();
+				},
+
+				_ => if is_candidate && matched < Close.len() && byte.eq_ignore_ascii_case(&Close[matched])
+				{
+					matched += 1;
+				}
+				else
+				{
+					is_candidate = false;
+				},
+			}
+		}
+
+		is_candidate && matched == Close.len()
+	}
+}
@@ -0,0 +1,54 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// The interim `100 Continue` response (RFC 7231, Section 6.2.1), sent to a client which sent `Expect: 100-continue` once the request body has been accepted.
+#[derive(Default, Debug, Copy, Clone)]
+pub(crate) struct ContinueResponse
+{
+	header_response_buffer_index: usize,
+	from_offset: usize,
+}
+
+impl Response for ContinueResponse
+{
+	#[inline(always)]
+	fn provide_data(&mut self) -> Option<Result<(&[u8], ResponseDataBufferIdentifier), ()>>
+	{
+		Self::header_response_buffer(self.header_response_buffer_index).map(|header_response_buffer| Ok((header_response_buffer.report_buffer(0, self.from_offset), self.header_response_buffer_index)))
+	}
+
+	#[inline(always)]
+	fn data_written(&mut self, bytes_written: usize, data_buffer_identifier: ResponseDataBufferIdentifier)
+	{
+		debug_assert_eq!(data_buffer_identifier, self.header_response_buffer_index, "data_buffer_identifier `{}` was not the one last provided", data_buffer_identifier);
+
+		self.from_offset += bytes_written;
+
+		let header_response_buffer_length = Self::header_response_buffer(self.header_response_buffer_index).unwrap().report_buffer(0, 0).len();
+		if self.from_offset == header_response_buffer_length
+		{
+			self.header_response_buffer_index += 1;
+			self.from_offset = 0;
+		}
+	}
+}
+
+impl ContinueResponse
+{
+	#[inline(always)]
+	fn header_response_buffer(header_response_buffer_index: usize) -> Option<&'static HeaderResponseBuffer>
+	{
+		static StatusLine: StatusLineHeaderResponseBuffer = StatusLineHeaderResponseBuffer::Http11Continue;
+		static EndOfHeaders: EndOfHeadersHeaderResponseBuffer = EndOfHeadersHeaderResponseBuffer;
+
+		match header_response_buffer_index
+		{
+			0 => Some(&StatusLine),
+
+			1 => Some(&EndOfHeaders),
+
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,28 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Handles the requests made on a connection.
+///
+/// An instance is cloned for each connection and then re-used for each (possibly pipelined) request on that connection.
+pub trait RequestHandler: RequestUser + Clone
+{
+	/// Called once a request has been completely parsed, ie after `body_finished()`.
+	///
+	/// `vectored_buffer` can be used to examine the ranges previously reported to this request user; they are not valid after this call returns.
+	///
+	/// The response returned is queued after the responses to any earlier requests on the same connection.
+	fn request_finished(&mut self, vectored_buffer: &VectoredBuffer) -> Box<Response>;
+
+	/// Request methods listed in the `Allow` header of a `405 Method Not Allowed` response, eg after `method()` returned `Err(MethodNotAllowed)`.
+	///
+	/// Defaults to `GET` and `HEAD`.
+	#[inline(always)]
+	fn allowed_request_methods(&self) -> BTreeSet<RequestMethod>
+	{
+		let mut allowed_request_methods = BTreeSet::new();
+		allowed_request_methods.insert(RequestMethod::GET);
+		allowed_request_methods.insert(RequestMethod::HEAD);
+		allowed_request_methods
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Identifies a data buffer provided by a `Response`.
+pub type ResponseDataBufferIdentifier = usize;
+
+/// Data buffers provided by a `Response` for a vectored write; 16 is enough to gather a status line, typical headers, the end of headers and a body.
+pub type ResponseDataBuffers<'a> = ArrayVec<[&'a [u8]; 16]>;
+
+/// A response, consisting of one or more buffers.
+///
+/// Designed to make it easy to resume writes, work with large static files and utilise fixed http.
+pub trait Response
+{
+	/// Provide a tuple of a data buffer to be written out and its identifier; this will be passed back with the amount of data written (this call will be made within the same stack frame).
+	///
+	/// Return None if no more data is to be provided.
+	fn provide_data(&mut self) -> Option<Result<(&[u8], ResponseDataBufferIdentifier), ()>>;
+
+	/// Called after some (but not necessarily all) of the data buffer identified by `data_buffer_identifier` has been written.
+	fn data_written(&mut self, bytes_written: usize, data_buffer_identifier: ResponseDataBufferIdentifier);
+
+	/// Inserts a header after those already added and before the end of headers, eg those of a `SecurityHeaderPolicy`.
+	///
+	/// Called before any data is provided.
+	///
+	/// The default implementation ignores the header, which is appropriate for an interim response such as `100 Continue`.
+	#[inline(always)]
+	fn insert_header_response_buffer(&mut self, _header_response_buffer: Box<HeaderResponseBuffer>)
+	{
+	}
+
+	/// Has this response failed part way through, eg because a file could not be read?
+	///
+	/// Such a response stops providing data and can not be completed, so the connection is closed once it has stopped.
+	#[inline(always)]
+	fn has_failed(&self) -> bool
+	{
+		false
+	}
+
+	/// A source to register with the connection's `Poll` which becomes readable when data, not yet available, may now be available; see `ChunkProducer::wake_up_source_to_register()`.
+	#[inline(always)]
+	fn wake_up_source_to_register(&mut self) -> Option<&Evented>
+	{
+		None
+	}
+
+	/// Provide as many of the data buffers still to be written, in order, as will fit into `data_buffers`.
+	///
+	/// Return `Err(())` if data is not yet available; providing no data buffers means no more data is to be provided.
+	///
+	/// The default implementation provides the single data buffer from `provide_data()`.
+	#[inline(always)]
+	fn provide_vectored_data<'a>(&'a mut self, data_buffers: &mut ResponseDataBuffers<'a>) -> Result<(), ()>
+	{
+		match self.provide_data()
+		{
+			None => Ok(()),
+
+			Some(Err(())) => Err(()),
+
+			Some(Ok((data, _data_buffer_identifier))) =>
+			{
+				data_buffers.push(data);
+				Ok(())
+			}
+		}
+	}
+
+	/// Called after some (but not necessarily all) of the data buffers last provided by `provide_vectored_data()` have been written; a partially written data buffer will be provided again, from where writing stopped.
+	///
+	/// The default implementation calls `provide_data()` again to obtain the data buffer identifier, so `provide_data()` must provide the same data buffer until `data_written()` is called.
+	#[inline(always)]
+	fn vectored_data_written(&mut self, bytes_written: usize)
+	{
+		let data_buffer_identifier = match self.provide_data()
+		{
+			Some(Ok((_data, data_buffer_identifier))) => data_buffer_identifier,
+
+			_ => return,
+		};
+
+		self.data_written(bytes_written, data_buffer_identifier)
+	}
+}
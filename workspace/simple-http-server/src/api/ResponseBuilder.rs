@@ -0,0 +1,346 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Assembles a response from a status line, headers and body response buffers, in the order they are added.
+///
+/// The end of headers is written after the last header and before the first body response buffer.
+///
+/// A response without a body, eg `304 Not Modified`, is terminated by the end of headers.
+pub struct ResponseBuilder
+{
+	status_code: StatusCode,
+	header_response_buffers: Vec<Box<HeaderResponseBuffer>>,
+	body_response_buffers: Vec<Box<HeaderResponseBuffer>>,
+}
+
+impl ResponseBuilder
+{
+	/// A response with a status line and a `Date` header, copied from the `Date` header cached by the current thread.
+	#[inline(always)]
+	pub fn new(status_line: StatusLineHeaderResponseBuffer) -> Self
+	{
+		debug_assert!(!status_line.0.is_informational(), "an informational response is not assembled with a ResponseBuilder");
+
+		let mut header_response_buffers: Vec<Box<HeaderResponseBuffer>> = Vec::with_capacity(12);
+		header_response_buffers.push(Box::new(status_line));
+		header_response_buffers.push(Box::new(DateHeaderResponseBuffer::cached()));
+
+		Self
+		{
+			status_code: status_line.0,
+			header_response_buffers,
+			body_response_buffers: Vec::new(),
+		}
+	}
+
+	/// Adds a header.
+	#[inline(always)]
+	pub fn header(mut self, header_response_buffer: impl HeaderResponseBuffer + 'static) -> Self
+	{
+		self.header_response_buffers.push(Box::new(header_response_buffer));
+		self
+	}
+
+	/// Adds a body response buffer after any already added; none of its buffers may be empty.
+	///
+	/// A `Content-Length` header for the total length of the body response buffers must be added with `header()`.
+	#[inline(always)]
+	pub fn body(mut self, body_response_buffer: impl HeaderResponseBuffer + 'static) -> Self
+	{
+		debug_assert!(self.status_code.is_body_permitted(), "a `{}` response can not have a body", self.status_code.code());
+
+		self.body_response_buffers.push(Box::new(body_response_buffer));
+		self
+	}
+
+	/// Adds a `Content-Length` header and, if it is not empty, `body` as the only body response buffer.
+	#[inline(always)]
+	pub fn in_memory_body<B: AsRef<[u8]> + 'static>(self, body: B) -> Self
+	{
+		let content_length = body.as_ref().len();
+		let this = self.header(ContentLengthHeaderResponseBuffer::new(content_length));
+
+		if content_length == 0
+		{
+			this
+		}
+		else
+		{
+			this.body(InMemoryBodyResponseBuffer::new(body))
+		}
+	}
+
+	/// Adds a `Vary:Accept-Encoding` header, then, if `content_coding` is not `None`, compresses `body` and adds a `Content-Encoding` header, then proceeds as `in_memory_body()`.
+	///
+	/// `content_coding` should be that from `CompressionPolicy::content_coding()`; the body is sent uncompressed if compressing it does not make it shorter.
+	pub fn compressed_in_memory_body<B: AsRef<[u8]> + 'static>(self, body: B, content_coding: Option<ContentCoding>, compression_policy: &CompressionPolicy) -> Self
+	{
+		let this = self.header(VaryHeaderResponseBuffer::accept_encoding());
+
+		if let Some(content_coding) = content_coding
+		{
+			let compressed_body = Compressor::compress_all(content_coding, compression_policy, body.as_ref());
+			if compressed_body.len() < body.as_ref().len()
+			{
+				return this.header(ContentEncodingHeaderResponseBuffer(content_coding)).in_memory_body(compressed_body)
+			}
+		}
+
+		this.in_memory_body(body)
+	}
+
+	/// Adds a `Transfer-Encoding:chunked` header and assembles the response with a chunked body produced by `chunk_producer`, ready to be written.
+	///
+	/// Neither a `Content-Length` header nor body response buffers may be added; a `Trailer` header should be added with `header()` if there will be trailer fields.
+	#[inline(always)]
+	pub fn chunked<CP: ChunkProducer>(self, chunk_producer: CP) -> ChunkedResponse<CP>
+	{
+		debug_assert!(self.body_response_buffers.is_empty(), "a chunked response can not also have body response buffers");
+
+		ChunkedResponse::new(self.header(ChunkedTransferEncodingHeaderResponseBuffer).build(), chunk_producer)
+	}
+
+	/// Adds a `Vary:Accept-Encoding` header and, if `content_coding` is not `None`, a `Content-Encoding` header, then proceeds as `chunked()` with each chunk compressed as it is produced.
+	///
+	/// `content_coding` should be that from `CompressionPolicy::content_coding()` with a `body_length` of `None`.
+	#[inline(always)]
+	pub fn compressed_chunked<CP: ChunkProducer>(self, chunk_producer: CP, content_coding: Option<ContentCoding>, compression_policy: &CompressionPolicy) -> ChunkedResponse<CompressingChunkProducer<CP>>
+	{
+		let mut this = self.header(VaryHeaderResponseBuffer::accept_encoding());
+
+		if let Some(content_coding) = content_coding
+		{
+			this = this.header(ContentEncodingHeaderResponseBuffer(content_coding));
+		}
+
+		this.chunked(CompressingChunkProducer::new(chunk_producer, content_coding, compression_policy))
+	}
+
+	/// Adds `Content-Type:text/event-stream` and `Cache-Control:no-store` headers, then proceeds as `chunked()` with the events of `server_sent_event_source`, and a comment each time `server_sent_events_heartbeat` beats without an event to send.
+	///
+	/// The connection is kept open until `server_sent_event_source` has no more events; responses to requests pipelined after this one wait until then.
+	/// If the request had a `Last-Event-ID` header (see `LastEventIdRequest`), the events missed should be the first from `server_sent_event_source`.
+	#[inline(always)]
+	pub fn server_sent_events<S: ServerSentEventSource>(self, server_sent_event_source: S, server_sent_events_heartbeat: &ServerSentEventsHeartbeat) -> ChunkedResponse<ServerSentEventsChunkProducer<S>>
+	{
+		let mut response_cache_control_directives = BTreeSet::new();
+		response_cache_control_directives.insert(ResponseCacheControlDirective::no_store);
+
+		self.header(ContentTypeHeaderResponseBuffer::text_event_stream()).header(CacheControlHeaderResponseBuffer::new(response_cache_control_directives)).chunked(ServerSentEventsChunkProducer::new(server_sent_event_source, server_sent_events_heartbeat))
+	}
+
+	/// Adds `Content-Length`, `Last-Modified` and `ETag` headers for `static_file`, eg for a response to a `HEAD` request.
+	///
+	/// Also adds `Content-Encoding` for a precompressed sibling, and `Vary:Accept-Encoding` if `static_file` was opened with `StaticFile::open_precompressed()`.
+	#[inline(always)]
+	pub fn static_file_headers(self, static_file: &StaticFile) -> Self
+	{
+		self.header(ContentLengthHeaderResponseBuffer::new(static_file.length() as usize)).static_file_representation_headers(static_file)
+	}
+
+	/// Adds the headers from `static_file_headers()` and assembles the response with the content of `static_file`, read by `static_file_reader`, as its body, ready to be written.
+	///
+	/// Body response buffers may not be added.
+	#[inline(always)]
+	pub fn static_file(self, static_file: StaticFile, static_file_reader: &StaticFileReader) -> StaticFileResponse
+	{
+		debug_assert!(self.body_response_buffers.is_empty(), "a static file response can not also have body response buffers");
+
+		StaticFileResponse::new(self.static_file_headers(&static_file).build(), static_file, static_file_reader)
+	}
+
+	/// Assembles a `206 Partial Content` response (RFC 7233, Section 4.1) with the ranges `byte_ranges` of `static_file` as its body, ready to be written.
+	///
+	/// `self` should have been created with `StatusLineHeaderResponseBuffer::Http11PartialContent`, and may have any `Cache-Control` header which would have been sent in a `200 OK` response; neither a `Content-Type` header nor body response buffers may be added.
+	///
+	/// `byte_ranges` should be those from evaluating a `RangeRequest` with the `entity_tag()`, `last_modified()` and `length()` of `static_file`.
+	/// `content_type` is that of the representation; it is sent as a header for a single range, and in the body part headers of a `multipart/byteranges` body.
+	///
+	/// The other headers are those of `static_file_headers()`, with a `Content-Length` for the partial body.
+	/// Each range is read from the file by `static_file_reader` as it is written out.
+	pub fn static_file_partial_content(self, static_file: StaticFile, byte_ranges: ByteRanges, content_type: ContentTypeHeaderResponseBuffer, static_file_reader: &StaticFileReader) -> StaticFileResponse
+	{
+		debug_assert_eq!(self.status_code, StatusCode::PartialContent, "a static file partial content response must be `206 Partial Content`");
+		debug_assert!(self.body_response_buffers.is_empty(), "a static file response can not also have body response buffers");
+		debug_assert!(byte_ranges.iter().all(|byte_range| byte_range.last_byte_position < static_file.length()), "byte_ranges were not for this static file");
+
+		let representation_length = static_file.length();
+		let ranges_length: u64 = byte_ranges.iter().map(ByteRange::length).sum();
+
+		let (this, multipart_byteranges, content_length) = match byte_ranges.single()
+		{
+			Some(byte_range) => (self.header(content_type).header(ContentRangeHeaderResponseBuffer::satisfied(byte_range, representation_length)), None, ranges_length),
+
+			None =>
+			{
+				let (body_part_headers, close_delimiter, multipart_content_type) = byte_ranges.multipart_byteranges(&content_type, representation_length);
+				let content_length = ranges_length + body_part_headers.iter().map(|body_part_header| body_part_header.len() as u64).sum::<u64>() + close_delimiter.len() as u64;
+				(self.header(multipart_content_type), Some((body_part_headers, close_delimiter)), content_length)
+			}
+		};
+
+		let head = this.header(ContentLengthHeaderResponseBuffer::new(content_length as usize)).static_file_representation_headers(&static_file).build();
+		StaticFileResponse::partial(head, static_file, static_file_reader, &byte_ranges, multipart_byteranges, content_length)
+	}
+
+	/// Assembles the response, ready to be written.
+	#[inline(always)]
+	pub fn build(self) -> BufferedResponse
+	{
+		let mut response_buffers = self.header_response_buffers;
+		let end_of_headers_index = response_buffers.len();
+		response_buffers.reserve_exact(1 + self.body_response_buffers.len());
+		response_buffers.push(Box::new(EndOfHeadersHeaderResponseBuffer));
+		response_buffers.extend(self.body_response_buffers);
+
+		BufferedResponse::new(response_buffers, Some(end_of_headers_index))
+	}
+
+	/// A redirection response, eg `303 See Other`, with a `Location` header and an empty body.
+	///
+	/// `307 Temporary Redirect` and `308 Permanent Redirect` preserve the request method; `301 Moved Permanently` and `302 Found` may be followed with a `GET` instead of a `POST`, and `303 See Other` always is.
+	#[inline(always)]
+	pub fn redirect(status_code: StatusCode, location: LocationHeaderResponseBuffer) -> Self
+	{
+		debug_assert!(status_code.is_redirection() && status_code != StatusCode::NotModified, "`{}` is not a redirection", status_code.code());
+
+		Self::new(StatusLineHeaderResponseBuffer(status_code))
+			.header(location)
+			.header(ContentLengthHeaderResponseBuffer::new(0))
+	}
+
+	/// A `206 Partial Content` response (RFC 7233, Section 4.1) for an in-memory representation.
+	///
+	/// A single range is sent as the body with a `Content-Range` header; multiple ranges are sent as a `multipart/byteranges` body.
+	///
+	/// See `ByteRangesBodyResponseBuffer::new()` for `byte_ranges` and `content_type`.
+	/// For a single range, add the representation's `Content-Type` with `header()`, along with any `ETag`, `Last-Modified`, `Cache-Control` or `Vary` which would have been sent in a `200 OK` response.
+	pub fn partial_content<R: AsRef<[u8]> + 'static>(representation: R, byte_ranges: ByteRanges, content_type: &ContentTypeHeaderResponseBuffer) -> Self
+	{
+		let representation_length = representation.as_ref().len() as u64;
+		let single_byte_range = byte_ranges.single();
+
+		let body = ByteRangesBodyResponseBuffer::new(representation, byte_ranges, content_type);
+
+		let this = Self::new(StatusLineHeaderResponseBuffer::Http11PartialContent).header(ContentLengthHeaderResponseBuffer::new(body.content_length()));
+
+		let this = match single_byte_range
+		{
+			Some(byte_range) => this.header(ContentRangeHeaderResponseBuffer::satisfied(byte_range, representation_length)),
+
+			None => this.header(body.multipart_content_type()),
+		};
+
+		this.body(body)
+	}
+
+	/// A `304 Not Modified` response (RFC 7232, Section 4.1).
+	///
+	/// `e_tag` and `last_modified` should be those which would have been sent in a `200 OK` response; any `Cache-Control` or `Vary` header which would have been sent should be added with `header()`.
+	///
+	/// There is never a body, nor a `Content-Length`, as a `304 Not Modified` is always terminated by the end of its headers (RFC 7230, Section 3.3.3).
+	#[inline(always)]
+	pub fn not_modified(e_tag: Option<ETagHeaderResponseBuffer>, last_modified: Option<LastModifiedHeaderResponseBuffer>) -> Self
+	{
+		let mut this = Self::new(StatusLineHeaderResponseBuffer::Http11NotModified);
+
+		if let Some(e_tag) = e_tag
+		{
+			this = this.header(e_tag);
+		}
+
+		if let Some(last_modified) = last_modified
+		{
+			this = this.header(last_modified);
+		}
+
+		this
+	}
+
+	/// A `412 Precondition Failed` response (RFC 7232, Section 4.2) with an empty body.
+	#[inline(always)]
+	pub fn precondition_failed() -> Self
+	{
+		Self::new(StatusLineHeaderResponseBuffer::Http11PreconditionFailed).header(ContentLengthHeaderResponseBuffer::new(0))
+	}
+
+	/// A `416 Range Not Satisfiable` response (RFC 7233, Section 4.4) with an empty body.
+	#[inline(always)]
+	pub fn range_not_satisfiable(representation_length: u64) -> Self
+	{
+		Self::new(StatusLineHeaderResponseBuffer::Http11RangeNotSatisfiable)
+			.header(ContentLengthHeaderResponseBuffer::new(0))
+			.header(ContentRangeHeaderResponseBuffer::unsatisfied(representation_length))
+	}
+
+	/// The final response on a connection, sent when a request was invalid.
+	///
+	/// It has an empty body; the connection is closed after it has been sent as the remaining bytes received can not be trusted to be the start of another request.
+	///
+	/// `allowed_request_methods` is only used for a `405 Method Not Allowed` response.
+	///
+	/// Security headers are those of the listener's `SecurityHeaderPolicy`, as for any other response.
+	pub(crate) fn invalid_request(invalid_reason: InvalidReason, allowed_request_methods: impl FnOnce() -> BTreeSet<RequestMethod>) -> Self
+	{
+		let this = Self::new(StatusLineHeaderResponseBuffer(Self::status_code(invalid_reason)))
+			.header(ContentLengthHeaderResponseBuffer::new(0))
+			.header(CloseConnectionHeaderResponseBuffer);
+
+		if invalid_reason == InvalidReason::MethodNotAllowed
+		{
+			this.header(AllowHeaderResponseBuffer::new(allowed_request_methods()))
+		}
+		else
+		{
+			this
+		}
+	}
+
+	#[inline(always)]
+	fn static_file_representation_headers(self, static_file: &StaticFile) -> Self
+	{
+		let mut this = self
+			.header(static_file.last_modified_header())
+			.header(static_file.e_tag_header());
+
+		if let Some(content_encoding) = static_file.content_encoding_header()
+		{
+			this = this.header(content_encoding);
+		}
+
+		if static_file.varies_by_accept_encoding()
+		{
+			this = this.header(VaryHeaderResponseBuffer::accept_encoding());
+		}
+
+		this
+	}
+
+	#[inline(always)]
+	fn status_code(invalid_reason: InvalidReason) -> StatusCode
+	{
+		use self::InvalidReason::*;
+
+		match invalid_reason
+		{
+			BadRequest(_) | ObsoleteLineFolding | WhitespaceBeforeHeaderFieldColon | InvalidContentLength | MultipleContentLength | ContentLengthAndTransferEncoding | TransferEncodingNotFinallyChunked => StatusCode::BadRequest,
+
+			MethodNotAllowed => StatusCode::MethodNotAllowed,
+
+			PayloadTooLarge => StatusCode::PayloadTooLarge,
+
+			UriTooLong => StatusCode::UriTooLong,
+
+			ExpectationFailed => StatusCode::ExpectationFailed,
+
+			MisdirectedRequest => StatusCode::MisdirectedRequest,
+
+			RequestHeaderFieldsTooLarge => StatusCode::RequestHeaderFieldsTooLarge,
+
+			UnsupportedTransferCoding => StatusCode::NotImplemented,
+
+			HttpVersionNotSupported => StatusCode::HttpVersionNotSupported,
+		}
+	}
+}
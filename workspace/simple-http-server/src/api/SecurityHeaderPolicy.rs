@@ -0,0 +1,75 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Security headers attached to every final response sent by a listener, eg `Strict-Transport-Security`.
+///
+/// The headers are rendered once, when the policy is built, and shared by every response.
+///
+/// The default is `X-Content-Type-Options:nosniff` and `X-Frame-Options:deny`.
+/// It does not include `X-XSS-Protection`, as the XSS auditors it controlled have been removed from browsers and could be abused to leak information across origins; use a `Content-Security-Policy` instead.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SecurityHeaderPolicy(Rc<Vec<u8>>);
+
+impl Default for SecurityHeaderPolicy
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::empty()
+			.with(NosniffXContentTypeOptionsHeaderResponseBuffer)
+			.with(DenyXFrameOptionsHeaderResponseBuffer)
+	}
+}
+
+impl HeaderResponseBuffer for SecurityHeaderPolicy
+{
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert_eq!(buffer_index, 0, "buffer_index `{}` out of range", buffer_index);
+		debug_assert!(from_offset == 0 || from_offset < self.0.len(), "from_offset `{}` out of range", from_offset);
+
+		&self.0[from_offset .. ]
+	}
+}
+
+impl SecurityHeaderPolicy
+{
+	/// A policy without any headers.
+	#[inline(always)]
+	pub fn empty() -> Self
+	{
+		SecurityHeaderPolicy(Rc::new(Vec::new()))
+	}
+
+	/// A typical policy for a HTTPS-only site: the default, plus `Strict-Transport-Security` for a year including sub domains, `Referrer-Policy:strict-origin-when-cross-origin`, `Cross-Origin-Opener-Policy:same-origin` and `Cross-Origin-Resource-Policy:same-origin`.
+	#[inline(always)]
+	pub fn recommended() -> Self
+	{
+		Self::default()
+			.with(StrictTransportSecurityHeaderResponseBuffer::new(StrictTransportSecurityHeaderResponseBuffer::MinimumPreloadMaximumAgeInSeconds, true, false).unwrap())
+			.with(ReferrerPolicyHeaderResponseBuffer(ReferrerPolicy::strict_origin_when_cross_origin))
+			.with(CrossOriginOpenerPolicyHeaderResponseBuffer(CrossOriginOpenerPolicy::same_origin))
+			.with(CrossOriginResourcePolicyHeaderResponseBuffer(CrossOriginResourcePolicy::same_origin))
+	}
+
+	/// Adds a header, eg a `ContentSecurityPolicyHeaderResponseBuffer`.
+	pub fn with(mut self, header_response_buffer: impl HeaderResponseBuffer) -> Self
+	{
+		{
+			let headers = Rc::make_mut(&mut self.0);
+			for buffer_index in 0 .. header_response_buffer.number_of_buffers()
+			{
+				headers.extend_from_slice(header_response_buffer.report_buffer(buffer_index, 0));
+			}
+		}
+		self
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_empty(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+}
@@ -11,4 +11,13 @@ pub(crate) trait ServedClientConnectionUser
 	///
 	/// Return an empty RegistrationState for the connection to be dropped.
 	fn service(&mut self, socket: &TcpStream) -> Result<RegistrationState, Self::Error>;
+
+	/// A source, other than the socket, to register with the connection's `Poll` under the connection's token, so that the connection is serviced when it becomes readable; see `Response::wake_up_source_to_register()`.
+	///
+	/// Called after each service until `None` is returned.
+	#[inline(always)]
+	fn wake_up_source_to_register(&mut self) -> Option<&Evented>
+	{
+		None
+	}
 }
@@ -13,7 +13,9 @@ pub trait ServedClientConnectionUserFactory
 	/// Create a new served client connection user.
 	///
 	/// Called after checking the remote address is permitted and the maximum number of connections has not been reached.
-	fn connect(&self, remote_address: SocketAddr) -> Result<Self::User, Self::Error>;
+	///
+	/// `request_parser_limits` are those of the listener which accepted the connection.
+	fn connect(&self, remote_address: SocketAddr, request_parser_limits: RequestParserLimits) -> Result<Self::User, Self::Error>;
 
 	/// Create a new served client connection user.
 	///
@@ -3,264 +3,209 @@
 
 
 /// A simple HTTP implementation.
-pub struct SimpleHttpServedClientConnectionUser
+///
+/// Supports persistent connections and pipelined requests (RFC 7230, Section 6.3).
+///
+/// An invalid request is answered with an error response, after which the connection is closed with a TLS close_notify.
+pub struct SimpleHttpServedClientConnectionUser<RH: RequestHandler>
 {
-	read_buffer: [u8; SimpleHttpServedClientConnectionUser::ReadBufferSize],
-	read_buffer_offset: usize,
-	reading_request: bool,
+	request: Request<ConnectionRequestUser<RH>>,
+	reading_requests: bool,
+	number_of_requests: usize,
+	responses: VecDeque<Box<Response>>,
+	request_invalid: Option<SimpleHttpServedClientConnectionUserError>,
+	sent_close_notify: bool,
 
 	settings: Rc<SimpleHttpServedClientConnectionUserSettings>,
+	virtual_hosts: Rc<VirtualHosts<RH>>,
 }
 
-impl ServedClientConnectionUser for SimpleHttpServedClientConnectionUser
+impl<RH: RequestHandler> ServedClientConnectionUser for SimpleHttpServedClientConnectionUser<RH>
 {
 	type Error = SimpleHttpServedClientConnectionUserError;
 
-	fn service<'a>(&mut self, simplified_server_session: SimplifiedServerSession<'a>) -> Result<(), Result<RegistrationState, Self::Error>>
+	fn service<'a>(&mut self, mut simplified_server_session: SimplifiedServerSession<'a>) -> Result<(), Result<RegistrationState, Self::Error>>
 	{
-		use self::SimpleHttpServedClientConnectionUserError::*;
+		simplified_server_session.write_then_read(self.reading_requests)?;
 
-		simplified_server_session.write_then_read(self.reading_request)?;
-
-		// TODO: Pipelined requests.
-
-		if self.reading_request
+		if self.sent_close_notify
 		{
-			let bytes_read = simplified_server_session.copy_plain_text_into_buffer(&mut self.read_buffer[self.read_buffer_offset .. ])?;
-			self.read_buffer_offset += bytes_read;
-			if self.read_buffer_offset == Self::ReadBufferSize
+			return if simplified_server_session.wants_write()
 			{
-				return Err(Err(ReadBufferLengthEqualed))
+				Err(Ok(simplified_server_session.registration_state(false)))
 			}
-
-			//parse_request_method(bytes: &mut Bytes) -> Result<RequestMethod, Status<NonNull<u8>>>
-			//TargetUriReentryPoint::parse
-			//parse_http_version
-			//
-
-
-
-
-
-
-
-			let mut headers: Vec<HeaderField> = Vec::with_capacity(LikelyMaximumRequestHeaders);
-			match Request::parse(&self.read_buffer, &mut headers).map_err(|error| HttpHeadersInvalid(error))?
+			else
 			{
-				Ok(Incomplete) =>
+				match self.request_invalid.take()
 				{
-					Err(Ok(simplified_server_session.read_registration_state()))
-				}
-
-				Ok(Complete((ref request, length))) =>
-				{
-					self.reading_request = false;
-					let (headers, target_uri, client_end_entity_certificate) = self.request_headers_completely_read(simplified_server_session, headers, request, length);
+					None => Ok(()),
 
-					// TODO: Call some method that returns a data buffer, the later of which can be got as a slice
-				},
+					Some(error) => Err(Err(error)),
+				}
 			}
 		}
 
-		pub type ResponseDataBufferIdentifier = usize;
-
-		/// A response, consisting of one or more buffers.
-		///
-		/// Designed to make it easy to resume writes, work with large static files and utilise fixed http.
-		pub trait Response
+		if !self.request.request_user().has_selected_virtual_host()
 		{
-			/// Provide a tuple of a data buffer to be written out and its identifier; this will be passed back with the amount of data written (this call will be made within the same stack frame).
-			///
-			/// Return None if no more data is to be provided.
-			fn provide_data(&mut self) -> Option<Result<(&[u8], DataBufferIdentifier), ()>>;
-
-			fn data_written(&mut self, bytes_written: usize, data_buffer_identifier: DataBufferIdentifier);
+			let virtual_host = self.virtual_hosts.find(simplified_server_session.get_sni_hostname()).map_err(Err)?;
+			self.request.request_user().select_virtual_host(virtual_host);
 		}
 
-		struct LargeFileResponse
+		if self.reading_requests
 		{
-			buffer: [u8; 1024 * 32],
-			buffer_end: usize,
-			file: Option<File>,
-		}
+			simplified_server_session.read_plain_text_into(&mut self.request).map_err(Err)?;
 
-		impl Response for FileResponse
-		{
-			fn provide_data(&mut self) -> Option<Result<(&[u8], DataBufferIdentifier), ()>>
-			{
-				match self.file.as_mut()
-				{
-					None => Some(Ok((&self.buffer[ .. self.buffer_end], 0))),
-					Some(file) =>
-					{
+			self.parse_requests();
+		}
 
-					}
-				}
-				unimplemented!()
-			}
+		self.write_responses(&mut simplified_server_session);
 
-			fn data_written(&mut self, bytes_written: usize, data_buffer_identifier: ResponseDataBufferIdentifier)
-			{
-				unimplemented!()
-			}
+		if self.has_finished()
+		{
+			simplified_server_session.send_close_notify();
+			self.sent_close_notify = true;
 		}
 
-		;
+		Err(Ok(simplified_server_session.registration_state(self.reading_requests)))
+	}
+
+	#[inline(always)]
+	fn wake_up_source_to_register(&mut self) -> Option<&Evented>
+	{
+		self.responses.iter_mut().filter_map(|response| response.wake_up_source_to_register()).next()
 	}
 }
 
-impl SimpleHttpServedClientConnectionUser
+impl<RH: RequestHandler> SimpleHttpServedClientConnectionUser<RH>
 {
-	const ReadBufferSize: usize = 4096;
-
-	const LikelyMaximumRequestHeaders: usize = 16;
+	const LikelyMaximumPipelinedRequests: usize = 4;
 
-	pub(crate) fn new(settings: &Rc<SimpleHttpServedClientConnectionUserSettings>) -> Self
+	pub(crate) fn new(settings: &Rc<SimpleHttpServedClientConnectionUserSettings>, virtual_hosts: &Rc<VirtualHosts<RH>>, bytes: Bytes, request_parser_limits: RequestParserLimits) -> Self
 	{
 		Self
 		{
-			reading_request: true,
-			read_buffer: unsafe { uninitialized() },
-			read_buffer_offset: 0,
+			request: Request::new(bytes, ConnectionRequestUser::new(), request_parser_limits),
+			reading_requests: true,
+			number_of_requests: 0,
+			responses: VecDeque::with_capacity(Self::LikelyMaximumPipelinedRequests),
+			request_invalid: None,
+			sent_close_notify: false,
 
 			settings: settings.clone(),
+			virtual_hosts: virtual_hosts.clone(),
 		}
 	}
 
-	fn request_headers_completely_read<'a>(&mut self, simplified_server_session: SimplifiedServerSession<'a>, headers: Vec<HeaderField>, request: &Request, _length: usize) -> Result<(Vec<HeaderField>, Url, Option<EndEntityCert>), SimpleHttpServedClientConnectionUserError>
+	/// Parses as many complete requests as possible from the plain text received so far, queuing a response for each in order.
+	///
+	/// Stops reading requests after an invalid one, queuing an error response for it.
+	fn parse_requests(&mut self)
 	{
-		Self::validate_minor_version(&request)?;
-
-		Self::validate_request_method_is_get(&request)?;
+		while self.reading_requests
+		{
+			match self.request.re_enter()
+			{
+				Err(invalid_reason) =>
+				{
+					self.reading_requests = false;
 
-		Self::validate_alpn_protocol(&request, &self.server_session)?;
+					let response = Box::new(ResponseBuilder::invalid_request(invalid_reason, || self.request.request_user().allowed_request_methods()).build());
+					self.push_final_response(response);
+					self.request_invalid = Some(self.request.request_user().take_connection_error().unwrap_or(SimpleHttpServedClientConnectionUserError::RequestInvalid(invalid_reason)));
+				}
 
-		Self::validate_host_header(&request, &headers, self.settings.our_hostname, self.settings.our_port_string)?;
+				Ok(false) =>
+				{
+					if self.request.request_user().take_send_continue()
+					{
+						self.responses.push_back(Box::new(ContinueResponse::default()));
+					}
+					return
+				}
 
-		Self::validate_sni_hostname(&self.server_session, self.settings.our_hostname)?;
+				Ok(true) =>
+				{
+					// The request body has already been received, so a `100 Continue` is pointless.
+					self.request.request_user().take_send_continue();
 
-		Self::validate_target_is_absolute(request.target)?;
+					self.number_of_requests += 1;
 
-		let target_uri = self.settings.our_base_url.join(request.target).map_err(|error| SimpleHttpServedClientConnectionUserError::TargetIsInvalidUri(error))?;
+					let (mut response, close) =
+					{
+						let (vectored_buffer, request_user) = self.request.vectored_buffer_and_request_user();
+						request_user.request_finished(vectored_buffer)
+					};
+					self.request.request_no_longer_needed();
 
-		let client_end_entity_certificate = match self.server_session.get_peer_certificates()
-		{
-			None => None,
+					// RFC 7230, Section 6.6, Tear-down: the final response on a connection should include a `Connection: close` header.
+					if close || self.number_of_requests == self.settings.maximum_requests_per_connection
+					{
+						self.reading_requests = false;
+						response.insert_header_response_buffer(Box::new(CloseConnectionHeaderResponseBuffer));
+					}
 
-			Some(certificates) => if certificates.is_empty()
-			{
-				None
+					self.push_final_response(response);
+				}
 			}
-			else
-			{
-				let first = unsafe { certificates.get_unchecked(0) };
-				Some(EndEntityCert::from(first.der_encoded_certificate()).map_err(SimpleHttpServedClientConnectionUserError::EndEntityClientCertificateInvalid)?)
-			},
-		};
-
-		Ok((headers, target_uri, client_end_entity_certificate))
-	}
-
-	fn validate_minor_version(request: &Request) -> Result<(), SimpleHttpServedClientConnectionUserError>
-	{
-		if request.minor_version_is_invalid()
-		{
-			Err(SimpleHttpServedClientConnectionUserError::UnsupportedHttpMinorVersion)
-		}
-		else
-		{
-			Ok(())
 		}
 	}
 
-	fn validate_request_method_is_get(request: &Request) -> Result<(), SimpleHttpServedClientConnectionUserError>
+	/// Queues a final (ie not `100 Continue`) response, attaching the listener's security headers.
+	#[inline(always)]
+	fn push_final_response(&mut self, mut response: Box<Response>)
 	{
-		if request.method_is_other_than_get()
+		if !self.settings.security_header_policy.is_empty()
 		{
-			Err(SimpleHttpServedClientConnectionUserError::UnsupportedHttpMethod)
-		}
-		else
-		{
-			Ok(())
+			response.insert_header_response_buffer(Box::new(self.settings.security_header_policy.clone()));
 		}
+		self.responses.push_back(response);
 	}
 
-	fn validate_alpn_protocol<'buffer>(request: &Request, server_session: &ServerSession) -> Result<(), SimpleHttpServedClientConnectionUserError>
+	/// Writes as much as possible of the queued responses, in order, as plain text, gathering each response's data buffers for a vectored write.
+	///
+	/// A response only partially written stays at the front of the queue, and writing resumes from where it stopped on the next call, ie on the next writable event once the session has written its TLS records to the socket.
+	fn write_responses<'a>(&mut self, simplified_server_session: &mut SimplifiedServerSession<'a>)
 	{
-		if let Some(alpn_protocol) = server_session.get_alpn_protocol()
+		while let Some(response) = self.responses.front_mut()
 		{
-			let minor_version_expected = match alpn_protocol
+			let (bytes_written, bytes_provided) =
 			{
-				TlsConfiguration::AlpnProtocolHttp_1_1 => 1,
+				let mut data_buffers = ResponseDataBuffers::new();
+				if response.provide_vectored_data(&mut data_buffers).is_err()
+				{
+					return
+				}
 
-				TlsConfiguration::AlpnProtocolHttp_1_0 => 0,
+				let bytes_provided: usize = data_buffers.iter().map(|data_buffer| data_buffer.len()).sum();
+				(simplified_server_session.write_plain_text_vectored(&data_buffers), bytes_provided)
 			};
 
-			if request.minor_version != minor_version_expected
+			if bytes_provided == 0
 			{
-				return Err(SimpleHttpServedClientConnectionUserError::AlpnProtocolMismatchesHttpMinorVersion)
-			}
-		}
-		Ok(())
-	}
-
-	fn validate_host_header<'buffer>(request: &Request, headers: &Vec<HeaderField<'buffer>>, our_hostname: &str, our_port_string: &str) -> Result<(), SimpleHttpServedClientConnectionUserError>
-	{
-		use self::SimpleHttpServedClientConnectionUserError::*;
-
-		let mut host_header_missing = true;
-		for header_field in headers.iter().rev()
-		{
-			if header_field.name.eq_ignore_ascii_case("Host")
-			{
-				let iterator = header_field.value.splitn(2, ':');
-				let hostname = Some(iterator.next().unwrap());
-
-				if !hostname.eq_ignore_ascii_case(our_hostname)
+				if response.has_failed()
 				{
-					return Err(HostHeaderHostnameMismatch)
+					self.reading_requests = false;
+					self.responses.clear();
+					return
 				}
 
-				if let Some(port_string) = iterator.next()
-				{
-					if port_string != our_port_string
-					{
-						return Err(HostHeaderHasIncorrectPort)
-					}
-				}
-
-				host_header_missing = false
+				self.responses.pop_front();
+				continue
 			}
-		}
 
-		if host_header_missing && request.minor_version == 1
-		{
-			return Err(Http11MissingHostHeader)
-		}
-	}
+			response.vectored_data_written(bytes_written);
 
-	fn validate_sni_hostname(server_session: &ServerSession, our_hostname: &str) -> Result<(), SimpleHttpServedClientConnectionUserError>
-	{
-		if let Some(sni_hostname) = server_session.get_sni_hostname()
-		{
-			if !sni_hostname.eq_ignore_ascii_case(our_hostname)
+			if bytes_written != bytes_provided
 			{
-				return Err(SimpleHttpServedClientConnectionUserError::SniHostnameMismatch)
+				return
 			}
 		}
-
-		Ok(())
 	}
 
-	fn validate_target_is_absolute(target: &str) -> Result<(), SimpleHttpServedClientConnectionUserError>
+	/// No more requests will be read and all responses have been written.
+	#[inline(always)]
+	fn has_finished(&self) -> bool
 	{
-		if request.target.starts_with('/')
-		{
-			Ok(())
-		}
-		else
-		{
-			Err(SimpleHttpServedClientConnectionUserError::TargetIsNotAbsolute)
-		}
+		!self.reading_requests && self.responses.is_empty()
 	}
 }
@@ -9,11 +9,7 @@ pub enum SimpleHttpServedClientConnectionUserError
 
 	ReadBufferLengthEqualed,
 
-	HttpHeadersInvalid(::thhp::Error),
-
-	UnsupportedHttpMinorVersion,
-
-	UnsupportedHttpMethod,
+	RequestInvalid(InvalidReason),
 
 	AlpnProtocolMismatchesHttpMinorVersion,
 
@@ -57,11 +53,7 @@ impl error::Error for SimpleHttpServedClientConnectionUserError
 
 			&ReadBufferLengthEqualed => None,
 
-			&HttpHeadersInvalid(ref error) => Some(error),
-
-			&UnsupportedHttpMinorVersion => None,
-
-			&UnsupportedHttpMethod => None,
+			&RequestInvalid(_) => None,
 
 			&AlpnProtocolMismatchesHttpMinorVersion => None,
 
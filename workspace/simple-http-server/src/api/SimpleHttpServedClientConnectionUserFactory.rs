@@ -4,28 +4,40 @@
 
 /// A simple HTTP implementation.
 #[derive(Debug)]
-pub struct SimpleHttpServedClientConnectionUserFactory
+pub struct SimpleHttpServedClientConnectionUserFactory<RH: RequestHandler, AO: AllocationObserver>
 {
 	settings: Rc<SimpleHttpServedClientConnectionUserSettings>,
+	virtual_hosts: Rc<VirtualHosts<RH>>,
+	buffer_arena: Arc<BufferArena<AO>>,
+	next_allocation_observer_identifier: Cell<AllocationObserverIdentifier>,
 }
 
-impl ServedClientConnectionUserFactory for SimpleHttpServedClientConnectionUserFactory
+impl<RH: RequestHandler, AO: AllocationObserver> ServedClientConnectionUserFactory for SimpleHttpServedClientConnectionUserFactory<RH, AO>
 {
-	type SCCU = SimpleHttpServedClientConnectionUser;
+	type SCCU = SimpleHttpServedClientConnectionUser<RH>;
 
-	fn new(&self, _remote_address: SocketAddr) -> Result<Self::SCCU, ()>
+	fn new(&self, _remote_address: SocketAddr, request_parser_limits: RequestParserLimits) -> Result<Self::SCCU, ()>
 	{
-		Ok(SimpleHttpServedClientConnectionUser::new(&self.settings))
+		let allocation_observer_identifier = self.next_allocation_observer_identifier.get();
+		self.next_allocation_observer_identifier.set(allocation_observer_identifier.wrapping_add(1));
+
+		let (vectored_buffer, _first_buffer_index) = VectoredBuffer::new(allocation_observer_identifier, &self.buffer_arena)?;
+
+		Ok(SimpleHttpServedClientConnectionUser::new(&self.settings, &self.virtual_hosts, Bytes::new(vectored_buffer), request_parser_limits))
 	}
 }
 
-impl SimpleHttpServedClientConnectionUserFactory
+impl<RH: RequestHandler, AO: AllocationObserver> SimpleHttpServedClientConnectionUserFactory<RH, AO>
 {
-	pub fn new(settings: SimpleHttpServedClientConnectionUserSettings) -> Self
+	/// A connection is rejected unless its TLS SNI hostname matches one of `virtual_hosts` (or it has no SNI hostname and there is a default virtual host).
+	pub fn new(settings: SimpleHttpServedClientConnectionUserSettings, virtual_hosts: VirtualHosts<RH>, buffer_arena: Arc<BufferArena<AO>>) -> Self
 	{
 		Self
 		{
 			settings: Rc::new(settings),
+			virtual_hosts: Rc::new(virtual_hosts),
+			buffer_arena,
+			next_allocation_observer_identifier: Cell::new(0),
 		}
 	}
 }
@@ -30,6 +30,62 @@ impl SimplifiedServerSession
 		self.0.read(buffer_to_copy_into).map_err(|_| SimpleHttpServedClientConnectionUserError::CloseNotify)
 	}
 
+	/// Call this after `write_then_read(true)`.
+	///
+	/// Reads plain text directly into the request's buffers; will only fail if the TLS message `close_notify` has been received.
+	#[inline(always)]
+	pub fn read_plain_text_into<RU: RequestUser>(&mut self, request: &mut Request<RU>) -> Result<usize, SimpleHttpServedClientConnectionUserError>
+	{
+		// The only error read() can return is ConnectionAborted which caused by CloseNotify being received.
+		request.read_into(&mut self.0).map_err(|_| SimpleHttpServedClientConnectionUserError::CloseNotify)
+	}
+
+	/// Queues plain text to be encrypted and written in the next `write_then_read` call.
+	///
+	/// Returns the number of bytes accepted, which may be less than `plain_text.len()` if the session's send buffer is full.
+	#[inline(always)]
+	pub fn write_plain_text(&mut self, plain_text: &[u8]) -> usize
+	{
+		// Writing plain text into a ServerSession never fails; it only accepts less than was offered.
+		self.0.write(plain_text).unwrap_or(0)
+	}
+
+	/// Queues plain text, gathered from `plain_texts` in order, to be encrypted and written in the next `write_then_read` call.
+	///
+	/// A `ServerSession` has no vectored write of plain text, so each is queued in turn, stopping at the first which is not accepted in full; encrypted TLS records are then written to the socket with a vectored write.
+	///
+	/// Returns the number of bytes accepted in total.
+	#[inline(always)]
+	pub fn write_plain_text_vectored(&mut self, plain_texts: &[&[u8]]) -> usize
+	{
+		let mut total_bytes_written = 0;
+		for plain_text in plain_texts
+		{
+			let bytes_written = self.write_plain_text(plain_text);
+			total_bytes_written += bytes_written;
+
+			if bytes_written != plain_text.len()
+			{
+				break
+			}
+		}
+		total_bytes_written
+	}
+
+	/// Are there TLS bytes, such as those of a close_notify, still to be written?
+	#[inline(always)]
+	pub fn wants_write(&self) -> bool
+	{
+		self.0.wants_write()
+	}
+
+	/// A registration state that includes a read available notification request if `wants_to_read` is true.
+	#[inline(always)]
+	pub fn registration_state(&self, wants_to_read: bool) -> RegistrationState
+	{
+		self.0.registration_state(wants_to_read)
+	}
+
 	/// A registration state that includes a read available notification request.
 	#[inline(always)]
 	pub fn read_registration_state(&self) -> RegistrationState
@@ -0,0 +1,183 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A regular file opened to be served as a static representation, with validators derived from its metadata.
+///
+/// Use `last_modified()` and `entity_tag()` to evaluate a `ConditionalRequest`, and then, with `length()`, a `RangeRequest`, before responding with `ResponseBuilder::static_file()` or `ResponseBuilder::static_file_partial_content()`.
+///
+/// If opened with `open_precompressed()`, it may be a precompressed sibling of the file requested.
+#[derive(Debug)]
+pub struct StaticFile
+{
+	file: File,
+	length: u64,
+	last_modified: Tm,
+	e_tag: Vec<u8>,
+	content_coding: Option<ContentCoding>,
+	varies_by_accept_encoding: bool,
+}
+
+impl StaticFile
+{
+	/// Opens a regular file; anything else, eg a directory, is an error.
+	pub fn open(path: impl AsRef<Path>) -> io::Result<Self>
+	{
+		let file = File::open(path)?;
+
+		let metadata = file.metadata()?;
+		if !metadata.is_file()
+		{
+			return Err(io::Error::new(ErrorKind::InvalidInput, "not a regular file"))
+		}
+
+		let length = metadata.len();
+
+		let modified = metadata.modified()?.duration_since(UNIX_EPOCH).unwrap_or(Duration::from_secs(0));
+
+		Ok
+		(
+			Self
+			{
+				file,
+				length,
+				last_modified: at_utc(Timespec::new(modified.as_secs() as i64, 0)),
+				e_tag: Self::e_tag(modified, length),
+				content_coding: None,
+				varies_by_accept_encoding: false,
+			}
+		)
+	}
+
+	/// Opens a regular file as `open()` does, but instead opens a sibling precompressed with a content coding the user agent prefers, if there is one, eg `app.js.br` or `app.js.gz` for `app.js`.
+	///
+	/// A sibling is ignored unless it is a regular file modified no earlier than `path`, so that one left behind by an earlier build is never served.
+	///
+	/// Whichever file is opened, the response varies by `Accept-Encoding`.
+	/// The `Content-Type` should be that of `path`, not of the sibling.
+	pub fn open_precompressed(path: impl AsRef<Path>, accept_encoding_request: &AcceptEncodingRequest, vectored_buffer: &VectoredBuffer) -> io::Result<Self>
+	{
+		let path = path.as_ref();
+
+		let mut identity = Self::open(path)?;
+		identity.varies_by_accept_encoding = true;
+
+		let mut content_codings: ArrayVec<[ContentCoding; 2]> = ArrayVec::from([ContentCoding::br, ContentCoding::gzip]);
+		while let Some(content_coding) = accept_encoding_request.preferred_content_coding_among(vectored_buffer, &content_codings[..])
+		{
+			if let Some(precompressed) = Self::open_precompressed_sibling(path, content_coding, &identity)
+			{
+				return Ok(precompressed)
+			}
+
+			content_codings.retain(|&mut untried| untried != content_coding);
+		}
+
+		Ok(identity)
+	}
+
+	/// Content coding, if this is a precompressed sibling.
+	#[inline(always)]
+	pub fn content_coding(&self) -> Option<ContentCoding>
+	{
+		self.content_coding
+	}
+
+	/// Length in bytes.
+	#[inline(always)]
+	pub fn length(&self) -> u64
+	{
+		self.length
+	}
+
+	/// Time last modified, for evaluating a `ConditionalRequest` or `RangeRequest`.
+	#[inline(always)]
+	pub fn last_modified(&self) -> HttpDate
+	{
+		HttpDate::from(self.last_modified)
+	}
+
+	/// Entity tag, for evaluating a `ConditionalRequest` or `RangeRequest`.
+	#[inline(always)]
+	pub fn entity_tag(&self) -> EntityTag
+	{
+		EntityTag
+		{
+			is_weak: false,
+			opaque_tag: &self.e_tag[..],
+		}
+	}
+
+	/// `Last-Modified` header.
+	#[inline(always)]
+	pub fn last_modified_header(&self) -> LastModifiedHeaderResponseBuffer
+	{
+		LastModifiedHeaderResponseBuffer::for_time(self.last_modified)
+	}
+
+	/// `ETag` header.
+	#[inline(always)]
+	pub fn e_tag_header(&self) -> ETagHeaderResponseBuffer
+	{
+		ETagHeaderResponseBuffer::new(false, self.e_tag.clone())
+	}
+
+	/// `Content-Encoding` header, if this is a precompressed sibling.
+	#[inline(always)]
+	pub fn content_encoding_header(&self) -> Option<ContentEncodingHeaderResponseBuffer>
+	{
+		self.content_coding.map(ContentEncodingHeaderResponseBuffer)
+	}
+
+	/// Was this opened with `open_precompressed()`, and so the response varies by `Accept-Encoding`?
+	#[inline(always)]
+	pub fn varies_by_accept_encoding(&self) -> bool
+	{
+		self.varies_by_accept_encoding
+	}
+
+	#[inline(always)]
+	pub(crate) fn into_file(self) -> File
+	{
+		self.file
+	}
+
+	// Any error opening the sibling, eg it not existing, means the file requested is served instead.
+	//
+	// The content coding is appended to the entity tag so that it differs from that of the file requested even if the sibling has the same modification time and length, eg `5b8ff8d1.1a2b3c4d-2a5f-br`.
+	fn open_precompressed_sibling(path: &Path, content_coding: ContentCoding, identity: &Self) -> Option<Self>
+	{
+		let extension = match content_coding
+		{
+			ContentCoding::br => ".br",
+
+			ContentCoding::gzip => ".gz",
+
+			ContentCoding::deflate => unreachable!("there is no conventional extension for deflate"),
+		};
+
+		let mut sibling_path = path.as_os_str().to_os_string();
+		sibling_path.push(extension);
+
+		let mut sibling = Self::open(sibling_path).ok()?;
+
+		if sibling.last_modified < identity.last_modified
+		{
+			return None
+		}
+
+		sibling.content_coding = Some(content_coding);
+		sibling.varies_by_accept_encoding = true;
+		sibling.e_tag.push(b'-');
+		sibling.e_tag.extend_from_slice(content_coding.buffer());
+
+		Some(sibling)
+	}
+
+	// The modification time has a resolution of nanoseconds on most file systems, so is combined with the length to give a strong validator, eg `5b8ff8d1.1a2b3c4d-2a5f`.
+	#[inline(always)]
+	fn e_tag(modified: Duration, length: u64) -> Vec<u8>
+	{
+		format!("{:x}.{:x}-{:x}", modified.as_secs(), modified.subsec_nanos(), length).into_bytes()
+	}
+}
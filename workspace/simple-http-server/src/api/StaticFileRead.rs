@@ -0,0 +1,55 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+// A read of part of a file into the read ahead buffer of a `StaticFileResponse`, made on a `StaticFileReader` thread.
+//
+// The buffer, truncated to the bytes read, is sent back to the response, which is woken by the send.
+pub(crate) struct StaticFileRead
+{
+	file: Arc<File>,
+	offset: u64,
+	buffer: Vec<u8>,
+	read_completed: Sender<Result<Vec<u8>, ()>>,
+}
+
+impl StaticFileRead
+{
+	/// Reads at most `buffer.len()` bytes from `offset`.
+	#[inline(always)]
+	pub(crate) fn new(file: &Arc<File>, offset: u64, buffer: Vec<u8>, read_completed: &Sender<Result<Vec<u8>, ()>>) -> Self
+	{
+		Self
+		{
+			file: file.clone(),
+			offset,
+			buffer,
+			read_completed: read_completed.clone(),
+		}
+	}
+
+	// The file is treated as failed if it can not be read or has been truncated since it was opened, as the `Content-Length` already sent can no longer be honoured.
+	pub(crate) fn read(mut self)
+	{
+		let read_completed = loop
+		{
+			match self.file.read_at(&mut self.buffer[..], self.offset)
+			{
+				Ok(0) => break Err(()),
+
+				Ok(bytes_read) =>
+				{
+					self.buffer.truncate(bytes_read);
+					break Ok(self.buffer)
+				}
+
+				Err(ref error) if error.kind() == ErrorKind::Interrupted => continue,
+
+				Err(_) => break Err(()),
+			}
+		};
+
+		// The response will have been dropped if its connection has been closed.
+		let _ = self.read_completed.send(read_completed);
+	}
+}
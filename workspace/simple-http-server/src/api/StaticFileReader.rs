@@ -0,0 +1,64 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Reads static files for `StaticFileResponse`s on a pool of threads, so that a thread servicing connections never blocks reading a file.
+///
+/// As every connection is encrypted with TLS, the content of a file has to pass through user space to be encrypted, so neither `sendfile()` nor `splice()` can be used; instead, each response has a bounded read ahead buffer which these threads fill.
+///
+/// The threads stop once every clone of this has been dropped.
+#[derive(Debug, Clone)]
+pub struct StaticFileReader
+{
+	reads: Arc<Mutex<mpsc::Sender<StaticFileRead>>>,
+}
+
+impl StaticFileReader
+{
+	/// Enough to keep a few local disks busy.
+	pub const DefaultNumberOfThreads: usize = 4;
+
+	/// Starts `number_of_threads` threads; panics if it is zero.
+	pub fn start(number_of_threads: usize) -> io::Result<Self>
+	{
+		assert_ne!(number_of_threads, 0, "number_of_threads must not be zero");
+
+		let (sender, receiver) = mpsc::channel();
+		let receiver = Arc::new(Mutex::new(receiver));
+
+		for index in 0 .. number_of_threads
+		{
+			let receiver = receiver.clone();
+			Builder::new().name(format!("static-file-reader-{}", index)).spawn(move || Self::read_loop(receiver))?;
+		}
+
+		Ok
+		(
+			Self
+			{
+				reads: Arc::new(Mutex::new(sender)),
+			}
+		)
+	}
+
+	#[inline(always)]
+	pub(crate) fn read(&self, static_file_read: StaticFileRead)
+	{
+		self.reads.lock().unwrap_or_else(PoisonError::into_inner).send(static_file_read).expect("The threads only stop once every clone has been dropped")
+	}
+
+	fn read_loop(reads: Arc<Mutex<mpsc::Receiver<StaticFileRead>>>)
+	{
+		loop
+		{
+			let static_file_read = match reads.lock().unwrap_or_else(PoisonError::into_inner).recv()
+			{
+				Err(_) => return,
+
+				Ok(static_file_read) => static_file_read,
+			};
+
+			static_file_read.read()
+		}
+	}
+}
@@ -0,0 +1,266 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A response with the content of a `StaticFile`, or ranges of it, as its body, created with `ResponseBuilder::static_file()` or `ResponseBuilder::static_file_partial_content()`.
+///
+/// The file is read ahead into a bounded buffer by a `StaticFileReader` thread, which refills it each time it has been written; the connection is woken once the buffer has been refilled.
+pub struct StaticFileResponse
+{
+	head: BufferedResponse,
+	file: Arc<File>,
+	static_file_reader: StaticFileReader,
+	read_completed_sender: Sender<Result<Vec<u8>, ()>>,
+	read_completed: Receiver<Result<Vec<u8>, ()>>,
+	wake_up_registered: bool,
+	read_at: u64,
+	remaining_length: u64,
+	body_parts: Vec<StaticFileBodyPart>,
+	read_ahead: Option<Vec<u8>>,
+	read_ahead_size: usize,
+	read_ahead_from: usize,
+	has_failed: bool,
+}
+
+impl Response for StaticFileResponse
+{
+	#[inline(always)]
+	fn provide_data(&mut self) -> Option<Result<(&[u8], ResponseDataBufferIdentifier), ()>>
+	{
+		if !self.head.has_been_written()
+		{
+			return self.head.provide_data()
+		}
+
+		if self.read_ahead_if_needed().is_err()
+		{
+			return Some(Err(()))
+		}
+
+		match self.read_ahead
+		{
+			Some(ref read_ahead) if self.read_ahead_from != read_ahead.len() => Some(Ok((&read_ahead[self.read_ahead_from .. ], 0))),
+
+			_ => None,
+		}
+	}
+
+	#[inline(always)]
+	fn data_written(&mut self, bytes_written: usize, data_buffer_identifier: ResponseDataBufferIdentifier)
+	{
+		if !self.head.has_been_written()
+		{
+			self.head.data_written(bytes_written, data_buffer_identifier)
+		}
+		else
+		{
+			self.read_ahead_from += bytes_written
+		}
+	}
+
+	#[inline(always)]
+	fn provide_vectored_data<'a>(&'a mut self, data_buffers: &mut ResponseDataBuffers<'a>) -> Result<(), ()>
+	{
+		let read_ahead_is_available = self.read_ahead_if_needed().is_ok();
+
+		self.head.provide_vectored_data(data_buffers)?;
+
+		match self.read_ahead
+		{
+			Some(ref read_ahead) if self.read_ahead_from != read_ahead.len() && !data_buffers.is_full() => data_buffers.push(&read_ahead[self.read_ahead_from .. ]),
+
+			_ => (),
+		}
+
+		if read_ahead_is_available || !data_buffers.is_empty()
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(())
+		}
+	}
+
+	#[inline(always)]
+	fn insert_header_response_buffer(&mut self, header_response_buffer: Box<HeaderResponseBuffer>)
+	{
+		self.head.insert_header_response_buffer(header_response_buffer)
+	}
+
+	#[inline(always)]
+	fn vectored_data_written(&mut self, bytes_written: usize)
+	{
+		let bytes_written_beyond_head = self.head.advance(bytes_written);
+		self.read_ahead_from += bytes_written_beyond_head
+	}
+
+	#[inline(always)]
+	fn has_failed(&self) -> bool
+	{
+		self.has_failed
+	}
+
+	#[inline(always)]
+	fn wake_up_source_to_register(&mut self) -> Option<&Evented>
+	{
+		if self.wake_up_registered
+		{
+			None
+		}
+		else
+		{
+			self.wake_up_registered = true;
+			Some(&self.read_completed)
+		}
+	}
+}
+
+impl StaticFileResponse
+{
+	const ReadAheadSize: usize = 32 * 1024;
+
+	#[inline(always)]
+	pub(crate) fn new(head: BufferedResponse, static_file: StaticFile, static_file_reader: &StaticFileReader) -> Self
+	{
+		let length = static_file.length();
+		Self::with_body_parts(head, static_file, static_file_reader, length, length, Vec::new())
+	}
+
+	/// `byte_ranges` must have been obtained using the length of `static_file`.
+	///
+	/// `multipart_byteranges` are the body part headers and close delimiter from `ByteRanges::multipart_byteranges()` if there is more than one range.
+	pub(crate) fn partial(head: BufferedResponse, static_file: StaticFile, static_file_reader: &StaticFileReader, byte_ranges: &ByteRanges, multipart_byteranges: Option<(Vec<Vec<u8>>, Vec<u8>)>, content_length: u64) -> Self
+	{
+		let mut body_parts = Vec::with_capacity(byte_ranges.len() * 2 + 1);
+
+		match multipart_byteranges
+		{
+			None => body_parts.extend(byte_ranges.iter().map(|byte_range| StaticFileBodyPart::Range(*byte_range))),
+
+			Some((body_part_headers, close_delimiter)) =>
+			{
+				for (body_part_header, byte_range) in body_part_headers.into_iter().zip(byte_ranges.iter())
+				{
+					body_parts.push(StaticFileBodyPart::InMemory(body_part_header));
+					body_parts.push(StaticFileBodyPart::Range(*byte_range));
+				}
+				body_parts.push(StaticFileBodyPart::InMemory(close_delimiter));
+			}
+		}
+
+		// Parts are taken from the end.
+		body_parts.reverse();
+
+		Self::with_body_parts(head, static_file, static_file_reader, content_length, 0, body_parts)
+	}
+
+	#[inline(always)]
+	fn with_body_parts(head: BufferedResponse, static_file: StaticFile, static_file_reader: &StaticFileReader, content_length: u64, remaining_length: u64, body_parts: Vec<StaticFileBodyPart>) -> Self
+	{
+		let read_ahead_size = min(content_length, Self::ReadAheadSize as u64) as usize;
+
+		debug_assert!(body_parts.iter().all(|body_part| match *body_part
+		{
+			StaticFileBodyPart::InMemory(ref bytes) => bytes.len() <= read_ahead_size,
+
+			StaticFileBodyPart::Range(_) => true,
+		}), "a body part header is larger than the read ahead buffer");
+
+		let (read_completed_sender, read_completed) = channel();
+
+		Self
+		{
+			head,
+			file: Arc::new(static_file.into_file()),
+			static_file_reader: static_file_reader.clone(),
+			read_completed_sender,
+			read_completed,
+			wake_up_registered: false,
+			read_at: 0,
+			remaining_length,
+			body_parts,
+			read_ahead: Some(Vec::with_capacity(read_ahead_size)),
+			read_ahead_size,
+			read_ahead_from: 0,
+			has_failed: false,
+		}
+	}
+
+	// Refills the read ahead buffer once it has been written, moving on to the next body part, if any, once the current range of the file has been read.
+	//
+	// Returns `Err(())` if the read ahead buffer is being refilled by a `StaticFileReader` thread.
+	fn read_ahead_if_needed(&mut self) -> Result<(), ()>
+	{
+		use self::TryRecvError::*;
+
+		if self.has_failed
+		{
+			return Ok(())
+		}
+
+		let mut read_ahead = match self.read_ahead.take()
+		{
+			None => return match self.read_completed.try_recv()
+			{
+				Ok(Ok(read_ahead)) =>
+				{
+					self.read_at += read_ahead.len() as u64;
+					self.remaining_length -= read_ahead.len() as u64;
+					self.read_ahead_from = 0;
+					self.read_ahead = Some(read_ahead);
+					Ok(())
+				}
+
+				Ok(Err(())) | Err(Disconnected) =>
+				{
+					self.has_failed = true;
+					Ok(())
+				}
+
+				Err(Empty) => Err(()),
+			},
+
+			Some(read_ahead) => read_ahead,
+		};
+
+		if self.read_ahead_from != read_ahead.len()
+		{
+			self.read_ahead = Some(read_ahead);
+			return Ok(())
+		}
+
+		if self.remaining_length == 0
+		{
+			match self.body_parts.pop()
+			{
+				None =>
+				{
+					self.read_ahead = Some(read_ahead);
+					return Ok(())
+				}
+
+				Some(StaticFileBodyPart::InMemory(bytes)) =>
+				{
+					read_ahead.clear();
+					read_ahead.extend_from_slice(&bytes[..]);
+					self.read_ahead_from = 0;
+					self.read_ahead = Some(read_ahead);
+					return Ok(())
+				}
+
+				Some(StaticFileBodyPart::Range(byte_range)) =>
+				{
+					self.read_at = byte_range.first_byte_position;
+					self.remaining_length = byte_range.length();
+				}
+			}
+		}
+
+		let read_ahead_length = min(self.remaining_length, self.read_ahead_size as u64) as usize;
+		read_ahead.resize(read_ahead_length, 0);
+
+		self.static_file_reader.read(StaticFileRead::new(&self.file, self.read_at, read_ahead, &self.read_completed_sender));
+		Err(())
+	}
+}
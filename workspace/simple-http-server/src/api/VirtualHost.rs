@@ -0,0 +1,95 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A site served by a listener, with its own request handler.
+#[derive(Debug)]
+pub struct VirtualHost<RH: RequestHandler>
+{
+	hostname: String,
+	port_string: String,
+	request_handler: RH,
+}
+
+impl<RH: RequestHandler> VirtualHost<RH>
+{
+	/// `request_handler` is a prototype; it is cloned for each new connection to this virtual host.
+	#[inline(always)]
+	pub fn new(hostname: &str, port: u16, request_handler: RH) -> Self
+	{
+		Self
+		{
+			hostname: hostname.to_ascii_lowercase(),
+			port_string: format!("{}", port),
+			request_handler,
+		}
+	}
+
+	/// Lower case hostname.
+	#[inline(always)]
+	pub fn hostname(&self) -> &str
+	{
+		&self.hostname
+	}
+
+	#[inline(always)]
+	pub(crate) fn new_request_handler(&self) -> RH
+	{
+		self.request_handler.clone()
+	}
+
+	/// Validates the value of a `Host` header (RFC 7230, Section 5.4).
+	///
+	/// ```text
+	/// Host = uri-host [ ":" port ]
+	/// ```
+	///
+	/// The port is optional but, if present, must be ours; an empty port (eg `Host: example.com:`) is treated as if it were omitted (RFC 3986, Section 3.2.3).
+	#[inline(always)]
+	pub(crate) fn validate_host_header(&self, value: &ImmutableVectoredBufferSlice) -> Result<(), SimpleHttpServedClientConnectionUserError>
+	{
+		self.validate_host_header_bytes(value.bytes())
+	}
+
+	fn validate_host_header_bytes(&self, mut bytes: impl Iterator<Item=u8>) -> Result<(), SimpleHttpServedClientConnectionUserError>
+	{
+		use self::SimpleHttpServedClientConnectionUserError::*;
+
+		let hostname = self.hostname.as_bytes();
+		let port_string = self.port_string.as_bytes();
+
+		let mut index = 0;
+		while let Some(byte) = bytes.next()
+		{
+			if byte == b':' && index == hostname.len()
+			{
+				let mut port = bytes.peekable();
+
+				return if port.peek().is_none() || port.eq(port_string.iter().cloned())
+				{
+					Ok(())
+				}
+				else
+				{
+					Err(HostHeaderHasIncorrectPort)
+				}
+			}
+
+			match hostname.get(index)
+			{
+				Some(hostname_byte) if byte.eq_ignore_ascii_case(hostname_byte) => index += 1,
+
+				_ => return Err(HostHeaderHostnameMismatch),
+			}
+		}
+
+		if index == hostname.len()
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(HostHeaderHostnameMismatch)
+		}
+	}
+}
@@ -0,0 +1,58 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Virtual hosts served by a listener, keyed by hostname.
+///
+/// A virtual host is chosen for each connection from the TLS SNI hostname; every request on that connection must then have a `Host` header matching it.
+/// This prevents a client which has connected to one site from sending requests to another.
+#[derive(Debug)]
+pub struct VirtualHosts<RH: RequestHandler>
+{
+	virtual_hosts: HashMap<String, Rc<VirtualHost<RH>>>,
+	default_virtual_host: Option<Rc<VirtualHost<RH>>>,
+}
+
+impl<RH: RequestHandler> Default for VirtualHosts<RH>
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			virtual_hosts: HashMap::default(),
+			default_virtual_host: None,
+		}
+	}
+}
+
+impl<RH: RequestHandler> VirtualHosts<RH>
+{
+	/// Adds a virtual host, replacing any with the same hostname.
+	///
+	/// If `is_default` is true, it is also used for clients that do not send a TLS SNI hostname.
+	pub fn add(&mut self, virtual_host: VirtualHost<RH>, is_default: bool)
+	{
+		let virtual_host = Rc::new(virtual_host);
+
+		if is_default
+		{
+			self.default_virtual_host = Some(virtual_host.clone());
+		}
+
+		self.virtual_hosts.insert(virtual_host.hostname().to_string(), virtual_host);
+	}
+
+	#[inline(always)]
+	pub(crate) fn find(&self, sni_hostname: Option<&str>) -> Result<Rc<VirtualHost<RH>>, SimpleHttpServedClientConnectionUserError>
+	{
+		let virtual_host = match sni_hostname
+		{
+			None => self.default_virtual_host.as_ref(),
+
+			Some(sni_hostname) => self.virtual_hosts.get(&sni_hostname.to_ascii_lowercase()),
+		};
+
+		virtual_host.cloned().ok_or(SimpleHttpServedClientConnectionUserError::SniHostnameMismatch)
+	}
+}
@@ -0,0 +1,228 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Records the `Accept-Encoding` headers of a request (RFC 7231, Section 5.3.4) so that a content coding can be negotiated for the response.
+///
+/// A `RequestHandler` should pass every call of `header_field()` to `header_field()` here, then use `CompressionPolicy::content_coding()` from `request_finished()` and finally call `clear()`.
+#[derive(Default, Debug, Clone)]
+pub struct AcceptEncodingRequest
+{
+	accept_encoding: ArrayVec<[(VectoredBufferOffset, VectoredBufferOffset); 4]>,
+}
+
+impl AcceptEncodingRequest
+{
+	const MaximumElementLength: usize = 32;
+
+	const MaximumQValue: u16 = 1000;
+
+	/// Records the header if it is `Accept-Encoding`.
+	#[inline(always)]
+	pub fn header_field(&mut self, known_header: KnownHeader, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		match known_header
+		{
+			KnownHeader::AcceptEncoding => self.accept_encoding.try_push((value_starts_at_inclusive, value_ends_at_exclusive)).map_err(|_| RequestHeaderFieldsTooLarge),
+
+			_ => Ok(()),
+		}
+	}
+
+	/// The content coding with the highest q-value, if any has a q-value above zero; ties are broken by the order of `ContentCoding`, eg `br` before `gzip`.
+	///
+	/// A coding not listed takes the q-value of `*`, if present.
+	/// `x-gzip` is treated as `gzip` (RFC 7230, Section 4.2.3); elements which can not be parsed are ignored.
+	///
+	/// Returns `None` if there is no `Accept-Encoding` header; although that permits any content coding, user agents which can decompress send one.
+	/// `None` is also returned if nothing is acceptable, not even `identity`; the response should still be sent without a content coding, which RFC 7231 permits and which is more useful than `406 Not Acceptable`.
+	#[inline(always)]
+	pub fn preferred_content_coding(&self, vectored_buffer: &VectoredBuffer) -> Option<ContentCoding>
+	{
+		self.preferred_content_coding_among(vectored_buffer, &[ContentCoding::br, ContentCoding::gzip, ContentCoding::deflate])
+	}
+
+	/// As `preferred_content_coding()`, but only considering `content_codings`, eg those for which a precompressed file exists; ties are broken by their order in `content_codings`.
+	pub fn preferred_content_coding_among(&self, vectored_buffer: &VectoredBuffer, content_codings: &[ContentCoding]) -> Option<ContentCoding>
+	{
+		let mut br = None;
+		let mut gzip = None;
+		let mut deflate = None;
+		let mut wildcard = None;
+
+		for &(value_starts_at_inclusive, value_ends_at_exclusive) in self.accept_encoding.iter()
+		{
+			let field_value = ImmutableVectoredBufferSlice::new(vectored_buffer, value_starts_at_inclusive, value_ends_at_exclusive);
+
+			let mut element: ArrayVec<[u8; Self::MaximumElementLength]> = ArrayVec::new();
+			let mut element_is_too_long = false;
+
+			let mut element_user = |element: &[u8]|
+			{
+				if let Some((coding, q_value)) = Self::parse_element(element)
+				{
+					let q_value = Some(q_value);
+
+					if coding.eq_ignore_ascii_case(b"br")
+					{
+						br = q_value
+					}
+					else if coding.eq_ignore_ascii_case(b"gzip") || coding.eq_ignore_ascii_case(b"x-gzip")
+					{
+						gzip = q_value
+					}
+					else if coding.eq_ignore_ascii_case(b"deflate")
+					{
+						deflate = q_value
+					}
+					else if coding == b"*"
+					{
+						wildcard = q_value
+					}
+				}
+			};
+
+			for byte in field_value.bytes()
+			{
+				if byte == b','
+				{
+					if !element_is_too_long
+					{
+						element_user(&element[..]);
+					}
+					element.clear();
+					element_is_too_long = false;
+				}
+				else if element.try_push(byte).is_err()
+				{
+					element_is_too_long = true;
+				}
+			}
+
+			if !element_is_too_long
+			{
+				element_user(&element[..]);
+			}
+		}
+
+		let mut preferred = None;
+		let mut preferred_q_value = 0;
+		for &content_coding in content_codings.iter()
+		{
+			let q_value = match content_coding
+			{
+				ContentCoding::br => br,
+
+				ContentCoding::gzip => gzip,
+
+				ContentCoding::deflate => deflate,
+			};
+
+			let q_value = q_value.or(wildcard).unwrap_or(0);
+			if q_value > preferred_q_value
+			{
+				preferred = Some(content_coding);
+				preferred_q_value = q_value;
+			}
+		}
+		preferred
+	}
+
+	/// Call after negotiation so this instance can be re-used for the next request on the connection.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.accept_encoding.clear();
+	}
+
+	// RFC 7231, Section 5.3.4:-
+	// ```
+	// Accept-Encoding  = #( codings [ weight ] )
+	// codings          = content-coding / "identity" / "*"
+	// ```
+	//
+	// Returns the coding, which may be empty, and its q-value in thousandths.
+	#[inline(always)]
+	fn parse_element(element: &[u8]) -> Option<(&[u8], u16)>
+	{
+		let mut parts = element.split(|&byte| byte == b';');
+
+		let coding = Self::trim(parts.next().unwrap());
+		if coding.is_empty()
+		{
+			return None
+		}
+
+		let mut q_value = Self::MaximumQValue;
+		for parameter in parts
+		{
+			let parameter = Self::trim(parameter);
+			if parameter.len() >= 2 && (parameter[0] == b'q' || parameter[0] == b'Q') && parameter[1] == b'='
+			{
+				q_value = Self::parse_q_value(&parameter[2 .. ])?;
+			}
+		}
+
+		Some((coding, q_value))
+	}
+
+	// RFC 7231, Section 5.3.1:-
+	// ```
+	// qvalue = ( "0" [ "." 0*3DIGIT ] )
+	//        / ( "1" [ "." 0*3("0") ] )
+	// ```
+	#[inline(always)]
+	fn parse_q_value(q_value: &[u8]) -> Option<u16>
+	{
+		let (integer, fraction) = match q_value.split_first()
+		{
+			Some((&b'0', fraction)) => (0, fraction),
+
+			Some((&b'1', fraction)) => (Self::MaximumQValue, fraction),
+
+			_ => return None,
+		};
+
+		let digits = match fraction.split_first()
+		{
+			None => return Some(integer),
+
+			Some((&b'.', digits)) if digits.len() <= 3 => digits,
+
+			_ => return None,
+		};
+
+		let mut thousandths = 0;
+		let mut multiplier = 100;
+		for &digit in digits
+		{
+			match digit
+			{
+				b'0' ... b'9' => thousandths += ((digit - b'0') as u16) * multiplier,
+
+				_ => return None,
+			}
+			multiplier /= 10;
+		}
+
+		if integer == Self::MaximumQValue && thousandths != 0
+		{
+			None
+		}
+		else
+		{
+			Some(integer + thousandths)
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn trim(value: &[u8]) -> &[u8]
+	{
+		let is_whitespace = |byte: &u8| *byte == b' ' || *byte == b'\t';
+
+		let starts_at = value.iter().position(|byte| !is_whitespace(byte)).unwrap_or(value.len());
+		let ends_at = value.iter().rposition(|byte| !is_whitespace(byte)).map(|index| index + 1).unwrap_or(starts_at);
+
+		&value[starts_at .. ends_at]
+	}
+}
@@ -0,0 +1,18 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Whether a response body is exposed to the BREACH attack, which can recover a secret from a compressed response sent over TLS by observing its length.
+///
+/// A response is exposed if its body contains both a secret, eg a CSRF token or session identifier, and data an attacker can influence, eg a query parameter reflected in the page; an exposed response should not be compressed.
+///
+/// Alternatively, a secret can be masked afresh for every response, eg by XOR-ing it with a random pad sent alongside, so that it never compresses against itself; the response is then not exposed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BreachExposure
+{
+	/// The body contains no secrets, or nothing an attacker can influence.
+	NotExposed,
+
+	/// The body contains a secret and something an attacker can influence.
+	Exposed,
+}
@@ -0,0 +1,30 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Chunk data produced by a `CompressingChunkProducer`.
+#[derive(Debug, Clone)]
+pub enum CompressedChunkData<D: AsRef<[u8]>>
+{
+	/// Chunk data which was not compressed as no content coding was negotiated.
+	Uncompressed(D),
+
+	/// Compressed chunk data.
+	Compressed(Vec<u8>),
+}
+
+impl<D: AsRef<[u8]>> AsRef<[u8]> for CompressedChunkData<D>
+{
+	#[inline(always)]
+	fn as_ref(&self) -> &[u8]
+	{
+		use self::CompressedChunkData::*;
+
+		match *self
+		{
+			Uncompressed(ref data) => data.as_ref(),
+
+			Compressed(ref data) => &data[..],
+		}
+	}
+}
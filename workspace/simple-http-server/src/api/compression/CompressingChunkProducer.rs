@@ -0,0 +1,83 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Compresses the chunks produced by another `ChunkProducer`, created by `ResponseBuilder::compressed_chunked()`.
+///
+/// Each chunk is compressed and flushed as soon as it is produced, so a user agent never waits for data which has been produced; producing larger chunks compresses better.
+///
+/// If no content coding was negotiated, chunks are passed through without being copied.
+pub struct CompressingChunkProducer<CP: ChunkProducer>
+{
+	chunk_producer: CP,
+	compressor: Option<Compressor>,
+	trailer_fields: Option<Vec<Box<HeaderResponseBuffer>>>,
+}
+
+impl<CP: ChunkProducer> ChunkProducer for CompressingChunkProducer<CP>
+{
+	type Data = CompressedChunkData<CP::Data>;
+
+	#[inline(always)]
+	fn produce_chunk(&mut self) -> Result<Chunk<Self::Data>, ()>
+	{
+		use self::Chunk::*;
+		use self::CompressedChunkData::*;
+
+		// Any compressed data remaining when the last chunk was produced has been sent first.
+		if let Some(trailer_fields) = self.trailer_fields.take()
+		{
+			return Ok(Last(trailer_fields))
+		}
+
+		let chunk = self.chunk_producer.produce_chunk()?;
+
+		let chunk = match self.compressor.take()
+		{
+			None => match chunk
+			{
+				Data(data) => Data(Uncompressed(data)),
+
+				Last(trailer_fields) => Last(trailer_fields),
+			},
+
+			Some(mut compressor) => match chunk
+			{
+				Data(data) =>
+				{
+					let compressed = compressor.compress(data.as_ref());
+					self.compressor = Some(compressor);
+					Data(Compressed(compressed))
+				}
+
+				Last(trailer_fields) =>
+				{
+					self.trailer_fields = Some(trailer_fields);
+					Data(Compressed(compressor.finish()))
+				}
+			}
+		};
+
+		Ok(chunk)
+	}
+
+	#[inline(always)]
+	fn wake_up_source_to_register(&mut self) -> Option<&Evented>
+	{
+		self.chunk_producer.wake_up_source_to_register()
+	}
+}
+
+impl<CP: ChunkProducer> CompressingChunkProducer<CP>
+{
+	#[inline(always)]
+	pub(crate) fn new(chunk_producer: CP, content_coding: Option<ContentCoding>, compression_policy: &CompressionPolicy) -> Self
+	{
+		Self
+		{
+			chunk_producer,
+			compressor: content_coding.map(|content_coding| Compressor::new(content_coding, compression_policy)),
+			trailer_fields: None,
+		}
+	}
+}
@@ -0,0 +1,112 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// When and how hard to compress response bodies.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompressionPolicy
+{
+	/// Bodies shorter than this are not compressed, as the saving is outweighed by the cost, and may even be negative.
+	pub minimum_body_length: usize,
+
+	/// Brotli quality, from 0 to 11; higher compresses better but more slowly.
+	pub brotli_quality: u32,
+
+	/// `gzip` and `deflate` compression level, from 0 to 9; higher compresses better but more slowly.
+	pub deflate_level: u32,
+}
+
+impl Default for CompressionPolicy
+{
+	/// Suitable for bodies generated per request; a body compressed once and then served many times, eg a static file, can afford the maximum quality and level.
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			minimum_body_length: 1024,
+			brotli_quality: 5,
+			deflate_level: 6,
+		}
+	}
+}
+
+impl CompressionPolicy
+{
+	/// The content coding to compress a response body with, if any.
+	///
+	/// `content_type` is the value of the response's `Content-Type`, eg `text/html;charset=utf-8`; bodies of media types which are already compressed, eg `image/png` or `application/zip`, are not compressed again.
+	///
+	/// `body_length` is `None` if it is not known in advance, eg for a chunked body.
+	///
+	/// Returns `None` for a body exposed to BREACH, whatever the user agent accepts.
+	pub fn content_coding(&self, accept_encoding_request: &AcceptEncodingRequest, vectored_buffer: &VectoredBuffer, content_type: &[u8], body_length: Option<usize>, breach_exposure: BreachExposure) -> Option<ContentCoding>
+	{
+		if breach_exposure == BreachExposure::Exposed
+		{
+			return None
+		}
+
+		if let Some(body_length) = body_length
+		{
+			if body_length < self.minimum_body_length
+			{
+				return None
+			}
+		}
+
+		if Self::is_already_compressed(content_type)
+		{
+			return None
+		}
+
+		accept_encoding_request.preferred_content_coding(vectored_buffer)
+	}
+
+	// Images other than SVG, bitmaps and icons are compressed, as are audio, video, fonts and archives.
+	fn is_already_compressed(content_type: &[u8]) -> bool
+	{
+		const Uncompressed: [&'static [u8]; 4] =
+		[
+			b"image/svg+xml",
+			b"image/bmp",
+			b"image/x-icon",
+			b"image/vnd.microsoft.icon",
+		];
+
+		const CompressedPrefixes: [&'static [u8]; 3] =
+		[
+			b"image/",
+			b"audio/",
+			b"video/",
+		];
+
+		const Compressed: [&'static [u8]; 15] =
+		[
+			b"application/gzip",
+			b"application/x-gzip",
+			b"application/zip",
+			b"application/x-bzip2",
+			b"application/x-xz",
+			b"application/x-7z-compressed",
+			b"application/x-rar-compressed",
+			b"application/vnd.rar",
+			b"application/zstd",
+			b"application/font-woff",
+			b"font/woff",
+			b"font/woff2",
+			b"application/vnd.ms-fontobject",
+			b"application/java-archive",
+			b"application/epub+zip",
+		];
+
+		let media_type = AcceptEncodingRequest::trim(content_type.split(|&byte| byte == b';').next().unwrap());
+
+		if Uncompressed.iter().any(|uncompressed| media_type.eq_ignore_ascii_case(uncompressed))
+		{
+			return false
+		}
+
+		CompressedPrefixes.iter().any(|prefix| media_type.len() > prefix.len() && media_type[.. prefix.len()].eq_ignore_ascii_case(prefix)) || Compressed.iter().any(|compressed| media_type.eq_ignore_ascii_case(compressed))
+	}
+}
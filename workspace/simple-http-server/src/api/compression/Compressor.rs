@@ -0,0 +1,101 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+// Compresses into a `Vec`, which can not fail to be written to.
+pub(crate) enum Compressor
+{
+	br(CompressorWriter<Vec<u8>>),
+
+	gzip(GzEncoder<Vec<u8>>),
+
+	deflate(ZlibEncoder<Vec<u8>>),
+}
+
+impl Compressor
+{
+	const BrotliBufferSize: usize = 4096;
+
+	const BrotliWindowSizeLogarithm: u32 = 22;
+
+	#[inline(always)]
+	pub(crate) fn new(content_coding: ContentCoding, compression_policy: &CompressionPolicy) -> Self
+	{
+		use self::Compressor::*;
+
+		match content_coding
+		{
+			ContentCoding::br => br(CompressorWriter::new(Vec::new(), Self::BrotliBufferSize, compression_policy.brotli_quality, Self::BrotliWindowSizeLogarithm)),
+
+			ContentCoding::gzip => gzip(GzEncoder::new(Vec::new(), Compression::new(compression_policy.deflate_level))),
+
+			ContentCoding::deflate => deflate(ZlibEncoder::new(Vec::new(), Compression::new(compression_policy.deflate_level))),
+		}
+	}
+
+	/// Compresses all of `data`.
+	#[inline(always)]
+	pub(crate) fn compress_all(content_coding: ContentCoding, compression_policy: &CompressionPolicy, data: &[u8]) -> Vec<u8>
+	{
+		let mut compressor = Self::new(content_coding, compression_policy);
+		compressor.writer().write_all(data).expect("writing to a Vec can not fail");
+		compressor.finish()
+	}
+
+	/// Compresses `data` and flushes, so that everything written so far can be decompressed by the user agent without waiting for more.
+	///
+	/// Returns the compressed data produced, which may be empty.
+	#[inline(always)]
+	pub(crate) fn compress(&mut self, data: &[u8]) -> Vec<u8>
+	{
+		{
+			let writer = self.writer();
+			writer.write_all(data).expect("writing to a Vec can not fail");
+			writer.flush().expect("writing to a Vec can not fail");
+		}
+
+		use self::Compressor::*;
+
+		let compressed = match *self
+		{
+			br(ref mut compressor) => compressor.get_mut(),
+
+			gzip(ref mut compressor) => compressor.get_mut(),
+
+			deflate(ref mut compressor) => compressor.get_mut(),
+		};
+
+		replace(compressed, Vec::new())
+	}
+
+	/// Returns the remaining compressed data, including any trailer, eg gzip's CRC.
+	#[inline(always)]
+	pub(crate) fn finish(self) -> Vec<u8>
+	{
+		use self::Compressor::*;
+
+		match self
+		{
+			br(compressor) => compressor.into_inner(),
+
+			gzip(compressor) => compressor.finish().expect("writing to a Vec can not fail"),
+
+			deflate(compressor) => compressor.finish().expect("writing to a Vec can not fail"),
+		}
+	}
+
+	#[inline(always)]
+	fn writer(&mut self) -> &mut Write
+	{
+		use self::Compressor::*;
+
+		match *self
+		{
+			br(ref mut compressor) => compressor,
+
+			gzip(ref mut compressor) => compressor,
+
+			deflate(ref mut compressor) => compressor,
+		}
+	}
+}
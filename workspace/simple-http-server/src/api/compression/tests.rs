@@ -0,0 +1,131 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn preferred_content_coding_among(field_values: &[&[u8]], content_codings: &[ContentCoding]) -> Option<ContentCoding>
+{
+	let mut input = Vec::new();
+	let mut accept_encoding_request = AcceptEncodingRequest::default();
+	for field_value in field_values.iter()
+	{
+		let value_starts_at_inclusive = VectoredBufferOffset::new(BufferIndex::Minimum, input.len());
+		input.extend_from_slice(field_value);
+		let value_ends_at_exclusive = VectoredBufferOffset::new(BufferIndex::Minimum, input.len());
+		accept_encoding_request.header_field(KnownHeader::AcceptEncoding, value_starts_at_inclusive, value_ends_at_exclusive).unwrap();
+	}
+
+	let bytes = received(&input);
+	accept_encoding_request.preferred_content_coding_among(bytes.vectored_buffer(), content_codings)
+}
+
+fn preferred_content_coding(field_values: &[&[u8]]) -> Option<ContentCoding>
+{
+	preferred_content_coding_among(field_values, &[ContentCoding::br, ContentCoding::gzip, ContentCoding::deflate])
+}
+
+#[test]
+fn q_value_integers_are_parsed()
+{
+	assert_eq!(AcceptEncodingRequest::parse_q_value(b"0"), Some(0));
+	assert_eq!(AcceptEncodingRequest::parse_q_value(b"1"), Some(1000));
+}
+
+#[test]
+fn q_value_fractions_are_parsed_in_thousandths()
+{
+	assert_eq!(AcceptEncodingRequest::parse_q_value(b"0."), Some(0));
+	assert_eq!(AcceptEncodingRequest::parse_q_value(b"0.5"), Some(500));
+	assert_eq!(AcceptEncodingRequest::parse_q_value(b"0.25"), Some(250));
+	assert_eq!(AcceptEncodingRequest::parse_q_value(b"0.125"), Some(125));
+	assert_eq!(AcceptEncodingRequest::parse_q_value(b"0.001"), Some(1));
+	assert_eq!(AcceptEncodingRequest::parse_q_value(b"0.999"), Some(999));
+	assert_eq!(AcceptEncodingRequest::parse_q_value(b"1."), Some(1000));
+	assert_eq!(AcceptEncodingRequest::parse_q_value(b"1.000"), Some(1000));
+}
+
+#[test]
+fn invalid_q_values_are_rejected()
+{
+	for q_value in [&b""[..], b".5", b"2", b"01", b"0.1234", b"1.001", b"1.5", b"0,5", b"0.a", b"-0", b" 0", b"0 "].iter()
+	{
+		assert_eq!(AcceptEncodingRequest::parse_q_value(q_value), None, "{:?}", q_value);
+	}
+}
+
+#[test]
+fn element_without_weight_has_maximum_q_value()
+{
+	assert_eq!(AcceptEncodingRequest::parse_element(b" gzip "), Some((&b"gzip"[..], 1000)));
+}
+
+#[test]
+fn element_weight_is_parsed()
+{
+	assert_eq!(AcceptEncodingRequest::parse_element(b"gzip ; Q=0.5"), Some((&b"gzip"[..], 500)));
+	assert_eq!(AcceptEncodingRequest::parse_element(b"gzip;level=1;q=0"), Some((&b"gzip"[..], 0)));
+}
+
+#[test]
+fn element_with_invalid_weight_or_without_coding_is_ignored()
+{
+	assert_eq!(AcceptEncodingRequest::parse_element(b"gzip;q=2"), None);
+	assert_eq!(AcceptEncodingRequest::parse_element(b"gzip;q="), None);
+	assert_eq!(AcceptEncodingRequest::parse_element(b" ;q=1"), None);
+	assert_eq!(AcceptEncodingRequest::parse_element(b""), None);
+}
+
+#[test]
+fn highest_q_value_is_preferred()
+{
+	assert_eq!(preferred_content_coding(&[b"gzip;q=0.5, deflate;q=0.8"]), Some(ContentCoding::deflate));
+}
+
+#[test]
+fn ties_are_broken_by_the_order_of_content_codings()
+{
+	assert_eq!(preferred_content_coding(&[b"deflate, gzip, br"]), Some(ContentCoding::br));
+	assert_eq!(preferred_content_coding_among(&[b"deflate, gzip, br"], &[ContentCoding::gzip, ContentCoding::br]), Some(ContentCoding::gzip));
+}
+
+#[test]
+fn x_gzip_is_gzip()
+{
+	assert_eq!(preferred_content_coding(&[b"X-GZIP"]), Some(ContentCoding::gzip));
+}
+
+#[test]
+fn wildcard_applies_to_codings_not_listed()
+{
+	assert_eq!(preferred_content_coding(&[b"*"]), Some(ContentCoding::br));
+	assert_eq!(preferred_content_coding(&[b"br;q=0, *;q=0.5"]), Some(ContentCoding::gzip));
+	assert_eq!(preferred_content_coding(&[b"*;q=0, deflate"]), Some(ContentCoding::deflate));
+}
+
+#[test]
+fn zero_q_values_are_not_acceptable()
+{
+	assert_eq!(preferred_content_coding(&[b"gzip;q=0, identity"]), None);
+	assert_eq!(preferred_content_coding(&[b"*;q=0"]), None);
+}
+
+#[test]
+fn no_accept_encoding_header_prefers_nothing()
+{
+	assert_eq!(preferred_content_coding(&[]), None);
+	assert_eq!(preferred_content_coding(&[b""]), None);
+}
+
+#[test]
+fn elements_in_more_than_one_header_are_combined()
+{
+	assert_eq!(preferred_content_coding(&[b"gzip;q=0.1", b"deflate;q=0.2"]), Some(ContentCoding::deflate));
+}
+
+#[test]
+fn element_which_is_too_long_is_ignored()
+{
+	assert_eq!(preferred_content_coding(&[b"gzip;q=0.1, br;                                    q=1"]), Some(ContentCoding::gzip));
+}
@@ -0,0 +1,140 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Records the conditional headers of a request (RFC 7232) so that they can be evaluated once the current representation's validators are known.
+///
+/// A `RequestHandler` should pass every call of `header_field()` to `header_field()` here, then call `evaluate()` from `request_finished()` and finally `clear()`.
+#[derive(Default, Debug, Clone)]
+pub struct ConditionalRequest
+{
+	if_match: ArrayVec<[(VectoredBufferOffset, VectoredBufferOffset); 4]>,
+	if_none_match: ArrayVec<[(VectoredBufferOffset, VectoredBufferOffset); 4]>,
+	if_modified_since: Option<(VectoredBufferOffset, VectoredBufferOffset)>,
+	if_unmodified_since: Option<(VectoredBufferOffset, VectoredBufferOffset)>,
+}
+
+impl ConditionalRequest
+{
+	/// Records the header if it is `If-Match`, `If-None-Match`, `If-Modified-Since` or `If-Unmodified-Since`.
+	///
+	/// `If-Match` and `If-None-Match` are lists and so may occur more than once; only the first `If-Modified-Since` and `If-Unmodified-Since` are used.
+	#[inline(always)]
+	pub fn header_field(&mut self, known_header: KnownHeader, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		let field_value = (value_starts_at_inclusive, value_ends_at_exclusive);
+
+		match known_header
+		{
+			KnownHeader::IfMatch => self.if_match.try_push(field_value).map_err(|_| RequestHeaderFieldsTooLarge),
+
+			KnownHeader::IfNoneMatch => self.if_none_match.try_push(field_value).map_err(|_| RequestHeaderFieldsTooLarge),
+
+			KnownHeader::IfModifiedSince =>
+			{
+				if self.if_modified_since.is_none()
+				{
+					self.if_modified_since = Some(field_value)
+				}
+				Ok(())
+			}
+
+			KnownHeader::IfUnmodifiedSince =>
+			{
+				if self.if_unmodified_since.is_none()
+				{
+					self.if_unmodified_since = Some(field_value)
+				}
+				Ok(())
+			}
+
+			_ => Ok(()),
+		}
+	}
+
+	/// Evaluates the preconditions in the order given in RFC 7232, Section 6.
+	///
+	/// `entity_tag` and `last_modified` are the validators of the current representation of the resource, if it has them.
+	/// There is no need to call this method if the resource does not exist.
+	pub fn evaluate(&self, vectored_buffer: &VectoredBuffer, request_method: RequestMethod, entity_tag: Option<EntityTag>, last_modified: Option<HttpDate>) -> ConditionalRequestOutcome
+	{
+		use self::ConditionalRequestOutcome::*;
+
+		let is_get_or_head = match request_method
+		{
+			RequestMethod::GET | RequestMethod::HEAD => true,
+
+			_ => false,
+		};
+
+		if !self.if_match.is_empty()
+		{
+			if !Self::is_matched_by_any(vectored_buffer, &self.if_match, entity_tag, EntityTagComparison::Strong)
+			{
+				return PreconditionFailed
+			}
+		}
+		else if let (Some(if_unmodified_since), Some(last_modified)) = (Self::http_date(vectored_buffer, self.if_unmodified_since), last_modified)
+		{
+			if last_modified > if_unmodified_since
+			{
+				return PreconditionFailed
+			}
+		}
+
+		if !self.if_none_match.is_empty()
+		{
+			if Self::is_matched_by_any(vectored_buffer, &self.if_none_match, entity_tag, EntityTagComparison::Weak)
+			{
+				return if is_get_or_head
+				{
+					NotModified
+				}
+				else
+				{
+					PreconditionFailed
+				}
+			}
+		}
+		else if is_get_or_head
+		{
+			if let (Some(if_modified_since), Some(last_modified)) = (Self::http_date(vectored_buffer, self.if_modified_since), last_modified)
+			{
+				if last_modified <= if_modified_since
+				{
+					return NotModified
+				}
+			}
+		}
+
+		Proceed
+	}
+
+	/// Call after `evaluate()` so this instance can be re-used for the next request on the connection.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.if_match.clear();
+		self.if_none_match.clear();
+		self.if_modified_since = None;
+		self.if_unmodified_since = None;
+	}
+
+	// `*` matches any current representation, even one without an entity tag.
+	#[inline(always)]
+	fn is_matched_by_any(vectored_buffer: &VectoredBuffer, field_values: &[(VectoredBufferOffset, VectoredBufferOffset)], entity_tag: Option<EntityTag>, comparison: EntityTagComparison) -> bool
+	{
+		match entity_tag
+		{
+			Some(entity_tag) => entity_tag.is_matched_by_any(vectored_buffer, field_values, comparison),
+
+			None => field_values.iter().any(|&(value_starts_at_inclusive, value_ends_at_exclusive)| ImmutableVectoredBufferSlice::new(vectored_buffer, value_starts_at_inclusive, value_ends_at_exclusive).eq_ignore_ascii_case(b"*")),
+		}
+	}
+
+	#[inline(always)]
+	fn http_date(vectored_buffer: &VectoredBuffer, field_value: Option<(VectoredBufferOffset, VectoredBufferOffset)>) -> Option<HttpDate>
+	{
+		field_value.and_then(|(value_starts_at_inclusive, value_ends_at_exclusive)| HttpDate::parse(&ImmutableVectoredBufferSlice::new(vectored_buffer, value_starts_at_inclusive, value_ends_at_exclusive)))
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// The result of evaluating the preconditions of a request.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConditionalRequestOutcome
+{
+	/// Perform the request method as if the conditional headers were absent, eg respond `200 OK`.
+	Proceed,
+
+	/// Respond `304 Not Modified` without a body, eg with `ResponseBuilder::not_modified()`.
+	NotModified,
+
+	/// Respond `412 Precondition Failed`, eg with `ResponseBuilder::precondition_failed()`.
+	PreconditionFailed,
+}
@@ -0,0 +1,123 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// The entity tag of the current representation of a resource, eg as sent in an `ETag` header.
+///
+/// The opaque tag excludes the surrounding double quotes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EntityTag<'a>
+{
+	/// Is this a weak validator, ie prefixed with `W/`?
+	pub is_weak: bool,
+
+	/// The opaque tag.
+	pub opaque_tag: &'a [u8],
+}
+
+impl<'a> EntityTag<'a>
+{
+	/// Does any entity tag in the header field values of an `If-Match`, `If-None-Match` or `If-Range` header match this one?
+	///
+	/// ```text
+	/// If-Match      = "*" / 1#entity-tag
+	/// entity-tag    = [ weak ] opaque-tag
+	/// weak          = %x57.2F ; "W/", case-sensitive
+	/// opaque-tag    = DQUOTE *etagc DQUOTE
+	/// etagc         = %x21 / %x23-7E / obs-text
+	/// ```
+	///
+	/// `*` matches any current representation.
+	///
+	/// A header field value which is not a valid list of entity tags matches nothing.
+	pub fn is_matched_by_any(&self, vectored_buffer: &VectoredBuffer, field_values: &[(VectoredBufferOffset, VectoredBufferOffset)], comparison: EntityTagComparison) -> bool
+	{
+		for &(value_starts_at_inclusive, value_ends_at_exclusive) in field_values.iter()
+		{
+			let field_value = ImmutableVectoredBufferSlice::new(vectored_buffer, value_starts_at_inclusive, value_ends_at_exclusive);
+
+			if field_value.eq_ignore_ascii_case(b"*")
+			{
+				return true
+			}
+
+			if self.is_matched_by(&field_value, comparison)
+			{
+				return true
+			}
+		}
+
+		false
+	}
+
+	/// Does an entity tag in a header field value match this one?
+	///
+	/// Used for `If-Range`, which has a single entity tag.
+	#[inline(always)]
+	pub fn is_matched_by(&self, field_value: &ImmutableVectoredBufferSlice, comparison: EntityTagComparison) -> bool
+	{
+		self.is_matched_by_any_in_field_value(field_value, comparison) == Ok(true)
+	}
+
+	fn is_matched_by_any_in_field_value(&self, field_value: &ImmutableVectoredBufferSlice, comparison: EntityTagComparison) -> Result<bool, ()>
+	{
+		use self::EntityTagListParseState::*;
+
+		let mut state = BeforeEntityTag;
+		for byte in field_value.bytes()
+		{
+			state = match (state, byte)
+			{
+				(BeforeEntityTag, b' ') | (BeforeEntityTag, b'\t') | (BeforeEntityTag, b',') => BeforeEntityTag,
+
+				(BeforeEntityTag, b'W') => WeakIndicator,
+
+				(BeforeEntityTag, b'"') => OpaqueTag { is_weak: false, length: 0, is_identical: true },
+
+				(WeakIndicator, b'/') => AfterWeakIndicator,
+
+				(AfterWeakIndicator, b'"') => OpaqueTag { is_weak: true, length: 0, is_identical: true },
+
+				(OpaqueTag { is_weak, length, is_identical }, b'"') =>
+				{
+					if is_identical && length == self.opaque_tag.len() && self.is_comparable_with(is_weak, comparison)
+					{
+						return Ok(true)
+					}
+					AfterEntityTag
+				}
+
+				(OpaqueTag { is_weak, length, is_identical }, 0x21) | (OpaqueTag { is_weak, length, is_identical }, 0x23 ... 0x7E) | (OpaqueTag { is_weak, length, is_identical }, 0x80 ... 0xFF) => OpaqueTag
+				{
+					is_weak,
+					length: length + 1,
+					is_identical: is_identical && self.opaque_tag.get(length) == Some(&byte),
+				},
+
+				(AfterEntityTag, b' ') | (AfterEntityTag, b'\t') => AfterEntityTag,
+
+				(AfterEntityTag, b',') => BeforeEntityTag,
+
+				_ => return Err(()),
+			}
+		}
+
+		match state
+		{
+			BeforeEntityTag | AfterEntityTag => Ok(false),
+
+			_ => Err(()),
+		}
+	}
+
+	#[inline(always)]
+	fn is_comparable_with(&self, other_is_weak: bool, comparison: EntityTagComparison) -> bool
+	{
+		match comparison
+		{
+			EntityTagComparison::Strong => !self.is_weak && !other_is_weak,
+
+			EntityTagComparison::Weak => true,
+		}
+	}
+}
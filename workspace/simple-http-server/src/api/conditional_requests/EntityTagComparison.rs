@@ -0,0 +1,18 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// How entity tags are compared (RFC 7232, Section 2.3.2).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EntityTagComparison
+{
+	/// Both entity tags must not be weak and their opaque tags must be identical.
+	///
+	/// Used for `If-Match` and `If-Range`.
+	Strong,
+
+	/// The opaque tags must be identical; either or both entity tags may be weak.
+	///
+	/// Used for `If-None-Match`.
+	Weak,
+}
@@ -0,0 +1,169 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A HTTP date, with a resolution of one second.
+///
+/// Used to compare the `If-Modified-Since`, `If-Unmodified-Since` and `If-Range` headers with the `Last-Modified` time of a representation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HttpDate
+{
+	seconds_since_unix_epoch: i64,
+}
+
+impl From<Tm> for HttpDate
+{
+	#[inline(always)]
+	fn from(time: Tm) -> Self
+	{
+		Self
+		{
+			seconds_since_unix_epoch: time.to_timespec().sec,
+		}
+	}
+}
+
+impl HttpDate
+{
+	/// Parses an IMF-fixdate (RFC 7231, Section 7.1.1.1), eg `Sun, 06 Nov 1994 08:49:37 GMT`.
+	///
+	/// The obsolete RFC 850 and ANSI C `asctime()` formats are not supported; as a recipient must ignore an invalid date in a conditional header (RFC 7232, Section 3.3), they are treated as if absent.
+	pub fn parse(field_value: &ImmutableVectoredBufferSlice) -> Option<Self>
+	{
+		let mut date: [u8; ImfFixdateLength] = unsafe { uninitialized() };
+		let mut length = 0;
+		for byte in field_value.bytes()
+		{
+			if unlikely!(length == ImfFixdateLength)
+			{
+				return None
+			}
+			unsafe { *date.get_unchecked_mut(length) = byte };
+			length += 1;
+		}
+
+		if length != ImfFixdateLength
+		{
+			return None
+		}
+
+		Self::parse_imf_fixdate(&date)
+	}
+
+	#[inline(always)]
+	fn parse_imf_fixdate(date: &[u8; ImfFixdateLength]) -> Option<Self>
+	{
+		match &date[0 .. 3]
+		{
+			b"Mon" | b"Tue" | b"Wed" | b"Thu" | b"Fri" | b"Sat" | b"Sun" => (),
+
+			_ => return None,
+		}
+
+		if &date[3 .. 5] != b", " || date[7] != b' ' || date[11] != b' ' || date[16] != b' ' || date[19] != b':' || date[22] != b':' || &date[25 .. ] != b" GMT"
+		{
+			return None
+		}
+
+		let month = match &date[8 .. 11]
+		{
+			b"Jan" => 1,
+			b"Feb" => 2,
+			b"Mar" => 3,
+			b"Apr" => 4,
+			b"May" => 5,
+			b"Jun" => 6,
+			b"Jul" => 7,
+			b"Aug" => 8,
+			b"Sep" => 9,
+			b"Oct" => 10,
+			b"Nov" => 11,
+			b"Dec" => 12,
+			_ => return None,
+		};
+
+		let day = Self::digits(&date[5 .. 7])?;
+		let year = Self::digits(&date[12 .. 16])?;
+		let hour = Self::digits(&date[17 .. 19])?;
+		let minute = Self::digits(&date[20 .. 22])?;
+		let second = Self::digits(&date[23 .. 25])?;
+
+		// NOTE: A second of 60 is permitted for leap seconds.
+		if day == 0 || day > Self::days_in_month(year, month) || hour > 23 || minute > 59 || second > 60
+		{
+			return None
+		}
+
+		Some
+		(
+			Self
+			{
+				seconds_since_unix_epoch: Self::days_since_unix_epoch(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second,
+			}
+		)
+	}
+
+	#[inline(always)]
+	fn digits(digits: &[u8]) -> Option<i64>
+	{
+		let mut value = 0;
+		for &digit in digits.iter()
+		{
+			match digit
+			{
+				b'0' ... b'9' => value = value * 10 + (digit - b'0') as i64,
+
+				_ => return None,
+			}
+		}
+		Some(value)
+	}
+
+	#[inline(always)]
+	fn days_in_month(year: i64, month: i64) -> i64
+	{
+		match month
+		{
+			2 => if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+			{
+				29
+			}
+			else
+			{
+				28
+			},
+
+			4 | 6 | 9 | 11 => 30,
+
+			_ => 31,
+		}
+	}
+
+	// Howard Hinnant's `days_from_civil()` algorithm; `year` is never negative as it has four digits.
+	#[inline(always)]
+	fn days_since_unix_epoch(year: i64, month: i64, day: i64) -> i64
+	{
+		let year = if month <= 2
+		{
+			year - 1
+		}
+		else
+		{
+			year
+		};
+
+		let era = if year >= 0
+		{
+			year
+		}
+		else
+		{
+			year - 399
+		} / 400;
+		let year_of_era = year - era * 400;
+		let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+		let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+		era * 146_097 + day_of_era - 719_468
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+use super::*;
+use super::response_buffers::header_response_buffers::time::ImfFixdateLength;
+
+
+#[cfg(test)] mod tests;
+
+
+include!("ConditionalRequest.rs");
+include!("ConditionalRequestOutcome.rs");
+include!("EntityTag.rs");
+include!("EntityTagComparison.rs");
+include!("EntityTagListParseState.rs");
+include!("HttpDate.rs");
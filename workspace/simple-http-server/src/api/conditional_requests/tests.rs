@@ -0,0 +1,179 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn parse(field_value: &[u8]) -> Option<HttpDate>
+{
+	with_immutable_vectored_buffer_slice(field_value, HttpDate::parse)
+}
+
+fn seconds_since_unix_epoch(field_value: &[u8]) -> Option<i64>
+{
+	parse(field_value).map(|http_date| http_date.seconds_since_unix_epoch)
+}
+
+const StrongEntityTag: EntityTag<'static> = EntityTag
+{
+	is_weak: false,
+	opaque_tag: b"xyzzy",
+};
+
+const WeakEntityTag: EntityTag<'static> = EntityTag
+{
+	is_weak: true,
+	opaque_tag: b"xyzzy",
+};
+
+fn is_matched_by(entity_tag: EntityTag, field_value: &[u8], comparison: EntityTagComparison) -> bool
+{
+	with_immutable_vectored_buffer_slice(field_value, |field_value| entity_tag.is_matched_by(field_value, comparison))
+}
+
+fn is_matched_by_any(entity_tag: EntityTag, field_values: &[&[u8]], comparison: EntityTagComparison) -> bool
+{
+	let mut input = Vec::new();
+	let mut offsets = Vec::with_capacity(field_values.len());
+	for field_value in field_values.iter()
+	{
+		let value_starts_at_inclusive = VectoredBufferOffset::new(BufferIndex::Minimum, input.len());
+		input.extend_from_slice(field_value);
+		let value_ends_at_exclusive = VectoredBufferOffset::new(BufferIndex::Minimum, input.len());
+		offsets.push((value_starts_at_inclusive, value_ends_at_exclusive));
+	}
+
+	let bytes = received(&input);
+	entity_tag.is_matched_by_any(bytes.vectored_buffer(), &offsets, comparison)
+}
+
+#[test]
+fn imf_fixdate_is_parsed()
+{
+	assert_eq!(seconds_since_unix_epoch(b"Sun, 06 Nov 1994 08:49:37 GMT"), Some(784_111_777));
+}
+
+#[test]
+fn unix_epoch_is_parsed()
+{
+	assert_eq!(seconds_since_unix_epoch(b"Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+}
+
+#[test]
+fn leap_days_are_parsed()
+{
+	assert_eq!(seconds_since_unix_epoch(b"Tue, 29 Feb 2000 00:00:00 GMT"), Some(951_782_400));
+	assert_eq!(seconds_since_unix_epoch(b"Sat, 29 Feb 2020 00:00:00 GMT"), Some(1_582_934_400));
+}
+
+#[test]
+fn leap_day_of_a_century_year_which_is_not_a_leap_year_is_rejected()
+{
+	assert_eq!(parse(b"Thu, 29 Feb 1900 00:00:00 GMT"), None);
+	assert_eq!(parse(b"Thu, 29 Feb 2018 00:00:00 GMT"), None);
+}
+
+#[test]
+fn leap_second_is_parsed()
+{
+	assert_eq!(seconds_since_unix_epoch(b"Sat, 31 Dec 2016 23:59:60 GMT"), Some(1_483_228_800));
+}
+
+#[test]
+fn later_dates_compare_greater()
+{
+	assert!(parse(b"Sun, 06 Nov 1994 08:49:38 GMT") > parse(b"Sun, 06 Nov 1994 08:49:37 GMT"));
+	assert!(parse(b"Mon, 01 Jan 2018 00:00:00 GMT") > parse(b"Sun, 31 Dec 2017 23:59:59 GMT"));
+}
+
+#[test]
+fn obsolete_rfc_850_date_is_not_supported()
+{
+	assert_eq!(parse(b"Sunday, 06-Nov-94 08:49:37 GMT"), None);
+}
+
+#[test]
+fn obsolete_asctime_date_is_not_supported()
+{
+	assert_eq!(parse(b"Sun Nov  6 08:49:37 1994"), None);
+}
+
+#[test]
+fn invalid_dates_are_rejected()
+{
+	assert_eq!(parse(b""), None);
+	assert_eq!(parse(b"Sun, 06 Nov 1994 08:49:37 GMT "), None);
+	assert_eq!(parse(b"Sun, 06 Nov 1994 08:49:37 UTC"), None);
+	assert_eq!(parse(b"Sun, 06 nov 1994 08:49:37 GMT"), None);
+	assert_eq!(parse(b"Xyz, 06 Nov 1994 08:49:37 GMT"), None);
+	assert_eq!(parse(b"Sun, 00 Nov 1994 08:49:37 GMT"), None);
+	assert_eq!(parse(b"Sun, 31 Nov 1994 08:49:37 GMT"), None);
+	assert_eq!(parse(b"Sun, 06 Nov 1994 24:00:00 GMT"), None);
+	assert_eq!(parse(b"Sun, 06 Nov 1994 08:60:37 GMT"), None);
+	assert_eq!(parse(b"Sun, 06 Nov 1994 08:49:61 GMT"), None);
+	assert_eq!(parse(b"Sun, 06 Nov 19x4 08:49:37 GMT"), None);
+}
+
+#[test]
+fn strong_comparison_matches_identical_strong_entity_tag()
+{
+	assert!(is_matched_by(StrongEntityTag, b"\"xyzzy\"", EntityTagComparison::Strong));
+}
+
+#[test]
+fn strong_comparison_does_not_match_weak_entity_tags()
+{
+	assert!(!is_matched_by(StrongEntityTag, b"W/\"xyzzy\"", EntityTagComparison::Strong));
+	assert!(!is_matched_by(WeakEntityTag, b"\"xyzzy\"", EntityTagComparison::Strong));
+	assert!(!is_matched_by(WeakEntityTag, b"W/\"xyzzy\"", EntityTagComparison::Strong));
+}
+
+#[test]
+fn weak_comparison_matches_weak_and_strong_entity_tags()
+{
+	assert!(is_matched_by(StrongEntityTag, b"W/\"xyzzy\"", EntityTagComparison::Weak));
+	assert!(is_matched_by(WeakEntityTag, b"\"xyzzy\"", EntityTagComparison::Weak));
+	assert!(is_matched_by(WeakEntityTag, b"W/\"xyzzy\"", EntityTagComparison::Weak));
+}
+
+#[test]
+fn different_opaque_tags_do_not_match()
+{
+	assert!(!is_matched_by(StrongEntityTag, b"\"xyzz\"", EntityTagComparison::Weak));
+	assert!(!is_matched_by(StrongEntityTag, b"\"xyzzyx\"", EntityTagComparison::Weak));
+	assert!(!is_matched_by(StrongEntityTag, b"\"XYZZY\"", EntityTagComparison::Weak));
+	assert!(!is_matched_by(StrongEntityTag, b"\"\"", EntityTagComparison::Weak));
+}
+
+#[test]
+fn entity_tag_in_a_list_matches()
+{
+	assert!(is_matched_by(StrongEntityTag, b"\"a\", W/\"b\",\t\"xyzzy\"", EntityTagComparison::Strong));
+	assert!(is_matched_by(StrongEntityTag, b",, \"xyzzy\" ,", EntityTagComparison::Strong));
+}
+
+#[test]
+fn invalid_entity_tag_list_matches_nothing()
+{
+	assert!(!is_matched_by(StrongEntityTag, b"xyzzy", EntityTagComparison::Weak));
+	assert!(!is_matched_by(StrongEntityTag, b"w/\"xyzzy\"", EntityTagComparison::Weak));
+	assert!(!is_matched_by(StrongEntityTag, b"\"xyz zy\"", EntityTagComparison::Weak));
+	assert!(!is_matched_by(StrongEntityTag, b"\"a\" \"xyzzy\"", EntityTagComparison::Weak));
+	assert!(!is_matched_by(StrongEntityTag, b"\"xyzzy", EntityTagComparison::Weak));
+}
+
+#[test]
+fn asterisk_matches_any_entity_tag()
+{
+	assert!(is_matched_by_any(StrongEntityTag, &[b"\"a\"", b"*"], EntityTagComparison::Strong));
+	assert!(!is_matched_by(StrongEntityTag, b"*", EntityTagComparison::Strong));
+}
+
+#[test]
+fn any_field_value_may_match()
+{
+	assert!(is_matched_by_any(StrongEntityTag, &[b"\"a\"", b"\"b\", \"xyzzy\""], EntityTagComparison::Strong));
+	assert!(!is_matched_by_any(StrongEntityTag, &[b"\"a\"", b"\"b\""], EntityTagComparison::Strong));
+	assert!(!is_matched_by_any(StrongEntityTag, &[], EntityTagComparison::Strong));
+}
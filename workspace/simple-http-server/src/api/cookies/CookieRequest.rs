@@ -0,0 +1,147 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Records the `Cookie` headers of a request (RFC 6265, Section 5.4) so that cookies can be found without copying them out of the request.
+///
+/// A `RequestHandler` should pass every call of `header_field()` to `header_field()` here, then use `find()` or `for_each()` from `request_finished()` and finally call `clear()`.
+///
+/// Parsing is lenient, as browsers are: whitespace around names and values is ignored, as are cookie pairs without a `=` or a name; a value enclosed in double quotes has them removed.
+/// Names and values are not decoded.
+#[derive(Default, Debug, Clone)]
+pub struct CookieRequest
+{
+	cookie: ArrayVec<[(VectoredBufferOffset, VectoredBufferOffset); 4]>,
+}
+
+impl CookieRequest
+{
+	/// Records the header if it is `Cookie`.
+	///
+	/// A HTTP/1.1 user agent sends only one `Cookie` header, but more are tolerated.
+	#[inline(always)]
+	pub fn header_field(&mut self, known_header: KnownHeader, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		match known_header
+		{
+			KnownHeader::Cookie => self.cookie.try_push((value_starts_at_inclusive, value_ends_at_exclusive)).map_err(|_| RequestHeaderFieldsTooLarge),
+
+			_ => Ok(()),
+		}
+	}
+
+	/// Finds the value of the first cookie called `name`; names are case-sensitive.
+	///
+	/// A user agent sends cookies with longer paths first (RFC 6265, Section 5.4), so the first is usually the most specific if there is more than one with the same name.
+	#[inline(always)]
+	pub fn find<'a>(&self, vectored_buffer: &'a VectoredBuffer, name: &[u8]) -> Option<ImmutableVectoredBufferSlice<'a>>
+	{
+		let mut found = None;
+
+		self.for_each(vectored_buffer, |cookie_name, cookie_value|
+		{
+			if cookie_name.bytes().eq(name.iter().cloned())
+			{
+				found = Some(cookie_value);
+				false
+			}
+			else
+			{
+				true
+			}
+		});
+
+		found
+	}
+
+	/// Calls `cookie_user` with the name and value of each cookie, in the order they were sent, until it returns `false`.
+	pub fn for_each<'a>(&self, vectored_buffer: &'a VectoredBuffer, mut cookie_user: impl FnMut(ImmutableVectoredBufferSlice<'a>, ImmutableVectoredBufferSlice<'a>) -> bool)
+	{
+		use self::CookieStringParseState::*;
+
+		for &(value_starts_at_inclusive, value_ends_at_exclusive) in self.cookie.iter()
+		{
+			let mut cookie_pair = |(name_starts_at_inclusive, name_ends_at_exclusive): (VectoredBufferOffset, VectoredBufferOffset), (cookie_value_starts_at_inclusive, cookie_value_ends_at_exclusive): (VectoredBufferOffset, VectoredBufferOffset)|
+			{
+				cookie_user(ImmutableVectoredBufferSlice::new(vectored_buffer, name_starts_at_inclusive, name_ends_at_exclusive), ImmutableVectoredBufferSlice::new(vectored_buffer, cookie_value_starts_at_inclusive, cookie_value_ends_at_exclusive))
+			};
+
+			let field_value = ImmutableVectoredBufferSlice::new(vectored_buffer, value_starts_at_inclusive, value_ends_at_exclusive);
+
+			let mut state = BeforeName;
+			for (offset, byte) in field_value.offsets_and_bytes()
+			{
+				state = match (state, byte)
+				{
+					(BeforeName, b' ') | (BeforeName, b'\t') | (BeforeName, b';') => BeforeName,
+
+					(BeforeName, b'=') => IgnoredCookiePair,
+
+					(BeforeName, _) => Name { starts_at_inclusive: offset, ends_at_exclusive: offset.increment_offset() },
+
+					(Name { starts_at_inclusive, ends_at_exclusive }, b'=') => BeforeValue { name: (starts_at_inclusive, ends_at_exclusive) },
+
+					(Name { .. }, b';') => BeforeName,
+
+					(Name { starts_at_inclusive, ends_at_exclusive }, b' ') | (Name { starts_at_inclusive, ends_at_exclusive }, b'\t') => Name { starts_at_inclusive, ends_at_exclusive },
+
+					(Name { starts_at_inclusive, .. }, _) => Name { starts_at_inclusive, ends_at_exclusive: offset.increment_offset() },
+
+					(BeforeValue { name }, b' ') | (BeforeValue { name }, b'\t') => BeforeValue { name },
+
+					(BeforeValue { name }, b';') =>
+					{
+						if !cookie_pair(name, (offset, offset))
+						{
+							return
+						}
+						BeforeName
+					}
+
+					(BeforeValue { name }, _) => CookieStringParseState::first_byte_of_value(name, offset, byte),
+
+					(value @ Value { .. }, b';') =>
+					{
+						let (name, value) = value.name_and_value();
+						if !cookie_pair(name, value)
+						{
+							return
+						}
+						BeforeName
+					}
+
+					(value @ Value { .. }, _) => value.subsequent_byte_of_value(offset, byte),
+
+					(IgnoredCookiePair, b';') => BeforeName,
+
+					(IgnoredCookiePair, _) => IgnoredCookiePair,
+				}
+			}
+
+			let continue_ = match state
+			{
+				BeforeValue { name } => cookie_pair(name, (value_ends_at_exclusive, value_ends_at_exclusive)),
+
+				value @ Value { .. } =>
+				{
+					let (name, value) = value.name_and_value();
+					cookie_pair(name, value)
+				}
+
+				_ => true,
+			};
+
+			if !continue_
+			{
+				return
+			}
+		}
+	}
+
+	/// Call after the cookies have been used so this instance can be re-used for the next request on the connection.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.cookie.clear();
+	}
+}
@@ -0,0 +1,97 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum CookieStringParseState
+{
+	BeforeName,
+
+	Name
+	{
+		starts_at_inclusive: VectoredBufferOffset,
+		ends_at_exclusive: VectoredBufferOffset,
+	},
+
+	BeforeValue
+	{
+		name: (VectoredBufferOffset, VectoredBufferOffset),
+	},
+
+	Value
+	{
+		name: (VectoredBufferOffset, VectoredBufferOffset),
+		starts_at_inclusive: VectoredBufferOffset,
+		first_byte_is_double_quote: bool,
+		second_byte_at: Option<VectoredBufferOffset>,
+		last_byte_at: VectoredBufferOffset,
+		last_byte_is_double_quote: bool,
+	},
+
+	IgnoredCookiePair,
+}
+
+impl CookieStringParseState
+{
+	#[inline(always)]
+	fn first_byte_of_value(name: (VectoredBufferOffset, VectoredBufferOffset), offset: VectoredBufferOffset, byte: u8) -> Self
+	{
+		CookieStringParseState::Value
+		{
+			name,
+			starts_at_inclusive: offset,
+			first_byte_is_double_quote: byte == b'"',
+			second_byte_at: None,
+			last_byte_at: offset,
+			last_byte_is_double_quote: byte == b'"',
+		}
+	}
+
+	// Whitespace is only part of a value if it is followed by something other than whitespace.
+	#[inline(always)]
+	fn subsequent_byte_of_value(self, offset: VectoredBufferOffset, byte: u8) -> Self
+	{
+		use self::CookieStringParseState::*;
+
+		match self
+		{
+			Value { name, starts_at_inclusive, first_byte_is_double_quote, second_byte_at, last_byte_at, last_byte_is_double_quote } =>
+			{
+				let second_byte_at = second_byte_at.or(Some(offset));
+
+				match byte
+				{
+					b' ' | b'\t' => Value { name, starts_at_inclusive, first_byte_is_double_quote, second_byte_at, last_byte_at, last_byte_is_double_quote },
+
+					_ => Value { name, starts_at_inclusive, first_byte_is_double_quote, second_byte_at, last_byte_at: offset, last_byte_is_double_quote: byte == b'"' },
+				}
+			}
+
+			_ => unreachable!(),
+		}
+	}
+
+	#[inline(always)]
+	fn name_and_value(self) -> ((VectoredBufferOffset, VectoredBufferOffset), (VectoredBufferOffset, VectoredBufferOffset))
+	{
+		use self::CookieStringParseState::*;
+
+		match self
+		{
+			Value { name, starts_at_inclusive, first_byte_is_double_quote, second_byte_at, last_byte_at, last_byte_is_double_quote } =>
+			{
+				// A value of a single double quote is not quoted.
+				let value = match second_byte_at
+				{
+					Some(second_byte_at) if first_byte_is_double_quote && last_byte_is_double_quote && last_byte_at != starts_at_inclusive => (second_byte_at, last_byte_at),
+
+					_ => (starts_at_inclusive, last_byte_at.increment_offset()),
+				};
+
+				(name, value)
+			}
+
+			_ => unreachable!(),
+		}
+	}
+}
@@ -0,0 +1,131 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn cookie_request(field_values: &[&[u8]]) -> (Bytes, CookieRequest)
+{
+	let mut input = Vec::new();
+	let mut cookie_request = CookieRequest::default();
+	for field_value in field_values.iter()
+	{
+		let value_starts_at_inclusive = VectoredBufferOffset::new(BufferIndex::Minimum, input.len());
+		input.extend_from_slice(field_value);
+		let value_ends_at_exclusive = VectoredBufferOffset::new(BufferIndex::Minimum, input.len());
+		cookie_request.header_field(KnownHeader::Cookie, value_starts_at_inclusive, value_ends_at_exclusive).unwrap();
+	}
+
+	(received(&input), cookie_request)
+}
+
+fn cookies(field_values: &[&[u8]]) -> Vec<(Vec<u8>, Vec<u8>)>
+{
+	let (bytes, cookie_request) = cookie_request(field_values);
+
+	let mut cookies = Vec::new();
+	cookie_request.for_each(bytes.vectored_buffer(), |name, value|
+	{
+		cookies.push((name.bytes().collect(), value.bytes().collect()));
+		true
+	});
+	cookies
+}
+
+fn cookie(name: &[u8], value: &[u8]) -> (Vec<u8>, Vec<u8>)
+{
+	(name.to_vec(), value.to_vec())
+}
+
+fn find(field_values: &[&[u8]], name: &[u8]) -> Option<Vec<u8>>
+{
+	let (bytes, cookie_request) = cookie_request(field_values);
+	cookie_request.find(bytes.vectored_buffer(), name).map(|value| value.bytes().collect())
+}
+
+#[test]
+fn cookie_pairs_are_found_in_the_order_sent()
+{
+	assert_eq!(cookies(&[b"session=38afes7a8; theme=dark"]), vec![cookie(b"session", b"38afes7a8"), cookie(b"theme", b"dark")]);
+}
+
+#[test]
+fn whitespace_around_names_and_values_is_ignored()
+{
+	assert_eq!(cookies(&[b" a = 1 ;\tb=x y\t; ;c=3"]), vec![cookie(b"a", b"1"), cookie(b"b", b"x y"), cookie(b"c", b"3")]);
+}
+
+#[test]
+fn double_quotes_around_a_value_are_removed()
+{
+	assert_eq!(cookies(&[b"a=\"1\"; b=\"\"; c=\"; d=\"x; e=x\""]), vec![cookie(b"a", b"1"), cookie(b"b", b""), cookie(b"c", b"\""), cookie(b"d", b"\"x"), cookie(b"e", b"x\"")]);
+}
+
+#[test]
+fn empty_values_are_found()
+{
+	assert_eq!(cookies(&[b"a=; b= ;c="]), vec![cookie(b"a", b""), cookie(b"b", b""), cookie(b"c", b"")]);
+}
+
+#[test]
+fn cookie_pairs_without_a_name_or_equals_sign_are_ignored()
+{
+	assert_eq!(cookies(&[b"a; =1; b=2; c"]), vec![cookie(b"b", b"2")]);
+}
+
+#[test]
+fn cookies_in_more_than_one_header_are_found()
+{
+	assert_eq!(cookies(&[b"a=1", b"b=2; c=3"]), vec![cookie(b"a", b"1"), cookie(b"b", b"2"), cookie(b"c", b"3")]);
+}
+
+#[test]
+fn find_returns_the_first_cookie_with_the_name()
+{
+	assert_eq!(find(&[b"a=1; b=2; a=3"], b"a"), Some(b"1".to_vec()));
+	assert_eq!(find(&[b"a=1", b"b=2"], b"b"), Some(b"2".to_vec()));
+}
+
+#[test]
+fn find_is_case_sensitive()
+{
+	assert_eq!(find(&[b"a=1"], b"A"), None);
+	assert_eq!(find(&[b"ab=1"], b"a"), None);
+	assert_eq!(find(&[], b"a"), None);
+}
+
+#[test]
+fn for_each_stops_when_the_cookie_user_returns_false()
+{
+	let (bytes, cookie_request) = cookie_request(&[b"a=1; b=2", b"c=3"]);
+
+	let mut names = Vec::new();
+	cookie_request.for_each(bytes.vectored_buffer(), |name, _value|
+	{
+		names.push(name.bytes().collect::<Vec<u8>>());
+		false
+	});
+	assert_eq!(names, vec![b"a".to_vec()]);
+}
+
+#[test]
+fn other_headers_are_ignored()
+{
+	let mut cookie_request = CookieRequest::default();
+	let offset = VectoredBufferOffset::new(BufferIndex::Minimum, 0);
+	assert_eq!(cookie_request.header_field(KnownHeader::Host, offset, offset), Ok(()));
+	assert!(cookie_request.cookie.is_empty());
+}
+
+#[test]
+fn too_many_cookie_headers_are_rejected()
+{
+	let mut cookie_request = CookieRequest::default();
+	let offset = VectoredBufferOffset::new(BufferIndex::Minimum, 0);
+	for _ in 0 .. 4
+	{
+		assert_eq!(cookie_request.header_field(KnownHeader::Cookie, offset, offset), Ok(()));
+	}
+	assert_eq!(cookie_request.header_field(KnownHeader::Cookie, offset, offset), Err(RequestHeaderFieldsTooLarge));
+}
@@ -0,0 +1,37 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A content coding (RFC 7231, Section 3.1.2.1) which can be used to compress a response body.
+///
+/// Ordered by preference, most preferred first.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ContentCoding
+{
+	/// `br`, Brotli (RFC 7932).
+	br,
+
+	/// `gzip` (RFC 1952).
+	gzip,
+
+	/// `deflate`; this is the zlib format (RFC 1950), not a raw deflate stream, although some very old user agents mistakenly expected the latter.
+	deflate,
+}
+
+impl ContentCoding
+{
+	#[inline(always)]
+	pub(crate) fn buffer(&self) -> &'static [u8]
+	{
+		use self::ContentCoding::*;
+
+		match *self
+		{
+			br => b"br",
+
+			gzip => b"gzip",
+
+			deflate => b"deflate",
+		}
+	}
+}
@@ -0,0 +1,95 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A directive of a `Content-Security-Policy` header which takes a list of sources (Content Security Policy Level 3, Section 6).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ContentSecurityPolicyDirective
+{
+	/// `default-src`; the fallback for the other fetch directives.
+	default_src,
+
+	/// `child-src`.
+	child_src,
+
+	/// `connect-src`.
+	connect_src,
+
+	/// `font-src`.
+	font_src,
+
+	/// `frame-src`.
+	frame_src,
+
+	/// `img-src`.
+	img_src,
+
+	/// `manifest-src`.
+	manifest_src,
+
+	/// `media-src`.
+	media_src,
+
+	/// `object-src`.
+	object_src,
+
+	/// `script-src`.
+	script_src,
+
+	/// `style-src`.
+	style_src,
+
+	/// `worker-src`.
+	worker_src,
+
+	/// `base-uri`.
+	base_uri,
+
+	/// `form-action`.
+	form_action,
+
+	/// `frame-ancestors`; supersedes `X-Frame-Options`.
+	frame_ancestors,
+}
+
+impl ContentSecurityPolicyDirective
+{
+	#[inline(always)]
+	pub(crate) fn buffer(&self) -> &'static [u8]
+	{
+		use self::ContentSecurityPolicyDirective::*;
+
+		match *self
+		{
+			default_src => b"default-src",
+
+			child_src => b"child-src",
+
+			connect_src => b"connect-src",
+
+			font_src => b"font-src",
+
+			frame_src => b"frame-src",
+
+			img_src => b"img-src",
+
+			manifest_src => b"manifest-src",
+
+			media_src => b"media-src",
+
+			object_src => b"object-src",
+
+			script_src => b"script-src",
+
+			style_src => b"style-src",
+
+			worker_src => b"worker-src",
+
+			base_uri => b"base-uri",
+
+			form_action => b"form-action",
+
+			frame_ancestors => b"frame-ancestors",
+		}
+	}
+}
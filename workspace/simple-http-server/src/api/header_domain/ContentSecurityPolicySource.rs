@@ -0,0 +1,132 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A source in the list of sources of a `ContentSecurityPolicyDirective` (Content Security Policy Level 3, Section 2.3.1).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ContentSecurityPolicySource
+{
+	/// `'none'`; must be the only source.
+	none,
+
+	/// `'self'`.
+	self_,
+
+	/// `'unsafe-inline'`.
+	unsafe_inline,
+
+	/// `'unsafe-eval'`.
+	unsafe_eval,
+
+	/// `'strict-dynamic'`.
+	strict_dynamic,
+
+	/// `'nonce-<base64-value>'`; the nonce must be freshly generated for each response.
+	nonce(Cow<'static, [u8]>),
+
+	/// `'sha256-<base64-value>'`.
+	sha256(Cow<'static, [u8]>),
+
+	/// `'sha384-<base64-value>'`.
+	sha384(Cow<'static, [u8]>),
+
+	/// `'sha512-<base64-value>'`.
+	sha512(Cow<'static, [u8]>),
+
+	/// A scheme, excluding the trailing colon, eg `https`.
+	scheme(Cow<'static, [u8]>),
+
+	/// A host source, eg `https://*.example.com:443/path`.
+	host(Cow<'static, [u8]>),
+}
+
+impl ContentSecurityPolicySource
+{
+	/// Appends this source, validating any value.
+	pub(crate) fn append_to(&self, buffer: &mut Vec<u8>) -> Result<(), HeaderResponseBufferError>
+	{
+		use self::ContentSecurityPolicySource::*;
+
+		match *self
+		{
+			none => buffer.extend_from_slice(b"'none'"),
+
+			self_ => buffer.extend_from_slice(b"'self'"),
+
+			unsafe_inline => buffer.extend_from_slice(b"'unsafe-inline'"),
+
+			unsafe_eval => buffer.extend_from_slice(b"'unsafe-eval'"),
+
+			strict_dynamic => buffer.extend_from_slice(b"'strict-dynamic'"),
+
+			nonce(ref value) => Self::append_base64_value(buffer, b"'nonce-", value)?,
+
+			sha256(ref value) => Self::append_base64_value(buffer, b"'sha256-", value)?,
+
+			sha384(ref value) => Self::append_base64_value(buffer, b"'sha384-", value)?,
+
+			sha512(ref value) => Self::append_base64_value(buffer, b"'sha512-", value)?,
+
+			scheme(ref value) =>
+			{
+				// scheme = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." ) (RFC 3986, Section 3.1).
+				let is_valid = match value.split_first()
+				{
+					None => false,
+
+					Some((first, remainder)) => first.is_ascii_alphabetic() && remainder.iter().all(|&byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'-' || byte == b'.'),
+				};
+
+				if !is_valid
+				{
+					return Err(HeaderResponseBufferError::InvalidContentSecurityPolicySource)
+				}
+
+				buffer.extend_from_slice(value);
+				buffer.push(b':');
+			}
+
+			host(ref value) =>
+			{
+				// Quotes would make a host source indistinguishable from a keyword, and whitespace, commas and semicolons delimit sources, directives and policies respectively.
+				if value.is_empty() || value.iter().any(|&byte| byte <= b' ' || byte >= 0x7F || byte == b'\'' || byte == b',' || byte == b';')
+				{
+					return Err(HeaderResponseBufferError::InvalidContentSecurityPolicySource)
+				}
+
+				buffer.extend_from_slice(value);
+			}
+		}
+
+		Ok(())
+	}
+
+	// base64-value = 1*( ALPHA / DIGIT / "+" / "/" / "-" / "_" )*2( "=" )
+	#[inline(always)]
+	fn append_base64_value(buffer: &mut Vec<u8>, prefix: &[u8], value: &[u8]) -> Result<(), HeaderResponseBufferError>
+	{
+		let without_padding = match value.iter().position(|&byte| byte == b'=')
+		{
+			None => value,
+
+			Some(index) => if value.len() - index <= 2 && value[index .. ].iter().all(|&byte| byte == b'=')
+			{
+				&value[ .. index]
+			}
+			else
+			{
+				return Err(HeaderResponseBufferError::InvalidContentSecurityPolicySource)
+			},
+		};
+
+		if without_padding.is_empty() || !without_padding.iter().all(|&byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'/' || byte == b'-' || byte == b'_')
+		{
+			return Err(HeaderResponseBufferError::InvalidContentSecurityPolicySource)
+		}
+
+		buffer.extend_from_slice(prefix);
+		buffer.extend_from_slice(value);
+		buffer.push(b'\'');
+		Ok(())
+	}
+}
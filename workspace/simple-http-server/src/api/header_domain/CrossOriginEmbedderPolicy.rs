@@ -0,0 +1,35 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A policy for a `Cross-Origin-Embedder-Policy` header (HTML Living Standard, Section 7.1.4).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CrossOriginEmbedderPolicy
+{
+	/// `unsafe-none`; the default.
+	unsafe_none,
+
+	/// `require-corp`; cross-origin resources must opt in with a `Cross-Origin-Resource-Policy` header or CORS.
+	require_corp,
+
+	/// `credentialless`; cross-origin resources are requested without credentials.
+	credentialless,
+}
+
+impl CrossOriginEmbedderPolicy
+{
+	#[inline(always)]
+	pub(crate) fn buffer(&self) -> &'static [u8]
+	{
+		use self::CrossOriginEmbedderPolicy::*;
+
+		match *self
+		{
+			unsafe_none => b"unsafe-none",
+
+			require_corp => b"require-corp",
+
+			credentialless => b"credentialless",
+		}
+	}
+}
@@ -0,0 +1,35 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A policy for a `Cross-Origin-Opener-Policy` header (HTML Living Standard, Section 7.1.3).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CrossOriginOpenerPolicy
+{
+	/// `unsafe-none`; the default.
+	unsafe_none,
+
+	/// `same-origin-allow-popups`.
+	same_origin_allow_popups,
+
+	/// `same-origin`; needed, along with `CrossOriginEmbedderPolicy::require_corp`, for a document to be cross-origin isolated.
+	same_origin,
+}
+
+impl CrossOriginOpenerPolicy
+{
+	#[inline(always)]
+	pub(crate) fn buffer(&self) -> &'static [u8]
+	{
+		use self::CrossOriginOpenerPolicy::*;
+
+		match *self
+		{
+			unsafe_none => b"unsafe-none",
+
+			same_origin_allow_popups => b"same-origin-allow-popups",
+
+			same_origin => b"same-origin",
+		}
+	}
+}
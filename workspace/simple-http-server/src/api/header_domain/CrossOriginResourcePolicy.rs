@@ -0,0 +1,35 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A policy for a `Cross-Origin-Resource-Policy` header (Fetch Living Standard, Section 3.7).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CrossOriginResourcePolicy
+{
+	/// `same-site`.
+	same_site,
+
+	/// `same-origin`.
+	same_origin,
+
+	/// `cross-origin`.
+	cross_origin,
+}
+
+impl CrossOriginResourcePolicy
+{
+	#[inline(always)]
+	pub(crate) fn buffer(&self) -> &'static [u8]
+	{
+		use self::CrossOriginResourcePolicy::*;
+
+		match *self
+		{
+			same_site => b"same-site",
+
+			same_origin => b"same-origin",
+
+			cross_origin => b"cross-origin",
+		}
+	}
+}
@@ -23,6 +23,13 @@ impl HeaderName
 		leading_comma_then_name: b",*",
 	};
 
+	/// Represents the header name `Accept-Encoding`, which a response varies by if it might be compressed.
+	pub const AcceptEncoding: Self = HeaderName
+	{
+		name: b"Accept-Encoding",
+		leading_comma_then_name: b",Accept-Encoding",
+	};
+
 	/// Is the wildcard (asterisk) header name?
 	#[inline(always)]
 	pub fn is_wildcard(&self) -> bool
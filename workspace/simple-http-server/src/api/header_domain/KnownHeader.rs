@@ -0,0 +1,215 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A well-known request header, recognised by the request parser so that users do not need to compare header names themselves.
+///
+/// Header names are case-insensitive (RFC 7230, Section 3.2).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KnownHeader
+{
+	/// `Accept` (RFC 7231, Section 5.3.2).
+	Accept,
+
+	/// `Accept-Encoding` (RFC 7231, Section 5.3.4).
+	AcceptEncoding,
+
+	/// `Accept-Language` (RFC 7231, Section 5.3.5).
+	AcceptLanguage,
+
+	/// `Access-Control-Request-Headers` of a CORS preflight request (Fetch Standard, Section 3.2.2).
+	AccessControlRequestHeaders,
+
+	/// `Access-Control-Request-Method` of a CORS preflight request (Fetch Standard, Section 3.2.2).
+	AccessControlRequestMethod,
+
+	/// `Authorization` (RFC 7235, Section 4.2).
+	Authorization,
+
+	/// `Connection` (RFC 7230, Section 6.1).
+	Connection,
+
+	/// `Content-Encoding` (RFC 7231, Section 3.1.2.2).
+	ContentEncoding,
+
+	/// `Content-Length` (RFC 7230, Section 3.3.2).
+	ContentLength,
+
+	/// `Content-Type` (RFC 7231, Section 3.1.1.5).
+	ContentType,
+
+	/// `Cookie` (RFC 6265, Section 4.2).
+	Cookie,
+
+	/// `Expect` (RFC 7231, Section 5.1.1).
+	Expect,
+
+	/// `Host` (RFC 7230, Section 5.4).
+	Host,
+
+	/// `If-Match` (RFC 7232, Section 3.1).
+	IfMatch,
+
+	/// `If-Modified-Since` (RFC 7232, Section 3.3).
+	IfModifiedSince,
+
+	/// `If-None-Match` (RFC 7232, Section 3.2).
+	IfNoneMatch,
+
+	/// `If-Range` (RFC 7233, Section 3.2).
+	IfRange,
+
+	/// `If-Unmodified-Since` (RFC 7232, Section 3.4).
+	IfUnmodifiedSince,
+
+	/// `Last-Event-ID` of a server-sent events reconnection (HTML Standard, Section 9.2).
+	LastEventId,
+
+	/// `Origin` (RFC 6454, Section 7).
+	Origin,
+
+	/// `Range` (RFC 7233, Section 3.1).
+	Range,
+
+	/// `Referer` (RFC 7231, Section 5.5.2).
+	Referer,
+
+	/// `TE` (RFC 7230, Section 4.3).
+	TE,
+
+	/// `Trailer` (RFC 7230, Section 4.4).
+	Trailer,
+
+	/// `Transfer-Encoding` (RFC 7230, Section 3.3.1).
+	TransferEncoding,
+
+	/// `Upgrade` (RFC 7230, Section 6.7).
+	Upgrade,
+
+	/// `User-Agent` (RFC 7231, Section 5.5.3).
+	UserAgent,
+
+	/// Any header not listed above.
+	Other,
+}
+
+impl KnownHeader
+{
+	/// Length of the longest known header name, `Access-Control-Request-Headers`.
+	pub(crate) const MaximumNameLength: usize = 30;
+
+	/// Classifies a header name.
+	///
+	/// Matches first on length and then on the first byte, so that at most one case-insensitive comparison is made.
+	pub fn classify(name: &[u8]) -> Self
+	{
+		use self::KnownHeader::*;
+
+		let length = name.len();
+		if unlikely!(length == 0 || length > Self::MaximumNameLength)
+		{
+			return Other
+		}
+
+		#[inline(always)]
+		fn is(name: &[u8], known_name: &'static [u8], known_header: KnownHeader) -> KnownHeader
+		{
+			if name.eq_ignore_ascii_case(known_name)
+			{
+				known_header
+			}
+			else
+			{
+				Other
+			}
+		}
+
+		match (length, name[0].to_ascii_lowercase())
+		{
+			(2, b't') => is(name, b"TE", TE),
+
+			(4, b'h') => is(name, b"Host", Host),
+
+			(5, b'r') => is(name, b"Range", Range),
+
+			(6, b'a') => is(name, b"Accept", Accept),
+
+			(6, b'c') => is(name, b"Cookie", Cookie),
+
+			(6, b'e') => is(name, b"Expect", Expect),
+
+			(6, b'o') => is(name, b"Origin", Origin),
+
+			(7, b'r') => is(name, b"Referer", Referer),
+
+			(7, b't') => is(name, b"Trailer", Trailer),
+
+			(7, b'u') => is(name, b"Upgrade", Upgrade),
+
+			(8, b'i') => match name[3].to_ascii_lowercase()
+			{
+				b'm' => is(name, b"If-Match", IfMatch),
+
+				b'r' => is(name, b"If-Range", IfRange),
+
+				_ => Other,
+			},
+
+			(10, b'c') => is(name, b"Connection", Connection),
+
+			(10, b'u') => is(name, b"User-Agent", UserAgent),
+
+			(12, b'c') => is(name, b"Content-Type", ContentType),
+
+			(13, b'a') => is(name, b"Authorization", Authorization),
+
+			(13, b'i') => is(name, b"If-None-Match", IfNoneMatch),
+
+			(13, b'l') => is(name, b"Last-Event-ID", LastEventId),
+
+			(14, b'c') => is(name, b"Content-Length", ContentLength),
+
+			(15, b'a') => match name[7].to_ascii_lowercase()
+			{
+				b'e' => is(name, b"Accept-Encoding", AcceptEncoding),
+
+				b'l' => is(name, b"Accept-Language", AcceptLanguage),
+
+				_ => Other,
+			},
+
+			(16, b'c') => is(name, b"Content-Encoding", ContentEncoding),
+
+			(17, b'i') => is(name, b"If-Modified-Since", IfModifiedSince),
+
+			(17, b't') => is(name, b"Transfer-Encoding", TransferEncoding),
+
+			(19, b'i') => is(name, b"If-Unmodified-Since", IfUnmodifiedSince),
+
+			(29, b'a') => is(name, b"Access-Control-Request-Method", AccessControlRequestMethod),
+
+			(30, b'a') => is(name, b"Access-Control-Request-Headers", AccessControlRequestHeaders),
+
+			_ => Other,
+		}
+	}
+
+	/// Classifies a header name which may span more than one buffer.
+	#[inline(always)]
+	pub fn classify_vectored(vectored_buffer: &VectoredBuffer, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset) -> Self
+	{
+		let mut name: [u8; Self::MaximumNameLength] = unsafe { uninitialized() };
+		let mut length = 0;
+		for byte in ImmutableVectoredBufferSlice::new(vectored_buffer, name_starts_at_inclusive, name_ends_at_exclusive).bytes()
+		{
+			if unlikely!(length == Self::MaximumNameLength)
+			{
+				return KnownHeader::Other
+			}
+			unsafe { *name.get_unchecked_mut(length) = byte };
+			length += 1;
+		}
+
+		Self::classify(&name[.. length])
+	}
+}
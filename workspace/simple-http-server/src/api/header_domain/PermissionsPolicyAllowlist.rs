@@ -0,0 +1,76 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// The origins allowed to use a feature in a `Permissions-Policy` header (W3C Permissions Policy, Section 5.2).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PermissionsPolicyAllowlist
+{
+	/// `()`; the feature is disabled.
+	none,
+
+	/// `*`; the feature is allowed for all origins.
+	all,
+
+	/// `self`; the feature is allowed for the same origin only.
+	self_,
+
+	/// The feature is allowed for each of `origins`, eg `https://example.com`, and the same origin if `include_self`.
+	origins
+	{
+		/// Also allow the same origin?
+		include_self: bool,
+
+		/// Serialized origins, without quotes.
+		origins: Vec<Cow<'static, [u8]>>,
+	},
+}
+
+impl PermissionsPolicyAllowlist
+{
+	/// Appends this allowlist as a Structured Field (RFC 8941) inner list or token, validating any origins.
+	pub(crate) fn append_to(&self, buffer: &mut Vec<u8>) -> Result<(), HeaderResponseBufferError>
+	{
+		use self::PermissionsPolicyAllowlist::*;
+
+		match *self
+		{
+			none => buffer.extend_from_slice(b"()"),
+
+			all => buffer.push(b'*'),
+
+			self_ => buffer.extend_from_slice(b"self"),
+
+			origins { include_self, ref origins } =>
+			{
+				buffer.push(b'(');
+
+				if include_self
+				{
+					buffer.extend_from_slice(b"self");
+				}
+
+				for (index, origin) in origins.iter().enumerate()
+				{
+					// A Structured Field string may only contain printable ASCII; quotes and backslashes would need escaping, and are never part of a serialized origin.
+					if origin.is_empty() || origin.iter().any(|&byte| byte <= b' ' || byte >= 0x7F || byte == b'"' || byte == b'\\')
+					{
+						return Err(HeaderResponseBufferError::InvalidPermissionsPolicyOrigin)
+					}
+
+					if include_self || index != 0
+					{
+						buffer.push(b' ');
+					}
+					buffer.push(b'"');
+					buffer.extend_from_slice(origin);
+					buffer.push(b'"');
+				}
+
+				buffer.push(b')');
+			}
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,69 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A policy for a `Referrer-Policy` header (W3C Referrer Policy, Section 3).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReferrerPolicy
+{
+	/// `no-referrer`.
+	no_referrer,
+
+	/// `no-referrer-when-downgrade`.
+	no_referrer_when_downgrade,
+
+	/// `origin`.
+	origin,
+
+	/// `origin-when-cross-origin`.
+	origin_when_cross_origin,
+
+	/// `same-origin`.
+	same_origin,
+
+	/// `strict-origin`.
+	strict_origin,
+
+	/// `strict-origin-when-cross-origin`; the default in modern browsers.
+	strict_origin_when_cross_origin,
+
+	/// `unsafe-url`.
+	unsafe_url,
+}
+
+impl Default for ReferrerPolicy
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		ReferrerPolicy::strict_origin_when_cross_origin
+	}
+}
+
+impl ReferrerPolicy
+{
+	#[inline(always)]
+	pub(crate) fn buffer(&self) -> &'static [u8]
+	{
+		use self::ReferrerPolicy::*;
+
+		match *self
+		{
+			no_referrer => b"no-referrer",
+
+			no_referrer_when_downgrade => b"no-referrer-when-downgrade",
+
+			origin => b"origin",
+
+			origin_when_cross_origin => b"origin-when-cross-origin",
+
+			same_origin => b"same-origin",
+
+			strict_origin => b"strict-origin",
+
+			strict_origin_when_cross_origin => b"strict-origin-when-cross-origin",
+
+			unsafe_url => b"unsafe-url",
+		}
+	}
+}
@@ -28,7 +28,7 @@ pub enum RequestMethod
 impl RequestMethod
 {
 	#[inline(always)]
-	fn buffer(self) -> &'static [u8]
+	pub(crate) fn buffer(self) -> &'static [u8]
 	{
 		use self::RequestMethod::*;
 
@@ -44,18 +44,14 @@ impl RequestMethod
 
 			DELETE => b"DELETE",
 
-			CONNECT => b"CONNECT",
-
 			OPTIONS => b"OPTIONS",
 
-			TRACE => b"TRACE",
-
 			PATCH => b"PATCH",
 		}
 	}
 
 	#[inline(always)]
-	fn with_leading_comma_buffer(self) -> &'static [u8]
+	pub(crate) fn with_leading_comma_buffer(self) -> &'static [u8]
 	{
 		use self::RequestMethod::*;
 
@@ -71,12 +67,8 @@ impl RequestMethod
 
 			DELETE => b",DELETE",
 
-			CONNECT => b",CONNECT",
-
 			OPTIONS => b",OPTIONS",
 
-			TRACE => b",TRACE",
-
 			PATCH => b",PATCH",
 		}
 	}
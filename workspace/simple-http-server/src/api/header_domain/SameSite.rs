@@ -0,0 +1,44 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A value for the `SameSite` attribute of a `Set-Cookie` header (RFC 6265bis, Section 4.1.2.7).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SameSite
+{
+	/// `Strict`; the cookie is only sent with same-site requests.
+	Strict,
+
+	/// `Lax`; the cookie is also sent with cross-site top-level navigations using a safe method, eg `GET`; the default in modern browsers.
+	Lax,
+
+	/// `None`; the cookie is sent with cross-site requests; browsers require the `Secure` attribute as well.
+	None,
+}
+
+impl Default for SameSite
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		SameSite::Lax
+	}
+}
+
+impl SameSite
+{
+	#[inline(always)]
+	pub(crate) fn buffer(&self) -> &'static [u8]
+	{
+		use self::SameSite::*;
+
+		match *self
+		{
+			Strict => b"Strict",
+
+			Lax => b"Lax",
+
+			None => b"None",
+		}
+	}
+}
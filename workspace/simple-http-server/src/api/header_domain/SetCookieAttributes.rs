@@ -0,0 +1,55 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// The attributes of a `Set-Cookie` header (RFC 6265, Section 4.1.1).
+///
+/// The default is a session cookie which is `Secure`, `HttpOnly` and `SameSite=Lax`, and which is sent for the path of the request which set it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetCookieAttributes
+{
+	/// `Secure`; the cookie is only sent over a secure connection.
+	///
+	/// Required for a cookie name with a `__Secure-` or `__Host-` prefix and for `SameSite=None`.
+	pub secure: bool,
+
+	/// `HttpOnly`; the cookie is not available to scripts.
+	pub http_only: bool,
+
+	/// `SameSite`, if any.
+	pub same_site: Option<SameSite>,
+
+	/// `Max-Age`, in seconds; takes precedence over `expires`; zero expires the cookie immediately.
+	pub maximum_age_in_seconds: Option<u64>,
+
+	/// `Expires`, assumed to be UTC.
+	pub expires: Option<Tm>,
+
+	/// `Path`, if any; otherwise the cookie is sent for the 'directory' of the request's path.
+	///
+	/// Must be `/` for a cookie name with a `__Host-` prefix.
+	pub path: Option<Cow<'static, [u8]>>,
+
+	/// `Domain`, if any; otherwise the cookie is only sent to the host which set it.
+	///
+	/// Must be absent for a cookie name with a `__Host-` prefix.
+	pub domain: Option<Cow<'static, [u8]>>,
+}
+
+impl Default for SetCookieAttributes
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			secure: true,
+			http_only: true,
+			same_site: Some(SameSite::default()),
+			maximum_age_in_seconds: None,
+			expires: None,
+			path: None,
+			domain: None,
+		}
+	}
+}
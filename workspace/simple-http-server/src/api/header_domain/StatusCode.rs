@@ -0,0 +1,553 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A status code registered with IANA in the HTTP Status Code Registry, with its reason phrase.
+///
+/// The codes `306` and `418` are reserved rather than registered, so are absent.
+#[repr(u16)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StatusCode
+{
+	/// `100 Continue` (RFC 7231, Section 6.2.1).
+	Continue = 100,
+
+	/// `101 Switching Protocols` (RFC 7231, Section 6.2.2).
+	SwitchingProtocols = 101,
+
+	/// `102 Processing` (RFC 2518, Section 10.1).
+	Processing = 102,
+
+	/// `103 Early Hints` (RFC 8297).
+	EarlyHints = 103,
+
+	/// `200 OK` (RFC 7231, Section 6.3.1).
+	Ok = 200,
+
+	/// `201 Created` (RFC 7231, Section 6.3.2).
+	Created = 201,
+
+	/// `202 Accepted` (RFC 7231, Section 6.3.3).
+	Accepted = 202,
+
+	/// `203 Non-Authoritative Information` (RFC 7231, Section 6.3.4).
+	NonAuthoritativeInformation = 203,
+
+	/// `204 No Content` (RFC 7231, Section 6.3.5).
+	NoContent = 204,
+
+	/// `205 Reset Content` (RFC 7231, Section 6.3.6).
+	ResetContent = 205,
+
+	/// `206 Partial Content` (RFC 7233, Section 4.1).
+	PartialContent = 206,
+
+	/// `207 Multi-Status` (RFC 4918, Section 11.1).
+	MultiStatus = 207,
+
+	/// `208 Already Reported` (RFC 5842, Section 7.1).
+	AlreadyReported = 208,
+
+	/// `226 IM Used` (RFC 3229, Section 10.4.1).
+	ImUsed = 226,
+
+	/// `300 Multiple Choices` (RFC 7231, Section 6.4.1).
+	MultipleChoices = 300,
+
+	/// `301 Moved Permanently` (RFC 7231, Section 6.4.2).
+	MovedPermanently = 301,
+
+	/// `302 Found` (RFC 7231, Section 6.4.3).
+	Found = 302,
+
+	/// `303 See Other` (RFC 7231, Section 6.4.4).
+	SeeOther = 303,
+
+	/// `304 Not Modified` (RFC 7232, Section 4.1).
+	NotModified = 304,
+
+	/// `305 Use Proxy` (RFC 7231, Section 6.4.5; deprecated).
+	UseProxy = 305,
+
+	/// `307 Temporary Redirect` (RFC 7231, Section 6.4.7).
+	TemporaryRedirect = 307,
+
+	/// `308 Permanent Redirect` (RFC 7538, Section 3).
+	PermanentRedirect = 308,
+
+	/// `400 Bad Request` (RFC 7231, Section 6.5.1).
+	BadRequest = 400,
+
+	/// `401 Unauthorized` (RFC 7235, Section 3.1).
+	Unauthorized = 401,
+
+	/// `402 Payment Required` (RFC 7231, Section 6.5.2).
+	PaymentRequired = 402,
+
+	/// `403 Forbidden` (RFC 7231, Section 6.5.3).
+	Forbidden = 403,
+
+	/// `404 Not Found` (RFC 7231, Section 6.5.4).
+	NotFound = 404,
+
+	/// `405 Method Not Allowed` (RFC 7231, Section 6.5.5).
+	MethodNotAllowed = 405,
+
+	/// `406 Not Acceptable` (RFC 7231, Section 6.5.6).
+	NotAcceptable = 406,
+
+	/// `407 Proxy Authentication Required` (RFC 7235, Section 3.2).
+	ProxyAuthenticationRequired = 407,
+
+	/// `408 Request Timeout` (RFC 7231, Section 6.5.7).
+	RequestTimeout = 408,
+
+	/// `409 Conflict` (RFC 7231, Section 6.5.8).
+	Conflict = 409,
+
+	/// `410 Gone` (RFC 7231, Section 6.5.9).
+	Gone = 410,
+
+	/// `411 Length Required` (RFC 7231, Section 6.5.10).
+	LengthRequired = 411,
+
+	/// `412 Precondition Failed` (RFC 7232, Section 4.2).
+	PreconditionFailed = 412,
+
+	/// `413 Payload Too Large` (RFC 7231, Section 6.5.11).
+	PayloadTooLarge = 413,
+
+	/// `414 URI Too Long` (RFC 7231, Section 6.5.12).
+	UriTooLong = 414,
+
+	/// `415 Unsupported Media Type` (RFC 7231, Section 6.5.13).
+	UnsupportedMediaType = 415,
+
+	/// `416 Range Not Satisfiable` (RFC 7233, Section 4.4).
+	RangeNotSatisfiable = 416,
+
+	/// `417 Expectation Failed` (RFC 7231, Section 6.5.14).
+	ExpectationFailed = 417,
+
+	/// `421 Misdirected Request` (RFC 7540, Section 9.1.2).
+	MisdirectedRequest = 421,
+
+	/// `422 Unprocessable Entity` (RFC 4918, Section 11.2).
+	UnprocessableEntity = 422,
+
+	/// `423 Locked` (RFC 4918, Section 11.3).
+	Locked = 423,
+
+	/// `424 Failed Dependency` (RFC 4918, Section 11.4).
+	FailedDependency = 424,
+
+	/// `425 Too Early` (RFC 8470, Section 5.2).
+	TooEarly = 425,
+
+	/// `426 Upgrade Required` (RFC 7231, Section 6.5.15).
+	UpgradeRequired = 426,
+
+	/// `428 Precondition Required` (RFC 6585, Section 3).
+	PreconditionRequired = 428,
+
+	/// `429 Too Many Requests` (RFC 6585, Section 4).
+	TooManyRequests = 429,
+
+	/// `431 Request Header Fields Too Large` (RFC 6585, Section 5).
+	RequestHeaderFieldsTooLarge = 431,
+
+	/// `451 Unavailable For Legal Reasons` (RFC 7725, Section 3).
+	UnavailableForLegalReasons = 451,
+
+	/// `500 Internal Server Error` (RFC 7231, Section 6.6.1).
+	InternalServerError = 500,
+
+	/// `501 Not Implemented` (RFC 7231, Section 6.6.2).
+	NotImplemented = 501,
+
+	/// `502 Bad Gateway` (RFC 7231, Section 6.6.3).
+	BadGateway = 502,
+
+	/// `503 Service Unavailable` (RFC 7231, Section 6.6.4).
+	ServiceUnavailable = 503,
+
+	/// `504 Gateway Timeout` (RFC 7231, Section 6.6.5).
+	GatewayTimeout = 504,
+
+	/// `505 HTTP Version Not Supported` (RFC 7231, Section 6.6.6).
+	HttpVersionNotSupported = 505,
+
+	/// `506 Variant Also Negotiates` (RFC 2295, Section 8.1).
+	VariantAlsoNegotiates = 506,
+
+	/// `507 Insufficient Storage` (RFC 4918, Section 11.5).
+	InsufficientStorage = 507,
+
+	/// `508 Loop Detected` (RFC 5842, Section 7.2).
+	LoopDetected = 508,
+
+	/// `510 Not Extended` (RFC 2774, Section 7; obsoleted).
+	NotExtended = 510,
+
+	/// `511 Network Authentication Required` (RFC 6585, Section 6).
+	NetworkAuthenticationRequired = 511,
+}
+
+impl Default for StatusCode
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		StatusCode::Ok
+	}
+}
+
+impl StatusCode
+{
+	/// Status code, eg `404`.
+	#[inline(always)]
+	pub fn code(self) -> u16
+	{
+		self as u16
+	}
+
+	/// The registered status code `code`, if any.
+	pub fn from_code(code: u16) -> Option<Self>
+	{
+		use self::StatusCode::*;
+
+		let status_code = match code
+		{
+			100 => Continue,
+
+			101 => SwitchingProtocols,
+
+			102 => Processing,
+
+			103 => EarlyHints,
+
+			200 => Ok,
+
+			201 => Created,
+
+			202 => Accepted,
+
+			203 => NonAuthoritativeInformation,
+
+			204 => NoContent,
+
+			205 => ResetContent,
+
+			206 => PartialContent,
+
+			207 => MultiStatus,
+
+			208 => AlreadyReported,
+
+			226 => ImUsed,
+
+			300 => MultipleChoices,
+
+			301 => MovedPermanently,
+
+			302 => Found,
+
+			303 => SeeOther,
+
+			304 => NotModified,
+
+			305 => UseProxy,
+
+			307 => TemporaryRedirect,
+
+			308 => PermanentRedirect,
+
+			400 => BadRequest,
+
+			401 => Unauthorized,
+
+			402 => PaymentRequired,
+
+			403 => Forbidden,
+
+			404 => NotFound,
+
+			405 => MethodNotAllowed,
+
+			406 => NotAcceptable,
+
+			407 => ProxyAuthenticationRequired,
+
+			408 => RequestTimeout,
+
+			409 => Conflict,
+
+			410 => Gone,
+
+			411 => LengthRequired,
+
+			412 => PreconditionFailed,
+
+			413 => PayloadTooLarge,
+
+			414 => UriTooLong,
+
+			415 => UnsupportedMediaType,
+
+			416 => RangeNotSatisfiable,
+
+			417 => ExpectationFailed,
+
+			421 => MisdirectedRequest,
+
+			422 => UnprocessableEntity,
+
+			423 => Locked,
+
+			424 => FailedDependency,
+
+			425 => TooEarly,
+
+			426 => UpgradeRequired,
+
+			428 => PreconditionRequired,
+
+			429 => TooManyRequests,
+
+			431 => RequestHeaderFieldsTooLarge,
+
+			451 => UnavailableForLegalReasons,
+
+			500 => InternalServerError,
+
+			501 => NotImplemented,
+
+			502 => BadGateway,
+
+			503 => ServiceUnavailable,
+
+			504 => GatewayTimeout,
+
+			505 => HttpVersionNotSupported,
+
+			506 => VariantAlsoNegotiates,
+
+			507 => InsufficientStorage,
+
+			508 => LoopDetected,
+
+			510 => NotExtended,
+
+			511 => NetworkAuthenticationRequired,
+
+			_ => return None,
+		};
+
+		Some(status_code)
+	}
+
+	/// Reason phrase, eg `Not Found`.
+	#[inline(always)]
+	pub fn reason_phrase(self) -> &'static [u8]
+	{
+		const StatusLinePrefixLength: usize = 13;
+
+		let status_line = self.status_line();
+		&status_line[StatusLinePrefixLength .. status_line.len() - 2]
+	}
+
+	/// The status line with trailing CRLF, eg `HTTP/1.1 404 Not Found\r\n`.
+	pub fn status_line(self) -> &'static [u8]
+	{
+		use self::StatusCode::*;
+
+		match self
+		{
+			Continue => b"HTTP/1.1 100 Continue\r\n",
+
+			SwitchingProtocols => b"HTTP/1.1 101 Switching Protocols\r\n",
+
+			Processing => b"HTTP/1.1 102 Processing\r\n",
+
+			EarlyHints => b"HTTP/1.1 103 Early Hints\r\n",
+
+			Ok => b"HTTP/1.1 200 OK\r\n",
+
+			Created => b"HTTP/1.1 201 Created\r\n",
+
+			Accepted => b"HTTP/1.1 202 Accepted\r\n",
+
+			NonAuthoritativeInformation => b"HTTP/1.1 203 Non-Authoritative Information\r\n",
+
+			NoContent => b"HTTP/1.1 204 No Content\r\n",
+
+			ResetContent => b"HTTP/1.1 205 Reset Content\r\n",
+
+			PartialContent => b"HTTP/1.1 206 Partial Content\r\n",
+
+			MultiStatus => b"HTTP/1.1 207 Multi-Status\r\n",
+
+			AlreadyReported => b"HTTP/1.1 208 Already Reported\r\n",
+
+			ImUsed => b"HTTP/1.1 226 IM Used\r\n",
+
+			MultipleChoices => b"HTTP/1.1 300 Multiple Choices\r\n",
+
+			MovedPermanently => b"HTTP/1.1 301 Moved Permanently\r\n",
+
+			Found => b"HTTP/1.1 302 Found\r\n",
+
+			SeeOther => b"HTTP/1.1 303 See Other\r\n",
+
+			NotModified => b"HTTP/1.1 304 Not Modified\r\n",
+
+			UseProxy => b"HTTP/1.1 305 Use Proxy\r\n",
+
+			TemporaryRedirect => b"HTTP/1.1 307 Temporary Redirect\r\n",
+
+			PermanentRedirect => b"HTTP/1.1 308 Permanent Redirect\r\n",
+
+			BadRequest => b"HTTP/1.1 400 Bad Request\r\n",
+
+			Unauthorized => b"HTTP/1.1 401 Unauthorized\r\n",
+
+			PaymentRequired => b"HTTP/1.1 402 Payment Required\r\n",
+
+			Forbidden => b"HTTP/1.1 403 Forbidden\r\n",
+
+			NotFound => b"HTTP/1.1 404 Not Found\r\n",
+
+			MethodNotAllowed => b"HTTP/1.1 405 Method Not Allowed\r\n",
+
+			NotAcceptable => b"HTTP/1.1 406 Not Acceptable\r\n",
+
+			ProxyAuthenticationRequired => b"HTTP/1.1 407 Proxy Authentication Required\r\n",
+
+			RequestTimeout => b"HTTP/1.1 408 Request Timeout\r\n",
+
+			Conflict => b"HTTP/1.1 409 Conflict\r\n",
+
+			Gone => b"HTTP/1.1 410 Gone\r\n",
+
+			LengthRequired => b"HTTP/1.1 411 Length Required\r\n",
+
+			PreconditionFailed => b"HTTP/1.1 412 Precondition Failed\r\n",
+
+			PayloadTooLarge => b"HTTP/1.1 413 Payload Too Large\r\n",
+
+			UriTooLong => b"HTTP/1.1 414 URI Too Long\r\n",
+
+			UnsupportedMediaType => b"HTTP/1.1 415 Unsupported Media Type\r\n",
+
+			RangeNotSatisfiable => b"HTTP/1.1 416 Range Not Satisfiable\r\n",
+
+			ExpectationFailed => b"HTTP/1.1 417 Expectation Failed\r\n",
+
+			MisdirectedRequest => b"HTTP/1.1 421 Misdirected Request\r\n",
+
+			UnprocessableEntity => b"HTTP/1.1 422 Unprocessable Entity\r\n",
+
+			Locked => b"HTTP/1.1 423 Locked\r\n",
+
+			FailedDependency => b"HTTP/1.1 424 Failed Dependency\r\n",
+
+			TooEarly => b"HTTP/1.1 425 Too Early\r\n",
+
+			UpgradeRequired => b"HTTP/1.1 426 Upgrade Required\r\n",
+
+			PreconditionRequired => b"HTTP/1.1 428 Precondition Required\r\n",
+
+			TooManyRequests => b"HTTP/1.1 429 Too Many Requests\r\n",
+
+			RequestHeaderFieldsTooLarge => b"HTTP/1.1 431 Request Header Fields Too Large\r\n",
+
+			UnavailableForLegalReasons => b"HTTP/1.1 451 Unavailable For Legal Reasons\r\n",
+
+			InternalServerError => b"HTTP/1.1 500 Internal Server Error\r\n",
+
+			NotImplemented => b"HTTP/1.1 501 Not Implemented\r\n",
+
+			BadGateway => b"HTTP/1.1 502 Bad Gateway\r\n",
+
+			ServiceUnavailable => b"HTTP/1.1 503 Service Unavailable\r\n",
+
+			GatewayTimeout => b"HTTP/1.1 504 Gateway Timeout\r\n",
+
+			HttpVersionNotSupported => b"HTTP/1.1 505 HTTP Version Not Supported\r\n",
+
+			VariantAlsoNegotiates => b"HTTP/1.1 506 Variant Also Negotiates\r\n",
+
+			InsufficientStorage => b"HTTP/1.1 507 Insufficient Storage\r\n",
+
+			LoopDetected => b"HTTP/1.1 508 Loop Detected\r\n",
+
+			NotExtended => b"HTTP/1.1 510 Not Extended\r\n",
+
+			NetworkAuthenticationRequired => b"HTTP/1.1 511 Network Authentication Required\r\n",
+		}
+	}
+
+	/// `1xx`; a response with an informational status code is interim and is followed by a final response.
+	#[inline(always)]
+	pub fn is_informational(self) -> bool
+	{
+		self.code() < 200
+	}
+
+	/// `2xx`.
+	#[inline(always)]
+	pub fn is_successful(self) -> bool
+	{
+		self.code() >= 200 && self.code() < 300
+	}
+
+	/// `3xx`.
+	#[inline(always)]
+	pub fn is_redirection(self) -> bool
+	{
+		self.code() >= 300 && self.code() < 400
+	}
+
+	/// `4xx`.
+	#[inline(always)]
+	pub fn is_client_error(self) -> bool
+	{
+		self.code() >= 400 && self.code() < 500
+	}
+
+	/// `5xx`.
+	#[inline(always)]
+	pub fn is_server_error(self) -> bool
+	{
+		self.code() >= 500
+	}
+
+	/// A response with this status code can be cached without explicit freshness information, eg `Cache-Control:max-age`, by using a heuristic (RFC 7231, Section 6.1).
+	///
+	/// Other status codes are only cached if `Cache-Control` or `Expires` explicitly permits it.
+	pub fn is_cacheable_by_default(self) -> bool
+	{
+		use self::StatusCode::*;
+
+		match self
+		{
+			Ok | NonAuthoritativeInformation | NoContent | PartialContent | MultipleChoices | MovedPermanently | PermanentRedirect | NotFound | MethodNotAllowed | Gone | UriTooLong | UnavailableForLegalReasons | NotImplemented => true,
+
+			_ => false,
+		}
+	}
+
+	/// Can a response with this status code have a body (RFC 7230, Section 3.3.3)?
+	///
+	/// Responses with an informational status code, `204 No Content` or `304 Not Modified` are always terminated by the end of their headers; a response to a `HEAD` request never has a body, whatever its status code.
+	#[inline(always)]
+	pub fn is_body_permitted(self) -> bool
+	{
+		match self
+		{
+			StatusCode::NoContent | StatusCode::NotModified => false,
+
+			_ => !self.is_informational(),
+		}
+	}
+}
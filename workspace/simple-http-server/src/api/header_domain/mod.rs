@@ -4,7 +4,19 @@
 
 use super::*;
 
+include!("ContentCoding.rs");
+include!("ContentSecurityPolicyDirective.rs");
+include!("ContentSecurityPolicySource.rs");
+include!("CrossOriginEmbedderPolicy.rs");
+include!("CrossOriginOpenerPolicy.rs");
+include!("CrossOriginResourcePolicy.rs");
 include!("HeaderName.rs");
+include!("KnownHeader.rs");
+include!("PermissionsPolicyAllowlist.rs");
+include!("ReferrerPolicy.rs");
 include!("RequestMethod.rs");
 include!("ResponseCacheControlDirective.rs");
 include!("RobotTag.rs");
+include!("SameSite.rs");
+include!("SetCookieAttributes.rs");
+include!("StatusCode.rs");
@@ -5,22 +5,62 @@
 use super::*;
 
 
+/// Compression of response bodies negotiated from `Accept-Encoding`, eg with `gzip`.
+pub mod compression;
+
+
+/// Evaluation of conditional requests, eg `If-None-Match`.
+pub mod conditional_requests;
+
+
+/// Finding the cookies of a request, eg `Cookie: session=38afes7a8; theme=dark`.
+pub mod cookies;
+
+
 /// HTTP header-related domain types.
 pub mod header_domain;
 
 
+/// Evaluation of `Range` requests, eg `Range: bytes=0-499`.
+pub mod range_requests;
+
+
 /// Response buffers.
 pub mod response_buffers;
 
 
+/// Server-sent events (`text/event-stream`), pushed to a user agent over a long-lived response.
+pub mod server_sent_events;
+
+
 /// A RFC 3986 URI adapted for use in HTTP targets ('GET /') and `Location` headers.
 pub mod uri;
 
 
+#[cfg(test)] mod tests;
+
+
+include!("BufferedResponse.rs");
+include!("Chunk.rs");
+include!("ChunkedResponse.rs");
+include!("ChunkProducer.rs");
+include!("ConnectionRequestUser.rs");
+include!("ContinueResponse.rs");
 include!("RegistrationState.rs");
+include!("RequestHandler.rs");
+include!("Response.rs");
+include!("ResponseBuilder.rs");
+include!("SecurityHeaderPolicy.rs");
 include!("ServedClientConnectionUser.rs");
 include!("ServedClientConnectionUserFactory.rs");
 include!("SimpleHttpServedClientConnectionUser.rs");
 include!("SimpleHttpServedClientConnectionUserError.rs");
 include!("SimpleHttpServedClientConnectionUserFactory.rs");
 include!("SimplifiedServerSession.rs");
+include!("StaticFile.rs");
+include!("StaticFileBodyPart.rs");
+include!("StaticFileRead.rs");
+include!("StaticFileReader.rs");
+include!("StaticFileResponse.rs");
+include!("VirtualHost.rs");
+include!("VirtualHosts.rs");
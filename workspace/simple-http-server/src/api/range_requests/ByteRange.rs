@@ -0,0 +1,31 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A satisfiable range of bytes of a representation, with both positions inclusive.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteRange
+{
+	/// Inclusive.
+	pub first_byte_position: u64,
+
+	/// Inclusive; never less than `first_byte_position`.
+	pub last_byte_position: u64,
+}
+
+impl ByteRange
+{
+	/// Number of bytes in this range; never zero.
+	#[inline(always)]
+	pub fn length(&self) -> u64
+	{
+		self.last_byte_position - self.first_byte_position + 1
+	}
+
+	/// This range of bytes of an in-memory representation.
+	#[inline(always)]
+	pub fn slice<'a>(&self, representation: &'a [u8]) -> &'a [u8]
+	{
+		&representation[(self.first_byte_position as usize) .. (self.last_byte_position as usize + 1)]
+	}
+}
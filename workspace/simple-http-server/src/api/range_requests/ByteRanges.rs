@@ -0,0 +1,272 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// The satisfiable ranges of a `Range` header, in the order requested.
+///
+/// Parsing does not allocate.
+#[derive(Debug, Clone)]
+pub struct ByteRanges
+{
+	byte_ranges: ArrayVec<[ByteRange; ByteRanges::MaximumNumberOfByteRanges]>,
+}
+
+impl ByteRanges
+{
+	/// A `Range` header with more satisfiable ranges than this is ignored, as many small or overlapping ranges are a denial-of-service risk (RFC 7233, Section 6.1).
+	pub const MaximumNumberOfByteRanges: usize = 16;
+
+	/// Parses the field value of a `Range` header and resolves it against the length of the selected representation (RFC 7233, Section 2.1).
+	///
+	/// ```text
+	/// Range                  = byte-ranges-specifier / other-ranges-specifier
+	/// byte-ranges-specifier  = bytes-unit "=" byte-range-set
+	/// byte-range-set         = 1#( byte-range-spec / suffix-byte-range-spec )
+	/// byte-range-spec        = first-byte-pos "-" [ last-byte-pos ]
+	/// suffix-byte-range-spec = "-" suffix-length
+	/// ```
+	///
+	/// A `Range` header which is invalid, is not for `bytes` or has too many satisfiable ranges is ignored, giving `RangeRequestOutcome::Full`.
+	///
+	/// Positions too large for an `u64` saturate rather than overflow; they are always beyond the end of the representation.
+	pub fn parse(field_value: &ImmutableVectoredBufferSlice, representation_length: u64) -> RangeRequestOutcome
+	{
+		use self::ByteRangeSetParseState::*;
+
+		const BytesUnit: &'static [u8] = b"bytes=";
+
+		let mut this = Self
+		{
+			byte_ranges: ArrayVec::new(),
+		};
+		let mut has_any_byte_range_specification = false;
+
+		let mut state = BeforeByteRangeSpecification;
+		for (index, byte) in field_value.bytes().enumerate()
+		{
+			if index < BytesUnit.len()
+			{
+				if !byte.eq_ignore_ascii_case(&BytesUnit[index])
+				{
+					return RangeRequestOutcome::Full
+				}
+				continue
+			}
+
+			state = match (state, byte)
+			{
+				(BeforeByteRangeSpecification, b' ') | (BeforeByteRangeSpecification, b'\t') | (BeforeByteRangeSpecification, b',') => BeforeByteRangeSpecification,
+
+				(BeforeByteRangeSpecification, b'0' ... b'9') => FirstBytePosition(Self::digit(0, byte)),
+
+				(BeforeByteRangeSpecification, b'-') => SuffixLength(None),
+
+				(FirstBytePosition(first_byte_position), b'0' ... b'9') => FirstBytePosition(Self::digit(first_byte_position, byte)),
+
+				(FirstBytePosition(first_byte_position), b'-') => LastBytePosition { first_byte_position, last_byte_position: None },
+
+				(LastBytePosition { first_byte_position, last_byte_position }, b'0' ... b'9') => LastBytePosition { first_byte_position, last_byte_position: Some(Self::digit(last_byte_position.unwrap_or(0), byte)) },
+
+				(SuffixLength(suffix_length), b'0' ... b'9') => SuffixLength(Some(Self::digit(suffix_length.unwrap_or(0), byte))),
+
+				(state @ LastBytePosition { .. }, b' ') | (state @ LastBytePosition { .. }, b'\t') | (state @ SuffixLength(_), b' ') | (state @ SuffixLength(_), b'\t') =>
+				{
+					if this.push(state, representation_length).is_err()
+					{
+						return RangeRequestOutcome::Full
+					}
+					has_any_byte_range_specification = true;
+					AfterByteRangeSpecification
+				}
+
+				(state @ LastBytePosition { .. }, b',') | (state @ SuffixLength(_), b',') =>
+				{
+					if this.push(state, representation_length).is_err()
+					{
+						return RangeRequestOutcome::Full
+					}
+					has_any_byte_range_specification = true;
+					BeforeByteRangeSpecification
+				}
+
+				(AfterByteRangeSpecification, b' ') | (AfterByteRangeSpecification, b'\t') => AfterByteRangeSpecification,
+
+				(AfterByteRangeSpecification, b',') => BeforeByteRangeSpecification,
+
+				_ => return RangeRequestOutcome::Full,
+			}
+		}
+
+		match state
+		{
+			LastBytePosition { .. } | SuffixLength(_) =>
+			{
+				if this.push(state, representation_length).is_err()
+				{
+					return RangeRequestOutcome::Full
+				}
+			}
+
+			BeforeByteRangeSpecification => if !has_any_byte_range_specification
+			{
+				return RangeRequestOutcome::Full
+			},
+
+			AfterByteRangeSpecification => (),
+
+			FirstBytePosition(_) => return RangeRequestOutcome::Full,
+		}
+
+		if this.byte_ranges.is_empty()
+		{
+			RangeRequestOutcome::NotSatisfiable
+		}
+		else
+		{
+			RangeRequestOutcome::Partial(this)
+		}
+	}
+
+	/// Iterates over the ranges in the order requested.
+	#[inline(always)]
+	pub fn iter(&self) -> Iter<ByteRange>
+	{
+		self.byte_ranges.iter()
+	}
+
+	/// Number of ranges; never zero.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.byte_ranges.len()
+	}
+
+	/// The range at `index`.
+	#[inline(always)]
+	pub fn get(&self, index: usize) -> Option<ByteRange>
+	{
+		self.byte_ranges.get(index).cloned()
+	}
+
+	/// If there is only one range, it is sent as the body of a `206 Partial Content` rather than as `multipart/byteranges`.
+	#[inline(always)]
+	pub fn single(&self) -> Option<ByteRange>
+	{
+		if self.byte_ranges.len() == 1
+		{
+			Some(self.byte_ranges[0])
+		}
+		else
+		{
+			None
+		}
+	}
+
+	/// The body part headers, each preceding the range with the same index, and the close delimiter of a `multipart/byteranges` body (RFC 7233, Appendix A), along with its `Content-Type`.
+	///
+	/// `content_type` is that of the representation.
+	pub(crate) fn multipart_byteranges(&self, content_type: &ContentTypeHeaderResponseBuffer, representation_length: u64) -> (Vec<Vec<u8>>, Vec<u8>, ContentTypeHeaderResponseBuffer)
+	{
+		let boundary = Self::boundary();
+
+		let mut body_part_headers = Vec::with_capacity(self.len());
+		for (index, byte_range) in self.iter().enumerate()
+		{
+			body_part_headers.push(Self::body_part_header(index == 0, &boundary, content_type, *byte_range, representation_length));
+		}
+
+		let mut close_delimiter = Vec::with_capacity(boundary.len() + 8);
+		close_delimiter.extend_from_slice(b"\r\n--");
+		close_delimiter.extend_from_slice(&boundary);
+		close_delimiter.extend_from_slice(b"--\r\n");
+
+		(body_part_headers, close_delimiter, ContentTypeHeaderResponseBuffer::multipart_byteranges(boundary))
+	}
+
+	#[inline(always)]
+	fn body_part_header(is_first: bool, boundary: &[u8], content_type: &ContentTypeHeaderResponseBuffer, byte_range: ByteRange, representation_length: u64) -> Vec<u8>
+	{
+		let mut body_part_header = Vec::with_capacity(128);
+
+		if !is_first
+		{
+			body_part_header.extend_from_slice(b"\r\n");
+		}
+		body_part_header.extend_from_slice(b"--");
+		body_part_header.extend_from_slice(boundary);
+		body_part_header.extend_from_slice(b"\r\n");
+		content_type.write_header(&mut body_part_header);
+		body_part_header.extend_from_slice(b"Content-Range:");
+		body_part_header.extend_from_slice(&ContentRangeHeaderResponseBuffer::satisfied_field_value(byte_range, representation_length));
+		body_part_header.extend_from_slice(b"\r\n\r\n");
+
+		body_part_header
+	}
+
+	// The boundary must not occur in the representation; a high resolution timestamp makes this vanishingly unlikely without scanning the representation.
+	// It only uses characters permitted by `ContentTypeHeaderResponseBuffer::multipart_byteranges()`.
+	#[inline(always)]
+	fn boundary() -> Vec<u8>
+	{
+		format!("SimpleHttpServer{:016x}", precise_time_ns()).into_bytes()
+	}
+
+	#[inline(always)]
+	fn digit(position: u64, digit: u8) -> u64
+	{
+		position.saturating_mul(10).saturating_add((digit - b'0') as u64)
+	}
+
+	// An unsatisfiable range is skipped (RFC 7233, Section 2.1); an invalid one causes the entire header to be ignored (RFC 7233, Section 3.1).
+	#[inline(always)]
+	fn push(&mut self, state: ByteRangeSetParseState, representation_length: u64) -> Result<(), ()>
+	{
+		use self::ByteRangeSetParseState::*;
+
+		let byte_range = match state
+		{
+			LastBytePosition { first_byte_position, last_byte_position } =>
+			{
+				if let Some(last_byte_position) = last_byte_position
+				{
+					if last_byte_position < first_byte_position
+					{
+						return Err(())
+					}
+				}
+
+				if first_byte_position >= representation_length
+				{
+					return Ok(())
+				}
+
+				let final_byte_position = representation_length - 1;
+				ByteRange
+				{
+					first_byte_position,
+					last_byte_position: min(last_byte_position.unwrap_or(final_byte_position), final_byte_position),
+				}
+			}
+
+			SuffixLength(None) => return Err(()),
+
+			SuffixLength(Some(suffix_length)) =>
+			{
+				if suffix_length == 0 || representation_length == 0
+				{
+					return Ok(())
+				}
+
+				ByteRange
+				{
+					first_byte_position: representation_length - min(suffix_length, representation_length),
+					last_byte_position: representation_length - 1,
+				}
+			}
+
+			_ => unreachable!(),
+		};
+
+		self.byte_ranges.try_push(byte_range).map_err(|_| ())
+	}
+}
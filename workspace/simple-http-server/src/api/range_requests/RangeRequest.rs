@@ -0,0 +1,104 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Records the `Range` and `If-Range` headers of a request (RFC 7233) so that they can be evaluated once the selected representation is known.
+///
+/// A `RequestHandler` should pass every call of `header_field()` to `header_field()` here, then call `evaluate()` from `request_finished()` and finally `clear()`.
+/// `evaluate()` should only be called if evaluating a `ConditionalRequest` gave `ConditionalRequestOutcome::Proceed`.
+#[derive(Default, Debug, Clone)]
+pub struct RangeRequest
+{
+	range: Option<(VectoredBufferOffset, VectoredBufferOffset)>,
+	if_range: Option<(VectoredBufferOffset, VectoredBufferOffset)>,
+}
+
+impl RangeRequest
+{
+	/// Records the header if it is `Range` or `If-Range`.
+	///
+	/// Neither is a list, so more than one of either is a bad request.
+	#[inline(always)]
+	pub fn header_field(&mut self, known_header: KnownHeader, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		let field = match known_header
+		{
+			KnownHeader::Range => &mut self.range,
+
+			KnownHeader::IfRange => &mut self.if_range,
+
+			_ => return Ok(()),
+		};
+
+		if field.is_some()
+		{
+			return Err(BadRequest("More than one Range or If-Range header"))
+		}
+		*field = Some((value_starts_at_inclusive, value_ends_at_exclusive));
+		Ok(())
+	}
+
+	/// Evaluates the `Range` header (RFC 7233, Section 3.1) and any `If-Range` precondition (RFC 7233, Section 3.2).
+	///
+	/// `entity_tag` and `last_modified` are the validators of the selected representation, if it has them, and `representation_length` its length in bytes.
+	///
+	/// Only `GET` requests can have ranges.
+	pub fn evaluate(&self, vectored_buffer: &VectoredBuffer, request_method: RequestMethod, entity_tag: Option<EntityTag>, last_modified: Option<HttpDate>, representation_length: u64) -> RangeRequestOutcome
+	{
+		if request_method != RequestMethod::GET
+		{
+			return RangeRequestOutcome::Full
+		}
+
+		let (range_starts_at_inclusive, range_ends_at_exclusive) = match self.range
+		{
+			None => return RangeRequestOutcome::Full,
+
+			Some(range) => range,
+		};
+
+		if let Some((if_range_starts_at_inclusive, if_range_ends_at_exclusive)) = self.if_range
+		{
+			if !Self::if_range_matches(&ImmutableVectoredBufferSlice::new(vectored_buffer, if_range_starts_at_inclusive, if_range_ends_at_exclusive), entity_tag, last_modified)
+			{
+				return RangeRequestOutcome::Full
+			}
+		}
+
+		ByteRanges::parse(&ImmutableVectoredBufferSlice::new(vectored_buffer, range_starts_at_inclusive, range_ends_at_exclusive), representation_length)
+	}
+
+	/// Call after `evaluate()` so this instance can be re-used for the next request on the connection.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.range = None;
+		self.if_range = None;
+	}
+
+	// ```text
+	// If-Range = entity-tag / HTTP-date
+	// ```
+	//
+	// An entity tag must match using the strong comparison; a date must exactly match the `Last-Modified` date.
+	#[inline(always)]
+	fn if_range_matches(field_value: &ImmutableVectoredBufferSlice, entity_tag: Option<EntityTag>, last_modified: Option<HttpDate>) -> bool
+	{
+		match field_value.bytes().next()
+		{
+			Some(b'"') | Some(b'W') => match entity_tag
+			{
+				None => false,
+
+				Some(entity_tag) => entity_tag.is_matched_by(field_value, EntityTagComparison::Strong),
+			},
+
+			_ => match (HttpDate::parse(field_value), last_modified)
+			{
+				(Some(if_range), Some(last_modified)) => if_range == last_modified,
+
+				_ => false,
+			},
+		}
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// The result of evaluating the `Range` and `If-Range` headers of a request.
+#[derive(Debug, Clone)]
+pub enum RangeRequestOutcome
+{
+	/// Respond with the full representation, eg `200 OK`, as there was no `Range` header, it was invalid or it was not applicable.
+	Full,
+
+	/// Respond `206 Partial Content`, eg with `ResponseBuilder::partial_content()` or `ResponseBuilder::static_file_partial_content()`.
+	Partial(ByteRanges),
+
+	/// Respond `416 Range Not Satisfiable`, eg with `ResponseBuilder::range_not_satisfiable()`.
+	NotSatisfiable,
+}
@@ -0,0 +1,171 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+use super::*;
+
+
+const RepresentationLength: u64 = 1000;
+
+fn parse(field_value: &[u8], representation_length: u64) -> RangeRequestOutcome
+{
+	with_immutable_vectored_buffer_slice(field_value, |field_value| ByteRanges::parse(field_value, representation_length))
+}
+
+fn partial(field_value: &[u8], representation_length: u64) -> Vec<(u64, u64)>
+{
+	match parse(field_value, representation_length)
+	{
+		RangeRequestOutcome::Partial(byte_ranges) => byte_ranges.iter().map(|byte_range| (byte_range.first_byte_position, byte_range.last_byte_position)).collect(),
+
+		unexpected => panic!("Unexpected {:?}", unexpected),
+	}
+}
+
+fn is_full(field_value: &[u8]) -> bool
+{
+	match parse(field_value, RepresentationLength)
+	{
+		RangeRequestOutcome::Full => true,
+
+		_ => false,
+	}
+}
+
+fn is_not_satisfiable(field_value: &[u8], representation_length: u64) -> bool
+{
+	match parse(field_value, representation_length)
+	{
+		RangeRequestOutcome::NotSatisfiable => true,
+
+		_ => false,
+	}
+}
+
+fn byte_range_set(number_of_byte_ranges: usize) -> Vec<u8>
+{
+	let mut field_value = b"bytes=".to_vec();
+	for index in 0 .. number_of_byte_ranges
+	{
+		if index != 0
+		{
+			field_value.push(b',');
+		}
+		field_value.extend_from_slice(format!("{}-{}", index, index).as_bytes());
+	}
+	field_value
+}
+
+#[test]
+fn single_range_is_parsed()
+{
+	assert_eq!(partial(b"bytes=0-499", RepresentationLength), vec![(0, 499)]);
+}
+
+#[test]
+fn bytes_unit_is_case_insensitive()
+{
+	assert_eq!(partial(b"BYTES=0-0", RepresentationLength), vec![(0, 0)]);
+}
+
+#[test]
+fn last_byte_position_beyond_the_end_is_clamped()
+{
+	assert_eq!(partial(b"bytes=500-9999", RepresentationLength), vec![(500, 999)]);
+}
+
+#[test]
+fn open_ended_range_is_parsed()
+{
+	assert_eq!(partial(b"bytes=900-", RepresentationLength), vec![(900, 999)]);
+}
+
+#[test]
+fn suffix_range_is_parsed()
+{
+	assert_eq!(partial(b"bytes=-100", RepresentationLength), vec![(900, 999)]);
+}
+
+#[test]
+fn suffix_range_longer_than_the_representation_is_all_of_it()
+{
+	assert_eq!(partial(b"bytes=-5000", RepresentationLength), vec![(0, 999)]);
+}
+
+#[test]
+fn multiple_ranges_are_parsed_in_the_order_requested()
+{
+	assert_eq!(partial(b"bytes=500-599, -1,\t0-0 ,, 10-", RepresentationLength), vec![(500, 599), (999, 999), (0, 0), (10, 999)]);
+}
+
+#[test]
+fn unsatisfiable_range_is_skipped()
+{
+	assert_eq!(partial(b"bytes=2000-3000,0-1", RepresentationLength), vec![(0, 1)]);
+}
+
+#[test]
+fn only_unsatisfiable_ranges_are_not_satisfiable()
+{
+	assert!(is_not_satisfiable(b"bytes=1000-", RepresentationLength));
+	assert!(is_not_satisfiable(b"bytes=1000-1001, 2000-", RepresentationLength));
+	assert!(is_not_satisfiable(b"bytes=-0", RepresentationLength));
+	assert!(is_not_satisfiable(b"bytes=0-", 0));
+	assert!(is_not_satisfiable(b"bytes=-10", 0));
+}
+
+#[test]
+fn invalid_range_header_is_ignored()
+{
+	assert!(is_full(b""));
+	assert!(is_full(b"bytes"));
+	assert!(is_full(b"bytes="));
+	assert!(is_full(b"bytes=,"));
+	assert!(is_full(b"bytes 0-1"));
+	assert!(is_full(b"items=0-1"));
+	assert!(is_full(b"bytes=5"));
+	assert!(is_full(b"bytes=-"));
+	assert!(is_full(b"bytes=a-b"));
+	assert!(is_full(b"bytes=0-1 2-3"));
+	assert!(is_full(b"bytes=0--1"));
+}
+
+#[test]
+fn range_with_last_byte_position_before_first_byte_position_is_ignored()
+{
+	assert!(is_full(b"bytes=5-4"));
+	assert!(is_full(b"bytes=0-1, 5-4"));
+}
+
+#[test]
+fn maximum_number_of_ranges_is_parsed()
+{
+	assert_eq!(partial(&byte_range_set(ByteRanges::MaximumNumberOfByteRanges), RepresentationLength).len(), ByteRanges::MaximumNumberOfByteRanges);
+}
+
+#[test]
+fn too_many_ranges_are_ignored()
+{
+	assert!(is_full(&byte_range_set(ByteRanges::MaximumNumberOfByteRanges + 1)));
+}
+
+#[test]
+fn positions_too_large_for_an_u64_saturate()
+{
+	assert!(is_not_satisfiable(b"bytes=99999999999999999999999999-", RepresentationLength));
+	assert_eq!(partial(b"bytes=0-99999999999999999999999999", RepresentationLength), vec![(0, 999)]);
+	assert_eq!(partial(b"bytes=-99999999999999999999999999", RepresentationLength), vec![(0, 999)]);
+}
+
+#[test]
+fn byte_range_length_and_slice_are_inclusive()
+{
+	let byte_range = ByteRange
+	{
+		first_byte_position: 1,
+		last_byte_position: 3,
+	};
+
+	assert_eq!(byte_range.length(), 3);
+	assert_eq!(byte_range.slice(b"abcde"), b"bcd");
+}
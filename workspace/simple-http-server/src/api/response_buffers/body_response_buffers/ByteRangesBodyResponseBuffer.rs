@@ -0,0 +1,123 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// The body of a `206 Partial Content` response (RFC 7233, Section 4.1) for an in-memory representation.
+///
+/// A single range is the body; multiple ranges are a `multipart/byteranges` body, with buffers alternating between body part headers and ranges, followed by the close delimiter (RFC 2046, Section 5.1.1).
+pub struct ByteRangesBodyResponseBuffer<R: AsRef<[u8]>>
+{
+	representation: R,
+	byte_ranges: ByteRanges,
+	body_part_headers: Vec<Vec<u8>>,
+	close_delimiter: Vec<u8>,
+	multipart_content_type: Option<ContentTypeHeaderResponseBuffer>,
+}
+
+impl<R: AsRef<[u8]>> HeaderResponseBuffer for ByteRangesBodyResponseBuffer<R>
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		if self.is_multipart()
+		{
+			self.body_part_headers.len() * 2 + 1
+		}
+		else
+		{
+			1
+		}
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		&self.buffer(buffer_index)[from_offset .. ]
+	}
+}
+
+impl<R: AsRef<[u8]>> ByteRangesBodyResponseBuffer<R>
+{
+	/// `byte_ranges` must have been obtained using the length of `representation`, eg from `RangeRequest::evaluate()`.
+	///
+	/// `content_type` is that of the representation, eg `text/html;charset=utf-8`; it is only used in the body part headers of a `multipart/byteranges` body.
+	pub fn new(representation: R, byte_ranges: ByteRanges, content_type: &ContentTypeHeaderResponseBuffer) -> Self
+	{
+		let (body_part_headers, close_delimiter, multipart_content_type) = if byte_ranges.single().is_some()
+		{
+			(Vec::new(), Vec::new(), None)
+		}
+		else
+		{
+			let (body_part_headers, close_delimiter, multipart_content_type) = byte_ranges.multipart_byteranges(content_type, representation.as_ref().len() as u64);
+			(body_part_headers, close_delimiter, Some(multipart_content_type))
+		};
+
+		let this = Self
+		{
+			representation,
+			byte_ranges,
+			body_part_headers,
+			close_delimiter,
+			multipart_content_type,
+		};
+
+		debug_assert!(this.byte_ranges.iter().all(|byte_range| byte_range.last_byte_position < this.representation_length()), "byte_ranges were not for this representation");
+
+		this
+	}
+
+	/// Length in bytes, for a `Content-Length` header.
+	#[inline(always)]
+	pub fn content_length(&self) -> usize
+	{
+		(0 .. self.number_of_buffers()).map(|buffer_index| self.buffer(buffer_index).len()).sum()
+	}
+
+	/// The `Content-Type` of a `multipart/byteranges` body.
+	///
+	/// Only valid for multiple ranges.
+	#[inline(always)]
+	pub(crate) fn multipart_content_type(&self) -> ContentTypeHeaderResponseBuffer
+	{
+		self.multipart_content_type.clone().expect("Not multipart")
+	}
+
+	#[inline(always)]
+	fn is_multipart(&self) -> bool
+	{
+		!self.close_delimiter.is_empty()
+	}
+
+	#[inline(always)]
+	fn representation_length(&self) -> u64
+	{
+		self.representation.as_ref().len() as u64
+	}
+
+	#[inline(always)]
+	fn buffer(&self, buffer_index: usize) -> &[u8]
+	{
+		let representation = self.representation.as_ref();
+
+		if !self.is_multipart()
+		{
+			debug_assert_eq!(buffer_index, 0, "buffer_index `{}` is not zero", buffer_index);
+			return self.byte_ranges.single().unwrap().slice(representation)
+		}
+
+		let body_part_index = buffer_index / 2;
+		if body_part_index == self.body_part_headers.len()
+		{
+			self.close_delimiter.as_slice()
+		}
+		else if buffer_index % 2 == 0
+		{
+			self.body_part_headers[body_part_index].as_slice()
+		}
+		else
+		{
+			self.byte_ranges.get(body_part_index).unwrap().slice(representation)
+		}
+	}
+}
@@ -0,0 +1,71 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A chunk of a chunked body (RFC 7230, Section 4.1): the chunk size in hexadecimal, the chunk data and a terminating CRLF.
+///
+/// Chunk extensions are never sent.
+pub struct ChunkBodyResponseBuffer<D: AsRef<[u8]>>
+{
+	chunk_size_line: ArrayVec<[u8; 18]>,
+	chunk_data: D,
+}
+
+impl<D: AsRef<[u8]>> HeaderResponseBuffer for ChunkBodyResponseBuffer<D>
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		3
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert!(buffer_index < 3, "buffer_index `{}` out of range", buffer_index);
+
+		let buffer: &[u8] = match buffer_index
+		{
+			0 => &self.chunk_size_line[..],
+
+			1 => self.chunk_data.as_ref(),
+
+			2 => b"\r\n",
+
+			_ => unreachable!(),
+		};
+
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+
+		&buffer[from_offset .. ]
+	}
+}
+
+impl<D: AsRef<[u8]>> ChunkBodyResponseBuffer<D>
+{
+	/// `chunk_data` must not be empty, as a chunk size of zero is the last chunk.
+	#[inline(always)]
+	pub fn new(chunk_data: D) -> Self
+	{
+		const HexadecimalDigits: &'static [u8; 16] = b"0123456789abcdef";
+
+		let chunk_size = chunk_data.as_ref().len();
+		debug_assert_ne!(chunk_size, 0, "chunk_data is empty");
+
+		let mut chunk_size_line = ArrayVec::new();
+
+		let number_of_digits = ((0usize.leading_zeros() - chunk_size.leading_zeros() + 3) / 4) as usize;
+		for digit_index in (0 .. number_of_digits).rev()
+		{
+			chunk_size_line.push(HexadecimalDigits[(chunk_size >> (digit_index * 4)) & 0xF]);
+		}
+		chunk_size_line.push(b'\r');
+		chunk_size_line.push(b'\n');
+
+		Self
+		{
+			chunk_size_line,
+			chunk_data,
+		}
+	}
+}
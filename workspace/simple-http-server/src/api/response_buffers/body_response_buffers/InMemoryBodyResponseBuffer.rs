@@ -0,0 +1,39 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A body held in memory, eg a `&'static [u8]`, a `Vec<u8>` or a `Rc<[u8]>` shared between responses.
+///
+/// Bodies are written out through the same resumable interface as headers.
+#[derive(Debug, Clone)]
+pub struct InMemoryBodyResponseBuffer<B: AsRef<[u8]>>(B);
+
+impl<B: AsRef<[u8]>> HeaderResponseBuffer for InMemoryBodyResponseBuffer<B>
+{
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert_eq!(buffer_index, 0, "buffer_index `{}` is not zero", buffer_index);
+
+		&self.0.as_ref()[from_offset .. ]
+	}
+}
+
+impl<B: AsRef<[u8]>> InMemoryBodyResponseBuffer<B>
+{
+	/// `body` must not be empty.
+	#[inline(always)]
+	pub fn new(body: B) -> Self
+	{
+		debug_assert_ne!(body.as_ref().len(), 0, "body is empty");
+
+		InMemoryBodyResponseBuffer(body)
+	}
+
+	/// Length in bytes, for a `Content-Length` header.
+	#[inline(always)]
+	pub fn content_length(&self) -> usize
+	{
+		self.0.as_ref().len()
+	}
+}
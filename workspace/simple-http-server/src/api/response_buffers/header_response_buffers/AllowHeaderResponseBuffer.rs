@@ -7,6 +7,12 @@ pub struct AllowHeaderResponseBuffer(ArrayVec<[&'static [u8]; 11]>);
 
 impl HeaderResponseBuffer for AllowHeaderResponseBuffer
 {
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		self.0.len()
+	}
+
 	#[inline(always)]
 	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
 	{
@@ -14,7 +20,7 @@ impl HeaderResponseBuffer for AllowHeaderResponseBuffer
 
 		let buffer = (unsafe { self.0.get_unchecked(buffer_index) }).borrow();
 
-		debug_assert!(from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
 
 		&buffer[from_offset .. ]
 	}
@@ -24,20 +30,22 @@ impl AllowHeaderResponseBuffer
 {
 	/// Create a new instance.
 	///
-	/// `request_methods` can be empty.
-	pub fn new(mut request_methods: BTreeSet<ResponseCacheControlDirective>) -> Self
+	/// `request_methods` can be empty, in which case the resource does not allow any request methods (RFC 7231, Section 7.4.1).
+	pub fn new(request_methods: BTreeSet<RequestMethod>) -> Self
 	{
 		let mut buffers = ArrayVec::new();
 
-		buffers.push((b"Allow:"));
-
-		let mut request_methods = request_methods.iter();
-		let first_request_method = request_methods.next.unwrap();
-		buffers.push(first_request_method.buffer());
+		buffers.push(b"Allow:" as &'static [u8]);
 
-		for request_method in request_methods
+		let mut request_methods = request_methods.into_iter();
+		if let Some(first_request_method) = request_methods.next()
 		{
-			buffers.push(request_method.with_leading_comma_buffer());
+			buffers.push(first_request_method.buffer());
+
+			for request_method in request_methods
+			{
+				buffers.push(request_method.with_leading_comma_buffer());
+			}
 		}
 		buffers.push(b"\r\n");
 
@@ -7,14 +7,20 @@ pub struct CacheControlHeaderResponseBuffer(ArrayVec<[Cow<'static, [u8]>; 14]>);
 
 impl HeaderResponseBuffer for CacheControlHeaderResponseBuffer
 {
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		self.0.len()
+	}
+
 	#[inline(always)]
 	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
 	{
 		debug_assert!(buffer_index < self.0.len(), "buffer_index `{}` out of range", buffer_index);
 
-		let buffer = (unsafe { self.0.get_unchecked(buffer_index) }).borrow();
+		let buffer: &[u8] = (unsafe { self.0.get_unchecked(buffer_index) }).borrow();
 
-		debug_assert!(from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
 
 		&buffer[from_offset .. ]
 	}
@@ -23,7 +29,7 @@ impl HeaderResponseBuffer for CacheControlHeaderResponseBuffer
 impl CacheControlHeaderResponseBuffer
 {
 	/// Create a new instance.
-	pub fn new(mut response_cache_control_directives: BTreeSet<ResponseCacheControlDirective>) -> Self
+	pub fn new(response_cache_control_directives: BTreeSet<ResponseCacheControlDirective>) -> Self
 	{
 		debug_assert_ne!(response_cache_control_directives.len(), 0, "response_cache_control_directives should always contain at least one tag");
 
@@ -34,7 +40,7 @@ impl CacheControlHeaderResponseBuffer
 		buffers.push(Borrowed(b"Cache-Control:"));
 
 		let mut response_cache_control_directives = response_cache_control_directives.iter();
-		let first_response_cache_control_directive = response_cache_control_directives.next.unwrap();
+		let first_response_cache_control_directive = response_cache_control_directives.next().unwrap();
 		buffers.push(first_response_cache_control_directive.buffer());
 
 		for response_cache_control_directive in response_cache_control_directives
@@ -0,0 +1,97 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Represents a list of language tags for a `Content-Language` header (RFC 7231, Section 3.1.3.2), eg `Content-Language:de-DE,en-CA`.
+#[derive(Debug, Clone)]
+pub struct ContentLanguageHeaderResponseBuffer(Vec<Cow<'static, [u8]>>);
+
+impl HeaderResponseBuffer for ContentLanguageHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		self.0.len()
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert!(buffer_index < self.0.len(), "buffer_index `{}` out of range", buffer_index);
+
+		let buffer: &[u8] = (unsafe { self.0.get_unchecked(buffer_index) }).borrow();
+
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+
+		&buffer[from_offset .. ]
+	}
+}
+
+impl ContentLanguageHeaderResponseBuffer
+{
+	/// Create a new instance.
+	///
+	/// `language_tags` are in order of preference and must not be empty.
+	pub fn new(language_tags: Vec<Cow<'static, [u8]>>) -> Result<Self, HeaderResponseBufferError>
+	{
+		use self::Cow::Borrowed;
+
+		if language_tags.is_empty()
+		{
+			return Err(HeaderResponseBufferError::NoLanguageTags)
+		}
+
+		let mut buffers = Vec::with_capacity(language_tags.len() * 2 + 1);
+
+		buffers.push(Borrowed(b"Content-Language:" as &'static [u8]));
+
+		for (index, language_tag) in language_tags.into_iter().enumerate()
+		{
+			Self::validate_language_tag(&language_tag)?;
+
+			if index != 0
+			{
+				buffers.push(Borrowed(b"," as &'static [u8]));
+			}
+			buffers.push(language_tag);
+		}
+
+		buffers.push(Borrowed(b"\r\n" as &'static [u8]));
+
+		Ok(ContentLanguageHeaderResponseBuffer(buffers))
+	}
+
+	// RFC 5646, Section 2.1 is very complex, so only the simplified form of RFC 3066, Section 2.1 is validated, which all valid language tags also satisfy:-
+	// ```
+	// Language-Tag = Primary-subtag *( "-" Subtag )
+	// Primary-subtag = 1*8ALPHA
+	// Subtag = 1*8(ALPHA / DIGIT)
+	// ```
+	#[inline(always)]
+	fn validate_language_tag(language_tag: &[u8]) -> Result<(), HeaderResponseBufferError>
+	{
+		for (index, subtag) in language_tag.split(|&byte| byte == b'-').enumerate()
+		{
+			if subtag.is_empty() || subtag.len() > 8
+			{
+				return Err(HeaderResponseBufferError::InvalidLanguageTag)
+			}
+
+			let is_valid = if index == 0
+			{
+				subtag.iter().all(|byte| byte.is_ascii_alphabetic())
+			}
+			else
+			{
+				subtag.iter().all(|byte| byte.is_ascii_alphanumeric())
+			};
+
+			if !is_valid
+			{
+				return Err(HeaderResponseBufferError::InvalidLanguageTag)
+			}
+		}
+
+		Ok(())
+	}
+}
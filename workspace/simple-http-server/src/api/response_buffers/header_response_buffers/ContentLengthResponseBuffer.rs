@@ -8,6 +8,12 @@ pub struct ContentLengthHeaderResponseBuffer(Vec<u8>);
 
 impl HeaderResponseBuffer for ContentLengthHeaderResponseBuffer
 {
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		3
+	}
+
 	#[inline(always)]
 	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
 	{
@@ -24,7 +30,7 @@ impl HeaderResponseBuffer for ContentLengthHeaderResponseBuffer
 			_ => unreachable!(),
 		};
 
-		debug_assert!(from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
 
 		&buffer[from_offset .. ]
 	}
@@ -0,0 +1,61 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A `Content-Range` header (RFC 7233, Section 4.2).
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ContentRangeHeaderResponseBuffer(Vec<u8>);
+
+impl HeaderResponseBuffer for ContentRangeHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		3
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert!(buffer_index < 3, "buffer_index `{}` out of range", buffer_index);
+
+		let buffer: &[u8] = match buffer_index
+		{
+			0 => b"Content-Range:",
+
+			1 => self.0.as_slice(),
+
+			2 => b"\r\n",
+
+			_ => unreachable!(),
+		};
+
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+
+		&buffer[from_offset .. ]
+	}
+}
+
+impl ContentRangeHeaderResponseBuffer
+{
+	/// For a `206 Partial Content` response, eg `Content-Range:bytes 0-499/1234`.
+	#[inline(always)]
+	pub fn satisfied(byte_range: ByteRange, representation_length: u64) -> Self
+	{
+		ContentRangeHeaderResponseBuffer(Self::satisfied_field_value(byte_range, representation_length))
+	}
+
+	/// For a `416 Range Not Satisfiable` response, eg `Content-Range:bytes */1234`.
+	#[inline(always)]
+	pub fn unsatisfied(representation_length: u64) -> Self
+	{
+		ContentRangeHeaderResponseBuffer(format!("bytes */{}", representation_length).into_bytes())
+	}
+
+	/// Also used in the body part headers of a `multipart/byteranges` response.
+	#[inline(always)]
+	pub(crate) fn satisfied_field_value(byte_range: ByteRange, representation_length: u64) -> Vec<u8>
+	{
+		format!("bytes {}-{}/{}", byte_range.first_byte_position, byte_range.last_byte_position, representation_length).into_bytes()
+	}
+}
@@ -0,0 +1,121 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Represents a `Content-Security-Policy` or `Content-Security-Policy-Report-Only` header (Content Security Policy Level 3, Section 3), eg `Content-Security-Policy:default-src 'self';img-src 'self' https://images.example.com;upgrade-insecure-requests`.
+///
+/// Built up one directive at a time, starting with the first passed to `new()`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContentSecurityPolicyHeaderResponseBuffer
+{
+	report_only: bool,
+	directives: Vec<u8>,
+}
+
+impl HeaderResponseBuffer for ContentSecurityPolicyHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		3
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		let buffer: &[u8] = match buffer_index
+		{
+			0 => if self.report_only
+			{
+				b"Content-Security-Policy-Report-Only:"
+			}
+			else
+			{
+				b"Content-Security-Policy:"
+			},
+
+			1 => &self.directives[..],
+
+			2 => b"\r\n",
+
+			_ => panic!("buffer_index `{}` out of range", buffer_index),
+		};
+
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+
+		&buffer[from_offset .. ]
+	}
+}
+
+impl ContentSecurityPolicyHeaderResponseBuffer
+{
+	/// Create a new instance with a first directive, usually `ContentSecurityPolicyDirective::default_src`.
+	///
+	/// `report_only` policies are reported on but not enforced, which is useful when deploying a new policy.
+	#[inline(always)]
+	pub fn new(report_only: bool, directive: ContentSecurityPolicyDirective, sources: &[ContentSecurityPolicySource]) -> Result<Self, HeaderResponseBufferError>
+	{
+		Self
+		{
+			report_only,
+			directives: Vec::with_capacity(256),
+		}.directive(directive, sources)
+	}
+
+	/// Adds a directive with a list of sources, which must not be empty; `ContentSecurityPolicySource::none` must be the only source if present.
+	pub fn directive(mut self, directive: ContentSecurityPolicyDirective, sources: &[ContentSecurityPolicySource]) -> Result<Self, HeaderResponseBufferError>
+	{
+		if sources.is_empty()
+		{
+			return Err(HeaderResponseBufferError::NoContentSecurityPolicySources)
+		}
+
+		if sources.len() > 1 && sources.contains(&ContentSecurityPolicySource::none)
+		{
+			return Err(HeaderResponseBufferError::InvalidContentSecurityPolicySource)
+		}
+
+		self.start_directive(directive.buffer());
+
+		for source in sources.iter()
+		{
+			self.directives.push(b' ');
+			source.append_to(&mut self.directives)?;
+		}
+
+		Ok(self)
+	}
+
+	/// Adds the `upgrade-insecure-requests` directive, which makes a browser fetch `http:` subresources with `https:` instead.
+	#[inline(always)]
+	pub fn upgrade_insecure_requests(mut self) -> Self
+	{
+		self.start_directive(b"upgrade-insecure-requests");
+		self
+	}
+
+	/// Adds the `report-to` directive, naming a group of reporting endpoints configured with a `Reporting-Endpoints` header.
+	#[inline(always)]
+	pub fn report_to(mut self, group: &[u8]) -> Result<Self, HeaderResponseBufferError>
+	{
+		if !ContentTypeHeaderResponseBuffer::is_token(group)
+		{
+			return Err(HeaderResponseBufferError::InvalidReportingGroup)
+		}
+
+		self.start_directive(b"report-to");
+		self.directives.push(b' ');
+		self.directives.extend_from_slice(group);
+		Ok(self)
+	}
+
+	#[inline(always)]
+	fn start_directive(&mut self, directive_name: &[u8])
+	{
+		if !self.directives.is_empty()
+		{
+			self.directives.push(b';');
+		}
+		self.directives.extend_from_slice(directive_name);
+	}
+}
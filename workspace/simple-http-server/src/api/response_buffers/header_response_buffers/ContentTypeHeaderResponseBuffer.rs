@@ -0,0 +1,207 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Represents a media type and optional parameter for a `Content-Type` header (RFC 7231, Section 3.1.1.5), eg `Content-Type:text/html;charset=utf-8`.
+///
+/// Only the `charset` parameter (for `text/*`) and the `boundary` parameter (for `multipart/*`) are supported.
+/// Media types are case-insensitive but lower case is recommended.
+#[derive(Debug, Clone)]
+pub struct ContentTypeHeaderResponseBuffer(ArrayVec<[Cow<'static, [u8]>; 5]>);
+
+impl HeaderResponseBuffer for ContentTypeHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		self.0.len()
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert!(buffer_index < self.0.len(), "buffer_index `{}` out of range", buffer_index);
+
+		let buffer: &[u8] = (unsafe { self.0.get_unchecked(buffer_index) }).borrow();
+
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+
+		&buffer[from_offset .. ]
+	}
+}
+
+impl ContentTypeHeaderResponseBuffer
+{
+	/// `text/html;charset=utf-8`.
+	#[inline(always)]
+	pub fn text_html_utf8() -> Self
+	{
+		Self::with_parameter(Cow::Borrowed(b"text/html"), b";charset=", Cow::Borrowed(b"utf-8"))
+	}
+
+	/// `text/plain;charset=utf-8`.
+	#[inline(always)]
+	pub fn text_plain_utf8() -> Self
+	{
+		Self::with_parameter(Cow::Borrowed(b"text/plain"), b";charset=", Cow::Borrowed(b"utf-8"))
+	}
+
+	/// `application/json`; JSON is always UTF-8 and has no `charset` parameter (RFC 8259, Section 11).
+	#[inline(always)]
+	pub fn application_json() -> Self
+	{
+		Self::without_parameter(Cow::Borrowed(b"application/json"))
+	}
+
+	/// `text/event-stream`; server-sent events are always UTF-8.
+	#[inline(always)]
+	pub fn text_event_stream() -> Self
+	{
+		Self::without_parameter(Cow::Borrowed(b"text/event-stream"))
+	}
+
+	/// A media type without parameters, eg `image/png`.
+	#[inline(always)]
+	pub fn new(media_type: Cow<'static, [u8]>) -> Result<Self, HeaderResponseBufferError>
+	{
+		Self::validate_media_type(&media_type)?;
+
+		Ok(Self::without_parameter(media_type))
+	}
+
+	/// A `text/*` media type with a `charset` parameter, eg `text/css;charset=utf-8`.
+	#[inline(always)]
+	pub fn with_charset(media_type: Cow<'static, [u8]>, charset: Cow<'static, [u8]>) -> Result<Self, HeaderResponseBufferError>
+	{
+		use self::HeaderResponseBufferError::*;
+
+		Self::validate_media_type(&media_type)?;
+
+		if !media_type.starts_with(b"text/")
+		{
+			return Err(CharsetNotPermitted)
+		}
+
+		if !Self::is_token(&charset)
+		{
+			return Err(InvalidCharset)
+		}
+
+		Ok(Self::with_parameter(media_type, b";charset=", charset))
+	}
+
+	/// A `multipart/*` media type with a `boundary` parameter, eg `multipart/byteranges;boundary=0123456789abcdef`.
+	///
+	/// The `boundary` is restricted to those characters (RFC 2046, Section 5.1.1) which do not need to be quoted.
+	#[inline(always)]
+	pub fn with_boundary(media_type: Cow<'static, [u8]>, boundary: Cow<'static, [u8]>) -> Result<Self, HeaderResponseBufferError>
+	{
+		use self::HeaderResponseBufferError::*;
+
+		Self::validate_media_type(&media_type)?;
+
+		if !media_type.starts_with(b"multipart/")
+		{
+			return Err(BoundaryNotPermitted)
+		}
+
+		if !Self::is_valid_boundary(&boundary)
+		{
+			return Err(InvalidBoundary)
+		}
+
+		Ok(Self::with_parameter(media_type, b";boundary=", boundary))
+	}
+
+	/// `multipart/byteranges` with a `boundary` parameter.
+	///
+	/// `boundary` must be valid for `with_boundary()`.
+	#[inline(always)]
+	pub(crate) fn multipart_byteranges(boundary: Vec<u8>) -> Self
+	{
+		debug_assert!(Self::is_valid_boundary(&boundary), "boundary is invalid");
+
+		Self::with_parameter(Cow::Borrowed(b"multipart/byteranges"), b";boundary=", Cow::Owned(boundary))
+	}
+
+	/// Appends this header, including its terminating `CRLF`, eg for the body part headers of a `multipart/byteranges` body.
+	#[inline(always)]
+	pub(crate) fn write_header(&self, into: &mut Vec<u8>)
+	{
+		for buffer in self.0.iter()
+		{
+			into.extend_from_slice(buffer);
+		}
+	}
+
+	#[inline(always)]
+	fn without_parameter(media_type: Cow<'static, [u8]>) -> Self
+	{
+		let mut buffers = ArrayVec::new();
+		buffers.push(Cow::Borrowed(b"Content-Type:" as &'static [u8]));
+		buffers.push(media_type);
+		buffers.push(Cow::Borrowed(b"\r\n" as &'static [u8]));
+		ContentTypeHeaderResponseBuffer(buffers)
+	}
+
+	#[inline(always)]
+	fn with_parameter(media_type: Cow<'static, [u8]>, parameter_name: &'static [u8], parameter_value: Cow<'static, [u8]>) -> Self
+	{
+		let mut buffers = ArrayVec::new();
+		buffers.push(Cow::Borrowed(b"Content-Type:" as &'static [u8]));
+		buffers.push(media_type);
+		buffers.push(Cow::Borrowed(parameter_name));
+		buffers.push(parameter_value);
+		buffers.push(Cow::Borrowed(b"\r\n" as &'static [u8]));
+		ContentTypeHeaderResponseBuffer(buffers)
+	}
+
+	// ```
+	// media-type = type "/" subtype *( OWS ";" OWS parameter )
+	// type       = token
+	// subtype    = token
+	// ```
+	#[inline(always)]
+	fn validate_media_type(media_type: &[u8]) -> Result<(), HeaderResponseBufferError>
+	{
+		let mut split = media_type.splitn(2, |&byte| byte == b'/');
+		let type_ = split.next().unwrap();
+		match split.next()
+		{
+			Some(subtype) if Self::is_token(type_) && Self::is_token(subtype) => Ok(()),
+
+			_ => Err(HeaderResponseBufferError::InvalidMediaType),
+		}
+	}
+
+	#[inline(always)]
+	fn is_valid_boundary(boundary: &[u8]) -> bool
+	{
+		!boundary.is_empty() && boundary.len() <= 70 && boundary.iter().all(|&byte| match byte
+		{
+			b'A' ... b'Z' | b'a' ... b'z' | b'0' ... b'9' | b'\'' | b'+' | b'_' | b'-' | b'.' => true,
+
+			_ => false,
+		})
+	}
+
+	// RFC 7230, Section 3.2.6, Field Value Components:-
+	// ```
+	// token          = 1*tchar
+	//
+	// tchar          = "!" / "#" / "$" / "%" / "&" / "'" / "*"
+	//                / "+" / "-" / "." / "^" / "_" / "`" / "|" / "~"
+	//                / DIGIT / ALPHA
+	//                ; any VCHAR, except delimiters
+	// ```
+	#[inline(always)]
+	fn is_token(value: &[u8]) -> bool
+	{
+		!value.is_empty() && value.iter().all(|&byte| match byte
+		{
+			b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'.' | b'^' | b'_' | b'`' | b'|' | b'~' | b'0' ... b'9' | b'A' ... b'Z' | b'a' ... b'z' => true,
+
+			_ => false,
+		})
+	}
+}
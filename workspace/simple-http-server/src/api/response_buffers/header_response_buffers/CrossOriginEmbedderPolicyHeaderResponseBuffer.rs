@@ -0,0 +1,37 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Returns a header-value of `Cross-Origin-Embedder-Policy:<policy>`, eg `Cross-Origin-Embedder-Policy:require-corp`.
+///
+/// Prevents a document from loading cross-origin resources which have not granted it permission.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CrossOriginEmbedderPolicyHeaderResponseBuffer(pub CrossOriginEmbedderPolicy);
+
+impl HeaderResponseBuffer for CrossOriginEmbedderPolicyHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		3
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		let buffer: &[u8] = match buffer_index
+		{
+			0 => b"Cross-Origin-Embedder-Policy:",
+
+			1 => self.0.buffer(),
+
+			2 => b"\r\n",
+
+			_ => panic!("buffer_index `{}` out of range", buffer_index),
+		};
+
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+
+		&buffer[from_offset .. ]
+	}
+}
@@ -5,8 +5,14 @@
 /// A `Date` header response buffer.
 ///
 /// Default is for the time 'now'.
+#[derive(Copy, Clone)]
 pub struct DateHeaderResponseBuffer([u8; DateHeaderResponseBuffer::ArraySize]);
 
+thread_local!
+{
+	static CachedDateHeaderResponseBuffer: Cell<(i64, DateHeaderResponseBuffer)> = Cell::new(DateHeaderResponseBuffer::cache_entry(get_time()));
+}
+
 impl Default for DateHeaderResponseBuffer
 {
 	#[inline(always)]
@@ -34,48 +40,55 @@ impl DateHeaderResponseBuffer
 
 	/// For now.
 	#[inline(always)]
-	pub fn now(time: Tm)
+	pub fn now() -> Self
 	{
 		let time = now_utc();
 		Self::for_time(time)
 	}
 
-	/// Assumes the provided time is UTC.
+	/// For now, to within a second, from a copy of the `Date` header cached by the current thread.
+	///
+	/// The cache is refreshed by the poll loop of the current thread; a thread without one should use `now()`.
 	#[inline(always)]
-	pub fn for_time(time: Tm) -> Self
+	pub fn cached() -> Self
 	{
-		let week_day = time.three_letter_week_day();
+		CachedDateHeaderResponseBuffer.with(|cached| cached.get().1)
+	}
 
-		let month = time.three_letter_month();
+	/// Refreshes the `Date` header cached by the current thread if the second has changed.
+	///
+	/// Called each time a poll loop wakes up, before any events are handled, so that the clock is read once per wake up rather than once per response and the date is formatted at most once a second.
+	#[inline(always)]
+	pub(crate) fn refresh_cached()
+	{
+		let current_time = get_time();
 
-		let two_digit_year = three_digits_to_two_digits(four_digits_to_three_digits(tm.tm_year));
+		CachedDateHeaderResponseBuffer.with(|cached| if cached.get().0 != current_time.sec
+		{
+			cached.set(Self::cache_entry(current_time))
+		})
+	}
+
+	/// Assumes the provided time is UTC.
+	#[inline(always)]
+	pub fn for_time(time: Tm) -> Self
+	{
+		const Name: &'static [u8] = b"Date:";
 
 		// Date:<day-name>, <day> <month> <year> <hour>:<minute>:<second> GMT
 		// eg Date:Tue, 15 Nov 1994 08:12:31 GMT
 
-		DateHeaderResponseBuffer
-		(
-			[
-				b'D', b'a', b't', b'e', b':',
-				week_day[0], week_day[1], week_day[2],
-				b',',
-				b' ',
-				two_digits_to_first_digit(time.tm_mday), two_digits_to_second_digit(time.tm_mday),
-				b' ',
-				month[0], month[1], month[2],
-				b' ',
-				four_digits_to_first_digit(tm.tm_year), four_digits_to_second_digit(tm.tm_year), two_digits_to_first_digit(two_digit_year), two_digits_to_second_digit(two_digit_year),
-				b' ',
-				two_digits_to_first_digit(time.tm_hour), two_digits_to_second_digit(time.tm_hour),
-				b':',
-				two_digits_to_first_digit(time.tm_min), two_digits_to_second_digit(time.tm_min),
-				b':',
-				// NOTE: tm_sec can have the value 60 due to leap seconds.
-				two_digits_to_first_digit(time.tm_sec), two_digits_to_second_digit(time.tm_sec),
-				b' ',
-				b'G', b'M', b'T',
-				b'\r', b'\n',
-			]
-		)
+		let mut buffer = [0u8; Self::ArraySize];
+		buffer[.. Name.len()].copy_from_slice(Name);
+		buffer[Name.len() .. Self::ArraySize - 2].copy_from_slice(&imf_fixdate(&time));
+		buffer[Self::ArraySize - 2 .. ].copy_from_slice(b"\r\n");
+
+		DateHeaderResponseBuffer(buffer)
+	}
+
+	#[inline(always)]
+	fn cache_entry(current_time: Timespec) -> (i64, Self)
+	{
+		(current_time.sec, Self::for_time(at_utc(current_time)))
 	}
 }
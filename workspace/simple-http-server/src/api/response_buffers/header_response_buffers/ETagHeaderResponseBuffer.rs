@@ -2,35 +2,48 @@
 // Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
 
 
-/// Represents a list of tags for an `Content-Length` header.
+/// Represents an entity tag for an `ETag` header.
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct ETagHeaderResponseBuffer
 {
-	prefix: &'static [u8],
+	is_weak: bool,
 	e_tag: Vec<u8>,
 }
 
 impl HeaderResponseBuffer for ETagHeaderResponseBuffer
 {
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		4
+	}
+
 	#[inline(always)]
 	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
 	{
 		debug_assert!(buffer_index < 4, "buffer_index `{}` out of range", buffer_index);
 
-		let buffer = match buffer_index
+		let buffer: &[u8] = match buffer_index
 		{
-			0 => b"Content-Length:",
+			0 => b"ETag:",
 
-			1 => self.prefix,
+			1 => if self.is_weak
+			{
+				b"W/\""
+			}
+			else
+			{
+				b"\""
+			},
 
-			2 => self.0.as_slice(),
+			2 => self.e_tag.as_slice(),
 
-			3 => b"\"",
+			3 => b"\"\r\n",
 
 			_ => unreachable!(),
 		};
 
-		debug_assert!(from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
 
 		&buffer[from_offset .. ]
 	}
@@ -39,20 +52,25 @@ impl HeaderResponseBuffer for ETagHeaderResponseBuffer
 impl ETagHeaderResponseBuffer
 {
 	/// Create a new instance.
+	///
+	/// `e_tag` excludes the surrounding double quotes.
 	pub fn new(is_weak: bool, e_tag: Vec<u8>) -> Self
 	{
 		Self
 		{
-			prefix: if is_weak
-			{
-				b"W/\""
-			}
-			else
-			{
-				b"\""
-			},
-
+			is_weak,
 			e_tag,
 		}
 	}
+
+	/// The entity tag, eg for evaluating a `ConditionalRequest`.
+	#[inline(always)]
+	pub fn entity_tag(&self) -> EntityTag
+	{
+		EntityTag
+		{
+			is_weak: self.is_weak,
+			opaque_tag: &self.e_tag[..],
+		}
+	}
 }
@@ -13,6 +13,15 @@ pub trait HeaderResponseBuffer
 	}
 
 	/// * `buffer_index` is between 0 and `self.number_of_buffers() - 1` inclusive.
-	/// * `from_offset` is between 0 and the length of the buffer from `buffer_index` less one.
+	/// * `from_offset` is between 0 and the length of the buffer from `buffer_index` less one, or 0 for an empty buffer.
 	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8];
+
+	/// Length of the buffer from `buffer_index`, which may be zero, eg for an empty entity tag.
+	///
+	/// * `buffer_index` is between 0 and `self.number_of_buffers() - 1` inclusive.
+	#[inline(always)]
+	fn buffer_length(&self, buffer_index: usize) -> usize
+	{
+		self.report_buffer(buffer_index, 0).len()
+	}
 }
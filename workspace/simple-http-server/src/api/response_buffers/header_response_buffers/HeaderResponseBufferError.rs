@@ -0,0 +1,87 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Reasons a header response buffer could not be constructed from the values supplied.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HeaderResponseBufferError
+{
+	/// A media type was not of the form `type/subtype`, with both being tokens.
+	InvalidMediaType,
+
+	/// A `charset` parameter was supplied for a media type other than `text/*`.
+	CharsetNotPermitted,
+
+	/// A `charset` parameter value was not a token.
+	InvalidCharset,
+
+	/// A `boundary` parameter was supplied for a media type other than `multipart/*`.
+	BoundaryNotPermitted,
+
+	/// A `boundary` parameter value was not 1 to 70 of the characters `A-Z`, `a-z`, `0-9`, `'`, `+`, `_`, `-` and `.`.
+	InvalidBoundary,
+
+	/// There were no language tags.
+	NoLanguageTags,
+
+	/// A language tag was not of the form `1*8ALPHA *( "-" 1*8alphanum )`.
+	InvalidLanguageTag,
+
+	/// A URI-reference was empty or contained a character which is not permitted, eg a space.
+	InvalidUriReference,
+
+	/// There were no trailer field names.
+	NoTrailerFieldNames,
+
+	/// A trailer field name was not a token.
+	InvalidTrailerFieldName,
+
+	/// `preload` was requested without `includeSubDomains` and a `max-age` of at least one year.
+	StrictTransportSecurityPreloadNotPermitted,
+
+	/// A Content Security Policy directive had no sources.
+	NoContentSecurityPolicySources,
+
+	/// A Content Security Policy source was not valid, or `'none'` was not the only source.
+	InvalidContentSecurityPolicySource,
+
+	/// A reporting group was not a token.
+	InvalidReportingGroup,
+
+	/// A Permissions Policy feature was not a Structured Field dictionary key, eg `geolocation`.
+	InvalidPermissionsPolicyFeature,
+
+	/// A Permissions Policy origin was empty or contained a character which is not permitted, eg a double quote.
+	InvalidPermissionsPolicyOrigin,
+
+	/// A cookie name was not a token.
+	InvalidCookieName,
+
+	/// A cookie value contained a character which is not permitted, eg a space, comma or semicolon.
+	InvalidCookieValue,
+
+	/// A cookie `Path` was empty, did not start with `/` or contained a control character or semicolon.
+	InvalidCookiePath,
+
+	/// A cookie `Domain` was not a host name.
+	InvalidCookieDomain,
+
+	/// A cookie name had a `__Secure-` prefix without `Secure`, or a `__Host-` prefix without `Secure` and `Path=/`, or with a `Domain`.
+	CookiePrefixNotPermitted,
+
+	/// `SameSite=None` was requested without `Secure`.
+	SameSiteNoneNotPermitted,
+}
+
+impl Display for HeaderResponseBufferError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl error::Error for HeaderResponseBufferError
+{
+}
@@ -10,7 +10,7 @@ impl Default for LastModifiedHeaderResponseBuffer
 	#[inline(always)]
 	fn default() -> Self
 	{
-		Self::now()
+		Self::for_time(now_utc())
 	}
 }
 
@@ -34,38 +34,16 @@ impl LastModifiedHeaderResponseBuffer
 	#[inline(always)]
 	pub fn for_time(time: Tm) -> Self
 	{
-		let week_day = time.three_letter_week_day();
-
-		let month = time.three_letter_month();
-
-		let two_digit_year = three_digits_to_two_digits(four_digits_to_three_digits(tm.tm_year));
+		const Name: &'static [u8] = b"Last-Modified:";
 
 		// Last-Modified:<day-name>, <day> <month> <year> <hour>:<minute>:<second> GMT
-		// eg Date:Tue, 15 Nov 1994 08:12:31 GMT
+		// eg Last-Modified:Tue, 15 Nov 1994 08:12:31 GMT
+
+		let mut buffer = [0u8; Self::ArraySize];
+		buffer[.. Name.len()].copy_from_slice(Name);
+		buffer[Name.len() .. Self::ArraySize - 2].copy_from_slice(&imf_fixdate(&time));
+		buffer[Self::ArraySize - 2 .. ].copy_from_slice(b"\r\n");
 
-		LastModifiedHeaderResponseBuffer
-		(
-			[
-				b'L', b'a', b's', b't', b'-', b'M', b'o', b'd', b'i', b'f', b'i', b'e', b'd', b':',
-				week_day[0], week_day[1], week_day[2],
-				b',',
-				b' ',
-				two_digits_to_first_digit(time.tm_mday), two_digits_to_second_digit(time.tm_mday),
-				b' ',
-				month[0], month[1], month[2],
-				b' ',
-				four_digits_to_first_digit(tm.tm_year), four_digits_to_second_digit(tm.tm_year), two_digits_to_first_digit(two_digit_year), two_digits_to_second_digit(two_digit_year),
-				b' ',
-				two_digits_to_first_digit(time.tm_hour), two_digits_to_second_digit(time.tm_hour),
-				b':',
-				two_digits_to_first_digit(time.tm_min), two_digits_to_second_digit(time.tm_min),
-				b':',
-				// NOTE: tm_sec can have the value 60 due to leap seconds.
-				two_digits_to_first_digit(time.tm_sec), two_digits_to_second_digit(time.tm_sec),
-				b' ',
-				b'G', b'M', b'T',
-				b'\r', b'\n',
-			]
-		)
+		LastModifiedHeaderResponseBuffer(buffer)
 	}
 }
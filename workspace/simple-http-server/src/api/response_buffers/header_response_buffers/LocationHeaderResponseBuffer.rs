@@ -0,0 +1,84 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Represents a URI-reference for a `Location` header (RFC 7231, Section 7.1.2), eg for a redirect.
+///
+/// The URI-reference may be relative, eg `/other/path` or `../sibling?x=1`; it is resolved by the client against the target-uri of the request.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct LocationHeaderResponseBuffer(Cow<'static, [u8]>);
+
+impl HeaderResponseBuffer for LocationHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		3
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert!(buffer_index < 3, "buffer_index `{}` out of range", buffer_index);
+
+		let buffer: &[u8] = match buffer_index
+		{
+			0 => b"Location:",
+
+			1 => self.0.borrow(),
+
+			2 => b"\r\n",
+
+			_ => unreachable!(),
+		};
+
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+
+		&buffer[from_offset .. ]
+	}
+}
+
+impl LocationHeaderResponseBuffer
+{
+	/// Create a new instance.
+	///
+	/// `uri_reference` must already be percent-encoded; only the characters permitted in a URI (RFC 3986, Appendix A) are accepted.
+	pub fn new(uri_reference: Cow<'static, [u8]>) -> Result<Self, HeaderResponseBufferError>
+	{
+		if uri_reference.is_empty()
+		{
+			return Err(HeaderResponseBufferError::InvalidUriReference)
+		}
+
+		let mut remaining_hexadecimal_digits = 0;
+		for &byte in uri_reference.iter()
+		{
+			if remaining_hexadecimal_digits != 0
+			{
+				if !byte.is_ascii_hexdigit()
+				{
+					return Err(HeaderResponseBufferError::InvalidUriReference)
+				}
+				remaining_hexadecimal_digits -= 1;
+				continue
+			}
+
+			match byte
+			{
+				b'%' => remaining_hexadecimal_digits = 2,
+
+				// unreserved, gen-delims and sub-delims.
+				b'A' ... b'Z' | b'a' ... b'z' | b'0' ... b'9' | b'-' | b'.' | b'_' | b'~' | b':' | b'/' | b'?' | b'#' | b'[' | b']' | b'@' | b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' => (),
+
+				_ => return Err(HeaderResponseBufferError::InvalidUriReference),
+			}
+		}
+
+		if remaining_hexadecimal_digits != 0
+		{
+			return Err(HeaderResponseBufferError::InvalidUriReference)
+		}
+
+		Ok(LocationHeaderResponseBuffer(uri_reference))
+	}
+}
@@ -0,0 +1,74 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Represents a `Permissions-Policy` header (W3C Permissions Policy, Section 5.2), eg `Permissions-Policy:camera=(), geolocation=(self "https://maps.example.com")`.
+///
+/// Built up one feature at a time, starting with the first passed to `new()`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PermissionsPolicyHeaderResponseBuffer(Vec<u8>);
+
+impl HeaderResponseBuffer for PermissionsPolicyHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		3
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		let buffer: &[u8] = match buffer_index
+		{
+			0 => b"Permissions-Policy:",
+
+			1 => &self.0[..],
+
+			2 => b"\r\n",
+
+			_ => panic!("buffer_index `{}` out of range", buffer_index),
+		};
+
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+
+		&buffer[from_offset .. ]
+	}
+}
+
+impl PermissionsPolicyHeaderResponseBuffer
+{
+	/// Create a new instance with a first feature, eg `camera`.
+	#[inline(always)]
+	pub fn new(feature: &[u8], allowlist: &PermissionsPolicyAllowlist) -> Result<Self, HeaderResponseBufferError>
+	{
+		PermissionsPolicyHeaderResponseBuffer(Vec::with_capacity(128)).feature(feature, allowlist)
+	}
+
+	/// Adds a feature, eg `geolocation`, and the origins allowed to use it.
+	pub fn feature(mut self, feature: &[u8], allowlist: &PermissionsPolicyAllowlist) -> Result<Self, HeaderResponseBufferError>
+	{
+		// A Structured Field dictionary key (RFC 8941, Section 3.2): key = ( lcalpha / "*" ) *( lcalpha / DIGIT / "_" / "-" / "." / "*" ).
+		let is_valid = match feature.split_first()
+		{
+			None => false,
+
+			Some((&first, remainder)) => (first.is_ascii_lowercase() || first == b'*') && remainder.iter().all(|&byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_' || byte == b'-' || byte == b'.' || byte == b'*'),
+		};
+
+		if !is_valid
+		{
+			return Err(HeaderResponseBufferError::InvalidPermissionsPolicyFeature)
+		}
+
+		if !self.0.is_empty()
+		{
+			self.0.extend_from_slice(b", ");
+		}
+		self.0.extend_from_slice(feature);
+		self.0.push(b'=');
+		allowlist.append_to(&mut self.0)?;
+
+		Ok(self)
+	}
+}
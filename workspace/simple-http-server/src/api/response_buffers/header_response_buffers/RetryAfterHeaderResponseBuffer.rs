@@ -0,0 +1,58 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A `Retry-After` header (RFC 7231, Section 7.1.3), eg for a `503 Service Unavailable` or `429 Too Many Requests` response.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct RetryAfterHeaderResponseBuffer(Vec<u8>);
+
+impl HeaderResponseBuffer for RetryAfterHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		3
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert!(buffer_index < 3, "buffer_index `{}` out of range", buffer_index);
+
+		let buffer: &[u8] = match buffer_index
+		{
+			0 => b"Retry-After:",
+
+			1 => self.0.as_slice(),
+
+			2 => b"\r\n",
+
+			_ => unreachable!(),
+		};
+
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+
+		&buffer[from_offset .. ]
+	}
+}
+
+impl RetryAfterHeaderResponseBuffer
+{
+	/// Retry after a number of seconds, eg `Retry-After:120`.
+	#[inline(always)]
+	pub fn after_delay(delay_seconds: usize) -> Self
+	{
+		let mut vec = Vec::with_capacity_for_usize(0, 0);
+		vec.write_usize(delay_seconds);
+		RetryAfterHeaderResponseBuffer(vec)
+	}
+
+	/// Retry after a time, eg `Retry-After:Fri, 31 Dec 1999 23:59:59 GMT`.
+	///
+	/// The time is converted to UTC if necessary.
+	#[inline(always)]
+	pub fn at(time: Tm) -> Self
+	{
+		RetryAfterHeaderResponseBuffer(format!("{}", time.to_utc().rfc822()).into_bytes())
+	}
+}
@@ -0,0 +1,212 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Represents a `Set-Cookie` header (RFC 6265, Section 4.1), eg `Set-Cookie:__Host-session=38afes7a8;Secure;HttpOnly;SameSite=Lax;Path=/`.
+///
+/// There is one `Set-Cookie` header per cookie; they can not be combined.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SetCookieHeaderResponseBuffer(Vec<u8>);
+
+impl HeaderResponseBuffer for SetCookieHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert_eq!(buffer_index, 0, "buffer_index `{}` out of range", buffer_index);
+		debug_assert!(from_offset < self.0.len(), "from_offset `{}` out of range", from_offset);
+
+		&self.0[from_offset .. ]
+	}
+}
+
+impl SetCookieHeaderResponseBuffer
+{
+	/// Create a new instance.
+	///
+	/// `name` must be a token and `value` must be zero or more cookie-octets, optionally enclosed in double quotes; neither is encoded.
+	///
+	/// The cookie prefixes (RFC 6265bis, Section 4.1.3) are enforced, as a browser would otherwise silently ignore the cookie: a name starting `__Secure-` requires `Secure`; a name starting `__Host-` requires `Secure` and `Path=/` and forbids `Domain`.
+	pub fn new(name: &[u8], value: &[u8], attributes: &SetCookieAttributes) -> Result<Self, HeaderResponseBufferError>
+	{
+		use self::HeaderResponseBufferError::*;
+
+		if !ContentTypeHeaderResponseBuffer::is_token(name)
+		{
+			return Err(InvalidCookieName)
+		}
+
+		if !Self::is_cookie_value(value)
+		{
+			return Err(InvalidCookieValue)
+		}
+
+		if let Some(ref path) = attributes.path
+		{
+			if !Self::is_path(path)
+			{
+				return Err(InvalidCookiePath)
+			}
+		}
+
+		if let Some(ref domain) = attributes.domain
+		{
+			if !Self::is_domain(domain)
+			{
+				return Err(InvalidCookieDomain)
+			}
+		}
+
+		if name.starts_with(b"__Secure-") && !attributes.secure
+		{
+			return Err(CookiePrefixNotPermitted)
+		}
+
+		if name.starts_with(b"__Host-")
+		{
+			let path_is_root = match attributes.path
+			{
+				Some(ref path) => &path[..] == b"/",
+
+				None => false,
+			};
+
+			if !attributes.secure || !path_is_root || attributes.domain.is_some()
+			{
+				return Err(CookiePrefixNotPermitted)
+			}
+		}
+
+		if attributes.same_site == Some(SameSite::None) && !attributes.secure
+		{
+			return Err(SameSiteNoneNotPermitted)
+		}
+
+		let mut buffer = Vec::with_capacity(128);
+		buffer.extend_from_slice(b"Set-Cookie:");
+		buffer.extend_from_slice(name);
+		buffer.push(b'=');
+		buffer.extend_from_slice(value);
+
+		if attributes.secure
+		{
+			buffer.extend_from_slice(b";Secure");
+		}
+
+		if attributes.http_only
+		{
+			buffer.extend_from_slice(b";HttpOnly");
+		}
+
+		if let Some(same_site) = attributes.same_site
+		{
+			buffer.extend_from_slice(b";SameSite=");
+			buffer.extend_from_slice(same_site.buffer());
+		}
+
+		if let Some(maximum_age_in_seconds) = attributes.maximum_age_in_seconds
+		{
+			buffer.extend_from_slice(format!(";Max-Age={}", maximum_age_in_seconds).as_bytes());
+		}
+
+		if let Some(ref expires) = attributes.expires
+		{
+			buffer.extend_from_slice(b";Expires=");
+			buffer.extend_from_slice(&imf_fixdate(expires));
+		}
+
+		if let Some(ref path) = attributes.path
+		{
+			buffer.extend_from_slice(b";Path=");
+			buffer.extend_from_slice(path);
+		}
+
+		if let Some(ref domain) = attributes.domain
+		{
+			buffer.extend_from_slice(b";Domain=");
+			buffer.extend_from_slice(domain);
+		}
+
+		buffer.extend_from_slice(b"\r\n");
+
+		Ok(SetCookieHeaderResponseBuffer(buffer))
+	}
+
+	/// A `Set-Cookie` header which tells a browser to delete the cookie `name`.
+	///
+	/// `attributes` must have the same `path` and `domain` as when the cookie was set; its `maximum_age_in_seconds` and `expires` are replaced.
+	#[inline(always)]
+	pub fn delete(name: &[u8], attributes: &SetCookieAttributes) -> Result<Self, HeaderResponseBufferError>
+	{
+		let attributes = SetCookieAttributes
+		{
+			maximum_age_in_seconds: Some(0),
+			expires: Some(at_utc(Timespec::new(0, 0))),
+			.. attributes.clone()
+		};
+
+		Self::new(name, b"", &attributes)
+	}
+
+	// RFC 6265, Section 4.1.1:-
+	// ```
+	// cookie-value      = *cookie-octet / ( DQUOTE *cookie-octet DQUOTE )
+	// cookie-octet      = %x21 / %x23-2B / %x2D-3A / %x3C-5B / %x5D-7E
+	// ```
+	#[inline(always)]
+	fn is_cookie_value(value: &[u8]) -> bool
+	{
+		#[inline(always)]
+		fn is_cookie_octet(byte: u8) -> bool
+		{
+			match byte
+			{
+				0x21 | 0x23 ... 0x2B | 0x2D ... 0x3A | 0x3C ... 0x5B | 0x5D ... 0x7E => true,
+
+				_ => false,
+			}
+		}
+
+		let unquoted = if value.len() >= 2 && value[0] == b'"' && value[value.len() - 1] == b'"'
+		{
+			&value[1 .. value.len() - 1]
+		}
+		else
+		{
+			value
+		};
+
+		unquoted.iter().all(|&byte| is_cookie_octet(byte))
+	}
+
+	// RFC 6265, Section 4.1.1:-
+	// ```
+	// path-value        = <any CHAR except CTLs or ";">
+	// ```
+	//
+	// A path which does not start with `/` is ignored by a browser (RFC 6265, Section 5.2.4).
+	#[inline(always)]
+	fn is_path(path: &[u8]) -> bool
+	{
+		path.first() == Some(&b'/') && path.iter().all(|&byte| byte > 0x1F && byte < 0x7F && byte != b';')
+	}
+
+	// RFC 6265, Section 4.1.1, a `<subdomain>` (RFC 1034, Section 3.5, as relaxed by RFC 1123, Section 2.1), optionally with a leading `.` which a browser ignores.
+	#[inline(always)]
+	fn is_domain(domain: &[u8]) -> bool
+	{
+		let domain = if domain.first() == Some(&b'.')
+		{
+			&domain[1 .. ]
+		}
+		else
+		{
+			domain
+		};
+
+		!domain.is_empty() && domain.split(|&byte| byte == b'.').all(|label|
+		{
+			!label.is_empty() && label.len() <= 63 && label[0] != b'-' && label[label.len() - 1] != b'-' && label.iter().all(|&byte| byte.is_ascii_alphanumeric() || byte == b'-')
+		})
+	}
+}
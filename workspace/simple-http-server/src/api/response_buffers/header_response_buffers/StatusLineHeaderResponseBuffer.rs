@@ -2,18 +2,25 @@
 // Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
 
 
-/// A status line response buffer with trailing CRLF, eg `HTTP/1.1 200 X`.
-///
-/// Status message, which is deprecated, is always `X`.
+/// A status line response buffer with trailing CRLF, eg `HTTP/1.1 200 OK`.
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub struct StatusLineHeaderResponseBuffer([u8; StatusLineHeaderResponseBuffer::ArraySize]);
+pub struct StatusLineHeaderResponseBuffer(pub StatusCode);
 
 impl Default for StatusLineHeaderResponseBuffer
 {
 	#[inline(always)]
 	fn default() -> Self
 	{
-		ResponseStatusLineBuffer::Http11Ok
+		StatusLineHeaderResponseBuffer::Http11Ok
+	}
+}
+
+impl From<StatusCode> for StatusLineHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn from(status_code: StatusCode) -> Self
+	{
+		StatusLineHeaderResponseBuffer(status_code)
 	}
 }
 
@@ -23,55 +30,59 @@ impl HeaderResponseBuffer for StatusLineHeaderResponseBuffer
 	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
 	{
 		debug_assert_eq!(buffer_index, 0, "buffer_index `{}` out of range", buffer_index);
-		debug_assert!(from_offset < Self::ArraySize, "from_offset `{}` out of range", from_offset);
 
-		&self.0[from_offset .. ]
+		let buffer = self.0.status_line();
+
+		debug_assert!(from_offset < buffer.len(), "from_offset `{}` out of range", from_offset);
+
+		&buffer[from_offset .. ]
 	}
 }
 
 impl StatusLineHeaderResponseBuffer
 {
-	const ArraySize: usize = 16;
+	/// `HTTP/1.1 100 Continue`.
+	pub const Http11Continue: Self = StatusLineHeaderResponseBuffer(StatusCode::Continue);
 
-	pub const Http11Ok: Self = Self::new(200);
+	/// `HTTP/1.1 200 OK`.
+	pub const Http11Ok: Self = StatusLineHeaderResponseBuffer(StatusCode::Ok);
 
-	#[inline(always)]
-	const fn new(status_code: u16) -> Self
-	{
-		const fn last_two_digits(status_code: u16) -> u16
-		{
-			status_code - ((status_code / 100) * 100)
-		}
-
-		const fn last_digit(status_code: u16) -> u16
-		{
-			last_two_digits(status_code) - ((last_two_digits(status_code) / 10) * 10)
-		}
-
-		const fn digit_to_ascii(digit: u16) -> u8
-		{
-			const AsciiZero: u8 = 48;
-
-			digit as u8 + AsciiZero
-		}
-
-		const fn digits_to_digit(digits: u16, scalar: u16) -> u16
-		{
-			digits / scalar
-		}
-
-		Self
-		(
-			[
-				b'H', b'T', b'T', b'P', b'/', b'1', b'.', b'1',
-				b' ',
-				digit_to_ascii(digits_to_digit(status_code, 100)),
-				digit_to_ascii(digits_to_digit(last_two_digits(status_code), 10)),
-				digit_to_ascii(digits_to_digit(last_digit(status_code), 1)),
-				b' ',
-				b'X',
-				b'\r', b'\n',
-			]
-		)
-	}
+	/// `HTTP/1.1 206 Partial Content`.
+	pub const Http11PartialContent: Self = StatusLineHeaderResponseBuffer(StatusCode::PartialContent);
+
+	/// `HTTP/1.1 304 Not Modified`.
+	pub const Http11NotModified: Self = StatusLineHeaderResponseBuffer(StatusCode::NotModified);
+
+	/// `HTTP/1.1 400 Bad Request`.
+	pub const Http11BadRequest: Self = StatusLineHeaderResponseBuffer(StatusCode::BadRequest);
+
+	/// `HTTP/1.1 405 Method Not Allowed`.
+	pub const Http11MethodNotAllowed: Self = StatusLineHeaderResponseBuffer(StatusCode::MethodNotAllowed);
+
+	/// `HTTP/1.1 413 Payload Too Large`.
+	pub const Http11PayloadTooLarge: Self = StatusLineHeaderResponseBuffer(StatusCode::PayloadTooLarge);
+
+	/// `HTTP/1.1 414 URI Too Long`.
+	pub const Http11UriTooLong: Self = StatusLineHeaderResponseBuffer(StatusCode::UriTooLong);
+
+	/// `HTTP/1.1 412 Precondition Failed`.
+	pub const Http11PreconditionFailed: Self = StatusLineHeaderResponseBuffer(StatusCode::PreconditionFailed);
+
+	/// `HTTP/1.1 416 Range Not Satisfiable`.
+	pub const Http11RangeNotSatisfiable: Self = StatusLineHeaderResponseBuffer(StatusCode::RangeNotSatisfiable);
+
+	/// `HTTP/1.1 417 Expectation Failed`.
+	pub const Http11ExpectationFailed: Self = StatusLineHeaderResponseBuffer(StatusCode::ExpectationFailed);
+
+	/// `HTTP/1.1 421 Misdirected Request`.
+	pub const Http11MisdirectedRequest: Self = StatusLineHeaderResponseBuffer(StatusCode::MisdirectedRequest);
+
+	/// `HTTP/1.1 431 Request Header Fields Too Large`.
+	pub const Http11RequestHeaderFieldsTooLarge: Self = StatusLineHeaderResponseBuffer(StatusCode::RequestHeaderFieldsTooLarge);
+
+	/// `HTTP/1.1 501 Not Implemented`.
+	pub const Http11NotImplemented: Self = StatusLineHeaderResponseBuffer(StatusCode::NotImplemented);
+
+	/// `HTTP/1.1 505 HTTP Version Not Supported`.
+	pub const Http11HttpVersionNotSupported: Self = StatusLineHeaderResponseBuffer(StatusCode::HttpVersionNotSupported);
 }
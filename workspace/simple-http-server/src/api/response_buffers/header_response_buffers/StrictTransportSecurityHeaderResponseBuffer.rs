@@ -0,0 +1,54 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Represents a `Strict-Transport-Security` header (RFC 6797, Section 6.1), eg `Strict-Transport-Security:max-age=31536000;includeSubDomains;preload`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrictTransportSecurityHeaderResponseBuffer(Vec<u8>);
+
+impl HeaderResponseBuffer for StrictTransportSecurityHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert_eq!(buffer_index, 0, "buffer_index `{}` out of range", buffer_index);
+		debug_assert!(from_offset < self.0.len(), "from_offset `{}` out of range", from_offset);
+
+		&self.0[from_offset .. ]
+	}
+}
+
+impl StrictTransportSecurityHeaderResponseBuffer
+{
+	/// The minimum `max-age` for inclusion in browsers' preload lists, one year.
+	pub const MinimumPreloadMaximumAgeInSeconds: u32 = 31_536_000;
+
+	/// Create a new instance.
+	///
+	/// A `max_age_in_seconds` of zero tells a browser to forget the host is a HSTS host.
+	///
+	/// `preload` requires `include_sub_domains` and a `max_age_in_seconds` of at least `MinimumPreloadMaximumAgeInSeconds`, as browsers' preload lists do; it is hard to undo once a host has been added to them.
+	pub fn new(max_age_in_seconds: u32, include_sub_domains: bool, preload: bool) -> Result<Self, HeaderResponseBufferError>
+	{
+		if preload && (!include_sub_domains || max_age_in_seconds < Self::MinimumPreloadMaximumAgeInSeconds)
+		{
+			return Err(HeaderResponseBufferError::StrictTransportSecurityPreloadNotPermitted)
+		}
+
+		let mut buffer = format!("Strict-Transport-Security:max-age={}", max_age_in_seconds).into_bytes();
+
+		if include_sub_domains
+		{
+			buffer.extend_from_slice(b";includeSubDomains");
+		}
+
+		if preload
+		{
+			buffer.extend_from_slice(b";preload");
+		}
+
+		buffer.extend_from_slice(b"\r\n");
+
+		Ok(StrictTransportSecurityHeaderResponseBuffer(buffer))
+	}
+}
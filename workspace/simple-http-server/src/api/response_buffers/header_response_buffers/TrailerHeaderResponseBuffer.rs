@@ -0,0 +1,68 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Represents a list of field names for a `Trailer` header (RFC 7230, Section 4.4), eg `Trailer:ETag,Server-Timing`.
+///
+/// Announces the fields which will be sent in the trailer of a chunked body.
+#[derive(Debug, Clone)]
+pub struct TrailerHeaderResponseBuffer(Vec<Cow<'static, [u8]>>);
+
+impl HeaderResponseBuffer for TrailerHeaderResponseBuffer
+{
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		self.0.len()
+	}
+
+	#[inline(always)]
+	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
+	{
+		debug_assert!(buffer_index < self.0.len(), "buffer_index `{}` out of range", buffer_index);
+
+		let buffer: &[u8] = (unsafe { self.0.get_unchecked(buffer_index) }).borrow();
+
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+
+		&buffer[from_offset .. ]
+	}
+}
+
+impl TrailerHeaderResponseBuffer
+{
+	/// Create a new instance.
+	///
+	/// `field_names` must not be empty, and must not include fields needed to frame or route the message, eg `Content-Length` or `Host` (RFC 7230, Section 4.1.2).
+	pub fn new(field_names: Vec<Cow<'static, [u8]>>) -> Result<Self, HeaderResponseBufferError>
+	{
+		use self::Cow::Borrowed;
+
+		if field_names.is_empty()
+		{
+			return Err(HeaderResponseBufferError::NoTrailerFieldNames)
+		}
+
+		let mut buffers = Vec::with_capacity(field_names.len() * 2 + 1);
+
+		buffers.push(Borrowed(b"Trailer:" as &'static [u8]));
+
+		for (index, field_name) in field_names.into_iter().enumerate()
+		{
+			if !ContentTypeHeaderResponseBuffer::is_token(&field_name)
+			{
+				return Err(HeaderResponseBufferError::InvalidTrailerFieldName)
+			}
+
+			if index != 0
+			{
+				buffers.push(Borrowed(b"," as &'static [u8]));
+			}
+			buffers.push(field_name);
+		}
+
+		buffers.push(Borrowed(b"\r\n" as &'static [u8]));
+
+		Ok(TrailerHeaderResponseBuffer(buffers))
+	}
+}
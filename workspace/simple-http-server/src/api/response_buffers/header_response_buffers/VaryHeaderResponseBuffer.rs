@@ -7,14 +7,20 @@ pub struct VaryHeaderResponseBuffer(Vec<&'static [u8]>);
 
 impl HeaderResponseBuffer for VaryHeaderResponseBuffer
 {
+	#[inline(always)]
+	fn number_of_buffers(&self) -> usize
+	{
+		self.0.len()
+	}
+
 	#[inline(always)]
 	fn report_buffer(&self, buffer_index: usize, from_offset: usize) -> &[u8]
 	{
 		debug_assert!(buffer_index < self.0.len(), "buffer_index `{}` out of range", buffer_index);
 
-		let buffer = (unsafe { self.0.get_unchecked(buffer_index) }).borrow();
+		let buffer = *(unsafe { self.0.get_unchecked(buffer_index) });
 
-		debug_assert!(from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
 
 		&buffer[from_offset .. ]
 	}
@@ -23,16 +29,16 @@ impl HeaderResponseBuffer for VaryHeaderResponseBuffer
 impl VaryHeaderResponseBuffer
 {
 	/// Create a new instance.
-	pub fn new(mut headers: BTreeSet<HeaderName>) -> Self
+	pub fn new(headers: BTreeSet<HeaderName>) -> Self
 	{
 		debug_assert_ne!(headers.len(), 0, "headers should always contain at least one header name");
 
-		let mut buffers = ArrayVec::new();
+		let mut buffers = Vec::with_capacity(headers.len() + 2);
 
-		buffers.push((b"Vary:"));
+		buffers.push(b"Vary:" as &'static [u8]);
 
 		let mut headers = headers.iter();
-		let first_header = headers.next.unwrap();
+		let first_header = headers.next().unwrap();
 		buffers.push(first_header.name);
 
 		for header in headers
@@ -43,4 +49,11 @@ impl VaryHeaderResponseBuffer
 
 		VaryHeaderResponseBuffer(buffers)
 	}
+
+	/// `Vary:Accept-Encoding`.
+	#[inline(always)]
+	pub fn accept_encoding() -> Self
+	{
+		VaryHeaderResponseBuffer(vec![b"Vary:" as &'static [u8], HeaderName::AcceptEncoding.name, b"\r\n"])
+	}
 }
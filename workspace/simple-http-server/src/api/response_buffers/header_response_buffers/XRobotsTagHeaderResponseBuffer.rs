@@ -19,7 +19,7 @@ impl HeaderResponseBuffer for XRobotsTagHeaderResponseBuffer
 
 		let buffer = (unsafe { self.0.get_unchecked(buffer_index) }).borrow();
 
-		debug_assert!(from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
+		debug_assert!(from_offset == 0 || from_offset < buffer.len(), "from_offset `{}` out of range for buffer_index `{}`", from_offset, buffer_index);
 
 		&buffer[from_offset .. ]
 	}
@@ -10,20 +10,42 @@ use self::time::*;
 pub(crate) mod time;
 
 
+#[cfg(test)] mod tests;
+
+
 include!("AccessControlAllowHeadersHeaderResponseBuffer.rs");
 include!("AccessControlAllowMethodsHeaderResponseBuffer.rs");
 include!("AccessControlMaxAgeResponseBuffer.rs");
 include!("AllowHeaderResponseBuffer.rs");
+include!("BytesAcceptRangesHeaderResponseBuffer.rs");
 include!("CacheControlHeaderResponseBuffer.rs");
+include!("ChunkedTransferEncodingHeaderResponseBuffer.rs");
+include!("CloseConnectionHeaderResponseBuffer.rs");
+include!("ContentEncodingHeaderResponseBuffer.rs");
+include!("ContentLanguageHeaderResponseBuffer.rs");
 include!("ContentLengthHeaderResponseBuffer.rs");
+include!("ContentRangeHeaderResponseBuffer.rs");
+include!("ContentSecurityPolicyHeaderResponseBuffer.rs");
+include!("ContentTypeHeaderResponseBuffer.rs");
+include!("CrossOriginEmbedderPolicyHeaderResponseBuffer.rs");
+include!("CrossOriginOpenerPolicyHeaderResponseBuffer.rs");
+include!("CrossOriginResourcePolicyHeaderResponseBuffer.rs");
 include!("DateHeaderResponseBuffer.rs");
 include!("DenyXFrameOptionsHeaderResponseBuffer.rs");
 include!("EndOfHeadersHeaderResponseBuffer.rs");
 include!("ETagHeaderResponseBuffer.rs");
 include!("HeaderResponseBuffer.rs");
+include!("HeaderResponseBufferError.rs");
 include!("LastModifiedHeaderResponseBuffer.rs");
+include!("LocationHeaderResponseBuffer.rs");
 include!("ModeBlockXXSSProtectionHeaderResponseBuffer.rs");
 include!("NosniffXContentTypeOptionsHeaderResponseBuffer.rs");
+include!("PermissionsPolicyHeaderResponseBuffer.rs");
+include!("ReferrerPolicyHeaderResponseBuffer.rs");
+include!("RetryAfterHeaderResponseBuffer.rs");
+include!("SetCookieHeaderResponseBuffer.rs");
 include!("StatusLineHeaderResponseBuffer.rs");
+include!("StrictTransportSecurityHeaderResponseBuffer.rs");
+include!("TrailerHeaderResponseBuffer.rs");
 include!("VaryHeaderResponseBuffer.rs");
 include!("XRobotsTagHeaderResponseBuffer.rs");
@@ -0,0 +1,156 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn set_cookie(name: &[u8], value: &[u8], attributes: &SetCookieAttributes) -> Result<Vec<u8>, HeaderResponseBufferError>
+{
+	SetCookieHeaderResponseBuffer::new(name, value, attributes).map(|set_cookie| set_cookie.0)
+}
+
+fn with_path(path: &'static [u8]) -> SetCookieAttributes
+{
+	SetCookieAttributes
+	{
+		path: Some(Cow::Borrowed(path)),
+		.. SetCookieAttributes::default()
+	}
+}
+
+fn with_domain(domain: &'static [u8]) -> SetCookieAttributes
+{
+	SetCookieAttributes
+	{
+		domain: Some(Cow::Borrowed(domain)),
+		.. SetCookieAttributes::default()
+	}
+}
+
+fn insecure() -> SetCookieAttributes
+{
+	SetCookieAttributes
+	{
+		secure: false,
+		.. SetCookieAttributes::default()
+	}
+}
+
+#[test]
+fn set_cookie_has_default_attributes()
+{
+	assert_eq!(set_cookie(b"session", b"38afes7a8", &SetCookieAttributes::default()), Ok(b"Set-Cookie:session=38afes7a8;Secure;HttpOnly;SameSite=Lax\r\n".to_vec()));
+}
+
+#[test]
+fn set_cookie_has_all_attributes()
+{
+	let attributes = SetCookieAttributes
+	{
+		secure: false,
+		http_only: false,
+		same_site: Some(SameSite::Strict),
+		maximum_age_in_seconds: Some(3600),
+		expires: None,
+		path: Some(Cow::Borrowed(b"/a path")),
+		domain: Some(Cow::Borrowed(b".example.com")),
+	};
+
+	assert_eq!(set_cookie(b"theme", b"\"dark\"", &attributes), Ok(b"Set-Cookie:theme=\"dark\";SameSite=Strict;Max-Age=3600;Path=/a path;Domain=.example.com\r\n".to_vec()));
+}
+
+#[test]
+fn deleted_cookie_has_expired()
+{
+	let deleted = SetCookieHeaderResponseBuffer::delete(b"session", &with_path(b"/")).map(|set_cookie| set_cookie.0);
+	assert_eq!(deleted, Ok(b"Set-Cookie:session=;Secure;HttpOnly;SameSite=Lax;Max-Age=0;Expires=Thu, 01 Jan 1970 00:00:00 GMT;Path=/\r\n".to_vec()));
+}
+
+#[test]
+fn cookie_name_must_be_a_token()
+{
+	for name in [&b""[..], b"a b", b"a=b", b"a;b", b"a\"b", b"a,b", b"a\x7Fb"].iter()
+	{
+		assert_eq!(set_cookie(name, b"1", &SetCookieAttributes::default()), Err(HeaderResponseBufferError::InvalidCookieName), "{:?}", name);
+	}
+}
+
+#[test]
+fn cookie_value_must_be_cookie_octets()
+{
+	for value in [&b"a b"[..], b"a,b", b"a;b", b"a\\b", b"\"", b"\"a", b"a\"", b"\"a\"b\"", b"\xFF"].iter()
+	{
+		assert_eq!(set_cookie(b"a", value, &SetCookieAttributes::default()), Err(HeaderResponseBufferError::InvalidCookieValue), "{:?}", value);
+	}
+
+	for value in [&b""[..], b"\"\"", b"\"a\"", b"!#$%&'()*+-./0123456789:<=>?@AZ[]^_`az{|}~"].iter()
+	{
+		assert!(set_cookie(b"a", value, &SetCookieAttributes::default()).is_ok(), "{:?}", value);
+	}
+}
+
+#[test]
+fn cookie_path_must_be_absolute_without_control_characters_or_semicolons()
+{
+	for path in [&b""[..], b"relative", b"/a;b", b"/a\x7Fb", b"/a\tb", b"/a\r\nb"].iter()
+	{
+		assert_eq!(set_cookie(b"a", b"1", &with_path(path)), Err(HeaderResponseBufferError::InvalidCookiePath), "{:?}", path);
+	}
+}
+
+#[test]
+fn cookie_domain_must_be_a_host_name()
+{
+	for domain in [&b""[..], b".", b"..example.com", b"example..com", b"example.com.", b"-example.com", b"example-.com", b"exa_mple.com", b"example.com:443", b"\xC3\xA9.com"].iter()
+	{
+		assert_eq!(set_cookie(b"a", b"1", &with_domain(domain)), Err(HeaderResponseBufferError::InvalidCookieDomain), "{:?}", domain);
+	}
+
+	for domain in [&b"example.com"[..], b".example.com", b"a-b.example.com", b"localhost", b"123.example"].iter()
+	{
+		assert!(set_cookie(b"a", b"1", &with_domain(domain)).is_ok(), "{:?}", domain);
+	}
+}
+
+#[test]
+fn cookie_domain_label_must_not_be_longer_than_63_bytes()
+{
+	let mut domain = vec![b'a'; 63];
+	domain.extend_from_slice(b".com");
+	assert!(SetCookieHeaderResponseBuffer::new(b"a", b"1", &SetCookieAttributes { domain: Some(Cow::Owned(domain.clone())), .. SetCookieAttributes::default() }).is_ok());
+
+	domain.insert(0, b'a');
+	assert_eq!(SetCookieHeaderResponseBuffer::new(b"a", b"1", &SetCookieAttributes { domain: Some(Cow::Owned(domain)), .. SetCookieAttributes::default() }), Err(HeaderResponseBufferError::InvalidCookieDomain));
+}
+
+#[test]
+fn secure_prefix_requires_secure()
+{
+	assert_eq!(set_cookie(b"__Secure-session", b"1", &insecure()), Err(HeaderResponseBufferError::CookiePrefixNotPermitted));
+	assert!(set_cookie(b"__Secure-session", b"1", &SetCookieAttributes::default()).is_ok());
+}
+
+#[test]
+fn host_prefix_requires_secure_and_root_path_without_domain()
+{
+	assert!(set_cookie(b"__Host-session", b"1", &with_path(b"/")).is_ok());
+
+	assert_eq!(set_cookie(b"__Host-session", b"1", &SetCookieAttributes::default()), Err(HeaderResponseBufferError::CookiePrefixNotPermitted));
+	assert_eq!(set_cookie(b"__Host-session", b"1", &with_path(b"/path")), Err(HeaderResponseBufferError::CookiePrefixNotPermitted));
+	assert_eq!(set_cookie(b"__Host-session", b"1", &SetCookieAttributes { secure: false, .. with_path(b"/") }), Err(HeaderResponseBufferError::CookiePrefixNotPermitted));
+	assert_eq!(set_cookie(b"__Host-session", b"1", &SetCookieAttributes { domain: Some(Cow::Borrowed(b"example.com")), .. with_path(b"/") }), Err(HeaderResponseBufferError::CookiePrefixNotPermitted));
+}
+
+#[test]
+fn same_site_none_requires_secure()
+{
+	let same_site_none = SetCookieAttributes
+	{
+		same_site: Some(SameSite::None),
+		.. SetCookieAttributes::default()
+	};
+
+	assert!(set_cookie(b"a", b"1", &same_site_none).is_ok());
+	assert_eq!(set_cookie(b"a", b"1", &SetCookieAttributes { secure: false, .. same_site_none }), Err(HeaderResponseBufferError::SameSiteNoneNotPermitted));
+}
@@ -3,7 +3,7 @@
 
 
 #[inline(always)]
-pub(crate) const fn four_digits_to_second_digit(four_digits: i32) -> i32
+pub(crate) const fn four_digits_to_second_digit(four_digits: i32) -> u8
 {
-	four_digits_to_three_digit(four_digits) - ((four_digits_to_three_digits(four_digits) / 100) * 100)
+	(((four_digits_to_three_digits(four_digits) / 100) + AsciiZero) as u8)
 }
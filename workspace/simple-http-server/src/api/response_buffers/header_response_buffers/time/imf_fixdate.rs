@@ -0,0 +1,40 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+pub(crate) const ImfFixdateLength: usize = 29;
+
+/// Formats an IMF-fixdate (RFC 7231, Section 7.1.1.1), eg `Sun, 06 Nov 1994 08:49:37 GMT`.
+///
+/// Assumes `time` is UTC.
+#[inline(always)]
+pub(crate) fn imf_fixdate(time: &Tm) -> [u8; ImfFixdateLength]
+{
+	let week_day = time.three_letter_week_day();
+
+	let month = time.three_letter_month();
+
+	let year = time.tm_year + 1900;
+
+	let two_digit_year = three_digits_to_two_digits(four_digits_to_three_digits(year));
+
+	[
+		week_day[0], week_day[1], week_day[2],
+		b',',
+		b' ',
+		two_digits_to_first_digit(time.tm_mday), two_digits_to_second_digit(time.tm_mday),
+		b' ',
+		month[0], month[1], month[2],
+		b' ',
+		four_digits_to_first_digit(year), four_digits_to_second_digit(year), two_digits_to_first_digit(two_digit_year), two_digits_to_second_digit(two_digit_year),
+		b' ',
+		two_digits_to_first_digit(time.tm_hour), two_digits_to_second_digit(time.tm_hour),
+		b':',
+		two_digits_to_first_digit(time.tm_min), two_digits_to_second_digit(time.tm_min),
+		b':',
+		// NOTE: tm_sec can have the value 60 due to leap seconds.
+		two_digits_to_first_digit(time.tm_sec), two_digits_to_second_digit(time.tm_sec),
+		b' ',
+		b'G', b'M', b'T',
+	]
+}
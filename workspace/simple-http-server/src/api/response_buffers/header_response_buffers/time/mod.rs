@@ -11,4 +11,5 @@ include!("two_digits_to_second_digit.rs");
 include!("four_digits_to_first_digit.rs");
 include!("four_digits_to_three_digits.rs");
 include!("four_digits_to_second_digit.rs");
+include!("imf_fixdate.rs");
 include!("three_digits_to_two_digits.rs");
@@ -5,4 +5,9 @@
 use super::*;
 
 
+/// Body response buffers.
+pub(crate) mod body_response_buffers;
+
+
+/// Header response buffers.
 pub(crate) mod header_response_buffers;
@@ -0,0 +1,81 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Wakes a connection when a file descriptor becomes readable, eg that of an `EventFileDescriptor` (from `simple-http-server-epoll`) written to by another thread.
+///
+/// Used to implement a `ServerSentEventSource` whose events are held elsewhere, eg in a shared buffer.
+///
+/// The file descriptor is duplicated, so the same one can wake many connections; the duplicate is removed from the `Poll` it was registered with and closed when this is dropped, eg once the response it belongs to has been written.
+#[derive(Debug)]
+pub struct EventFileDescriptorWakeUp
+{
+	raw_fd: RawFd,
+	registered_with_poll: Cell<Option<RawFd>>,
+}
+
+impl Drop for EventFileDescriptorWakeUp
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		// epoll only removes a file descriptor once every duplicate of it has been closed, so the original would otherwise carry on waking a token which may have been re-used.
+		#[cfg(any(target_os = "android", target_os = "linux"))]
+		{
+			if let Some(poll_raw_fd) = self.registered_with_poll.get()
+			{
+				unsafe { epoll_ctl(poll_raw_fd, EPOLL_CTL_DEL, self.raw_fd, null_mut()) };
+			}
+		}
+
+		unsafe { close(self.raw_fd) };
+	}
+}
+
+impl Evented for EventFileDescriptorWakeUp
+{
+	#[inline(always)]
+	fn register(&self, poll: &Poll, token: Token, interest: Ready, opts: PollOpt) -> io::Result<()>
+	{
+		EventedFd(&self.raw_fd).register(poll, token, interest, opts)?;
+		self.registered_with_poll.set(Some(poll.as_raw_fd()));
+		Ok(())
+	}
+
+	#[inline(always)]
+	fn reregister(&self, poll: &Poll, token: Token, interest: Ready, opts: PollOpt) -> io::Result<()>
+	{
+		EventedFd(&self.raw_fd).reregister(poll, token, interest, opts)
+	}
+
+	#[inline(always)]
+	fn deregister(&self, poll: &Poll) -> io::Result<()>
+	{
+		EventedFd(&self.raw_fd).deregister(poll)?;
+		self.registered_with_poll.set(None);
+		Ok(())
+	}
+}
+
+impl EventFileDescriptorWakeUp
+{
+	/// Duplicates `raw_fd`, which remains owned by the caller and can be closed at any time.
+	#[inline(always)]
+	pub fn new(raw_fd: RawFd) -> io::Result<Self>
+	{
+		let duplicate_raw_fd = unsafe { fcntl(raw_fd, F_DUPFD_CLOEXEC, 0) };
+		if duplicate_raw_fd == -1
+		{
+			return Err(io::Error::last_os_error())
+		}
+
+		Ok
+		(
+			Self
+			{
+				raw_fd: duplicate_raw_fd,
+				registered_with_poll: Cell::new(None),
+			}
+		)
+	}
+}
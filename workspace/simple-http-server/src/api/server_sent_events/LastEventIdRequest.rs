@@ -0,0 +1,42 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Records the `Last-Event-ID` header of a request, sent by a user agent reconnecting to a `text/event-stream`, so that the events it missed can be sent first.
+///
+/// A `RequestHandler` should pass every call of `header_field()` to `header_field()` here, then use `last_event_id()` from `request_finished()`, eg to send the missed events with a `ServerSentEventSender` before any new ones, and finally call `clear()`.
+#[derive(Default, Debug, Clone)]
+pub struct LastEventIdRequest
+{
+	last_event_id: Option<(VectoredBufferOffset, VectoredBufferOffset)>,
+}
+
+impl LastEventIdRequest
+{
+	/// Records the header if it is `Last-Event-ID`; only the first is used.
+	#[inline(always)]
+	pub fn header_field(&mut self, known_header: KnownHeader, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		if known_header == KnownHeader::LastEventId && self.last_event_id.is_none()
+		{
+			self.last_event_id = Some((value_starts_at_inclusive, value_ends_at_exclusive))
+		}
+		Ok(())
+	}
+
+	/// The identifier of the last event the user agent received, if it is reconnecting.
+	///
+	/// It is that of a `ServerSentEvent::identifier()`, but, having come from the user agent, must not be trusted.
+	#[inline(always)]
+	pub fn last_event_id<'a>(&self, vectored_buffer: &'a VectoredBuffer) -> Option<ImmutableVectoredBufferSlice<'a>>
+	{
+		self.last_event_id.map(|(value_starts_at_inclusive, value_ends_at_exclusive)| ImmutableVectoredBufferSlice::new(vectored_buffer, value_starts_at_inclusive, value_ends_at_exclusive))
+	}
+
+	/// Call after the last event identifier has been used so this instance can be re-used for the next request on the connection.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.last_event_id = None;
+	}
+}
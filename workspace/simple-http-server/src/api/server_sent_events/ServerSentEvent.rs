@@ -0,0 +1,97 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// An event to be sent in a `text/event-stream` (HTML Living Standard, Section 9.2), eg `event: update\ndata: {"x":1}\nid: 42\n\n`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServerSentEvent
+{
+	event_type: Option<Cow<'static, str>>,
+	data: Cow<'static, str>,
+	identifier: Option<Cow<'static, str>>,
+	reconnection_time_in_milliseconds: Option<u32>,
+}
+
+impl ServerSentEvent
+{
+	/// An event of the default type, `message`.
+	///
+	/// `data` may contain line breaks; each line is sent as a separate `data:` field and rejoined by the user agent.
+	#[inline(always)]
+	pub fn new(data: Cow<'static, str>) -> Self
+	{
+		Self
+		{
+			event_type: None,
+			data,
+			identifier: None,
+			reconnection_time_in_milliseconds: None,
+		}
+	}
+
+	/// The event type, ie the name of the event dispatched in the user agent, eg `update`.
+	#[inline(always)]
+	pub fn event_type(mut self, event_type: Cow<'static, str>) -> Result<Self, ServerSentEventError>
+	{
+		if event_type.bytes().any(|byte| byte == b'\r' || byte == b'\n')
+		{
+			return Err(ServerSentEventError::InvalidEventType)
+		}
+
+		self.event_type = Some(event_type);
+		Ok(self)
+	}
+
+	/// The event identifier, which a user agent sends back as `Last-Event-ID` when it reconnects.
+	#[inline(always)]
+	pub fn identifier(mut self, identifier: Cow<'static, str>) -> Result<Self, ServerSentEventError>
+	{
+		if identifier.bytes().any(|byte| byte == b'\r' || byte == b'\n' || byte == b'\0')
+		{
+			return Err(ServerSentEventError::InvalidIdentifier)
+		}
+
+		self.identifier = Some(identifier);
+		Ok(self)
+	}
+
+	/// How long the user agent should wait before reconnecting if the connection is lost.
+	#[inline(always)]
+	pub fn reconnection_time(mut self, reconnection_time_in_milliseconds: u32) -> Self
+	{
+		self.reconnection_time_in_milliseconds = Some(reconnection_time_in_milliseconds);
+		self
+	}
+
+	// Each field's value is preceded by a space, as a user agent removes one space, if present, after the colon.
+	pub(crate) fn frame(&self, buffer: &mut Vec<u8>)
+	{
+		if let Some(ref event_type) = self.event_type
+		{
+			buffer.extend_from_slice(b"event: ");
+			buffer.extend_from_slice(event_type.as_bytes());
+			buffer.push(b'\n');
+		}
+
+		for line in self.data.split("\r\n").flat_map(|line| line.split(|character| character == '\r' || character == '\n'))
+		{
+			buffer.extend_from_slice(b"data: ");
+			buffer.extend_from_slice(line.as_bytes());
+			buffer.push(b'\n');
+		}
+
+		if let Some(ref identifier) = self.identifier
+		{
+			buffer.extend_from_slice(b"id: ");
+			buffer.extend_from_slice(identifier.as_bytes());
+			buffer.push(b'\n');
+		}
+
+		if let Some(reconnection_time_in_milliseconds) = self.reconnection_time_in_milliseconds
+		{
+			buffer.extend_from_slice(format!("retry: {}\n", reconnection_time_in_milliseconds).as_bytes());
+		}
+
+		buffer.push(b'\n');
+	}
+}
@@ -0,0 +1,31 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Receives the server-sent events sent by `ServerSentEventSender`s, on the thread servicing the connection.
+#[derive(Debug)]
+pub struct ServerSentEventReceiver(Receiver<ServerSentEvent>);
+
+impl ServerSentEventSource for ServerSentEventReceiver
+{
+	#[inline(always)]
+	fn wake_up(&self) -> &Evented
+	{
+		&self.0
+	}
+
+	#[inline(always)]
+	fn next_event(&mut self) -> Result<Option<ServerSentEvent>, ()>
+	{
+		use self::TryRecvError::*;
+
+		match self.0.try_recv()
+		{
+			Ok(server_sent_event) => Ok(Some(server_sent_event)),
+
+			Err(Empty) => Ok(None),
+
+			Err(Disconnected) => Err(()),
+		}
+	}
+}
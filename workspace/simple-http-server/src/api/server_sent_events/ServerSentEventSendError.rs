@@ -0,0 +1,39 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Reasons a server-sent event could not be sent by a `ServerSentEventSender`.
+#[derive(Debug)]
+pub enum ServerSentEventSendError
+{
+	/// The receiver has been dropped, eg because the user agent disconnected; the sender should be dropped too.
+	Disconnected(ServerSentEvent),
+
+	/// The event was queued but the connection could not be woken; it is sent when the connection is next woken, eg by a `ServerSentEventsHeartbeat`.
+	WakeUpFailed(io::Error),
+}
+
+impl Display for ServerSentEventSendError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl error::Error for ServerSentEventSendError
+{
+	#[inline(always)]
+	fn source(&self) -> Option<&(error::Error + 'static)>
+	{
+		use self::ServerSentEventSendError::*;
+
+		match self
+		{
+			&Disconnected(_) => None,
+
+			&WakeUpFailed(ref error) => Some(error),
+		}
+	}
+}
@@ -0,0 +1,38 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Sends server-sent events from any thread to a `ServerSentEventReceiver`.
+///
+/// The stream of events ends once every clone has been dropped.
+#[derive(Debug, Clone)]
+pub struct ServerSentEventSender(Sender<ServerSentEvent>);
+
+impl ServerSentEventSender
+{
+	/// Creates a connected sender and receiver.
+	#[inline(always)]
+	pub fn channel() -> (Self, ServerSentEventReceiver)
+	{
+		let (sender, receiver) = channel();
+		(ServerSentEventSender(sender), ServerSentEventReceiver(receiver))
+	}
+
+	/// Sends an event, waking the connection.
+	///
+	/// Fails with the event if the receiver has been dropped, eg because the user agent disconnected; the sender should then be dropped too.
+	#[inline(always)]
+	pub fn send(&self, server_sent_event: ServerSentEvent) -> Result<(), ServerSentEventSendError>
+	{
+		use self::SendError::*;
+
+		match self.0.send(server_sent_event)
+		{
+			Ok(()) => Ok(()),
+
+			Err(Disconnected(server_sent_event)) => Err(ServerSentEventSendError::Disconnected(server_sent_event)),
+
+			Err(Io(io_error)) => Err(ServerSentEventSendError::WakeUpFailed(io_error)),
+		}
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A source of server-sent events for a `ServerSentEventsChunkProducer`, eg a `ServerSentEventReceiver`.
+pub trait ServerSentEventSource
+{
+	/// Becomes readable when events may be available, waking the connection, eg an `EventFileDescriptorWakeUp`.
+	///
+	/// Registered, edge-triggered, with the connection's `Poll` once the response is queued.
+	fn wake_up(&self) -> &Evented;
+
+	/// The next event, if one is available.
+	///
+	/// Return `Err(())` once there will be no more events; the response is then finished.
+	fn next_event(&mut self) -> Result<Option<ServerSentEvent>, ()>;
+}
@@ -0,0 +1,126 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Produces a `text/event-stream` body from the events of a `ServerSentEventSource`, created by `ResponseBuilder::server_sent_events()`.
+///
+/// The response stays open, sending events as they become available, until the source has no more events or the user agent disconnects.
+///
+/// All the events available when the connection is woken are sent in one chunk.
+/// If there are none when woken by the `ServerSentEventsHeartbeat`, a comment is sent instead.
+pub struct ServerSentEventsChunkProducer<S: ServerSentEventSource>
+{
+	server_sent_event_source: S,
+	heartbeat_registration: Registration,
+	heartbeat_set_readiness: Arc<SetReadiness>,
+	wake_up_registered: bool,
+	server_sent_event_source_has_ended: bool,
+}
+
+impl<S: ServerSentEventSource> Evented for ServerSentEventsChunkProducer<S>
+{
+	#[inline(always)]
+	fn register(&self, poll: &Poll, token: Token, interest: Ready, opts: PollOpt) -> io::Result<()>
+	{
+		self.server_sent_event_source.wake_up().register(poll, token, interest, opts)?;
+		self.heartbeat_registration.register(poll, token, interest, opts)
+	}
+
+	#[inline(always)]
+	fn reregister(&self, poll: &Poll, token: Token, interest: Ready, opts: PollOpt) -> io::Result<()>
+	{
+		self.server_sent_event_source.wake_up().reregister(poll, token, interest, opts)?;
+		self.heartbeat_registration.reregister(poll, token, interest, opts)
+	}
+
+	#[inline(always)]
+	fn deregister(&self, poll: &Poll) -> io::Result<()>
+	{
+		self.server_sent_event_source.wake_up().deregister(poll)?;
+		self.heartbeat_registration.deregister(poll)
+	}
+}
+
+impl<S: ServerSentEventSource> ChunkProducer for ServerSentEventsChunkProducer<S>
+{
+	type Data = Vec<u8>;
+
+	fn produce_chunk(&mut self) -> Result<Chunk<Self::Data>, ()>
+	{
+		const Heartbeat: &'static [u8] = b":\n\n";
+
+		if self.server_sent_event_source_has_ended
+		{
+			return Ok(Chunk::Last(Vec::new()))
+		}
+
+		let mut chunk = Vec::new();
+		loop
+		{
+			match self.server_sent_event_source.next_event()
+			{
+				Ok(Some(server_sent_event)) => server_sent_event.frame(&mut chunk),
+
+				Ok(None) => break,
+
+				Err(()) =>
+				{
+					self.server_sent_event_source_has_ended = true;
+					break
+				}
+			}
+		}
+
+		let heartbeat_is_due = self.heartbeat_set_readiness.readiness().is_readable();
+		self.heartbeat_set_readiness.set_readiness(Ready::empty()).expect("Resetting readiness should not fail");
+
+		if chunk.is_empty()
+		{
+			if self.server_sent_event_source_has_ended
+			{
+				return Ok(Chunk::Last(Vec::new()))
+			}
+
+			if !heartbeat_is_due
+			{
+				return Err(())
+			}
+
+			chunk.extend_from_slice(Heartbeat);
+		}
+
+		Ok(Chunk::Data(chunk))
+	}
+
+	#[inline(always)]
+	fn wake_up_source_to_register(&mut self) -> Option<&Evented>
+	{
+		if self.wake_up_registered
+		{
+			None
+		}
+		else
+		{
+			self.wake_up_registered = true;
+			Some(self)
+		}
+	}
+}
+
+impl<S: ServerSentEventSource> ServerSentEventsChunkProducer<S>
+{
+	#[inline(always)]
+	pub(crate) fn new(server_sent_event_source: S, server_sent_events_heartbeat: &ServerSentEventsHeartbeat) -> Self
+	{
+		let (heartbeat_registration, heartbeat_set_readiness) = server_sent_events_heartbeat.subscribe();
+
+		Self
+		{
+			server_sent_event_source,
+			heartbeat_registration,
+			heartbeat_set_readiness,
+			wake_up_registered: false,
+			server_sent_event_source_has_ended: false,
+		}
+	}
+}
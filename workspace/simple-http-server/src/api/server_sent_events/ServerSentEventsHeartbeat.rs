@@ -0,0 +1,71 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Wakes every server-sent events stream subscribed to it at a fixed interval, so that a stream with no events sends a comment.
+///
+/// This stops proxies and load balancers closing a connection which appears idle, and lets a user agent notice a connection which has silently failed.
+///
+/// One thread is shared by all the streams subscribed; it stops once every clone of this has been dropped.
+#[derive(Debug, Clone)]
+pub struct ServerSentEventsHeartbeat
+{
+	subscribers: Arc<Mutex<Vec<Weak<SetReadiness>>>>,
+}
+
+impl ServerSentEventsHeartbeat
+{
+	/// Often enough for the idle timeouts of common proxies, which are at least 30 seconds.
+	pub const DefaultInterval: Duration = Duration::from_secs(15);
+
+	/// Starts the thread.
+	pub fn start(interval: Duration) -> io::Result<Self>
+	{
+		let subscribers = Arc::new(Mutex::new(Vec::new()));
+
+		let weak_subscribers = Arc::downgrade(&subscribers);
+		Builder::new().name("server-sent-events-heartbeat".to_owned()).spawn(move || Self::beat(interval, weak_subscribers))?;
+
+		Ok
+		(
+			Self
+			{
+				subscribers,
+			}
+		)
+	}
+
+	#[inline(always)]
+	pub(crate) fn subscribe(&self) -> (Registration, Arc<SetReadiness>)
+	{
+		let (registration, set_readiness) = Registration::new2();
+		let set_readiness = Arc::new(set_readiness);
+
+		self.subscribers.lock().unwrap_or_else(PoisonError::into_inner).push(Arc::downgrade(&set_readiness));
+
+		(registration, set_readiness)
+	}
+
+	fn beat(interval: Duration, subscribers: Weak<Mutex<Vec<Weak<SetReadiness>>>>)
+	{
+		loop
+		{
+			sleep(interval);
+
+			let subscribers = match subscribers.upgrade()
+			{
+				None => return,
+
+				Some(subscribers) => subscribers,
+			};
+
+			let mut subscribers = subscribers.lock().unwrap_or_else(PoisonError::into_inner);
+			subscribers.retain(|subscriber| match subscriber.upgrade()
+			{
+				None => false,
+
+				Some(set_readiness) => set_readiness.set_readiness(Ready::readable()).is_ok(),
+			});
+		}
+	}
+}
@@ -0,0 +1,82 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn frame(server_sent_event: &ServerSentEvent) -> Vec<u8>
+{
+	let mut buffer = Vec::new();
+	server_sent_event.frame(&mut buffer);
+	buffer
+}
+
+fn data(data: &'static str) -> Vec<u8>
+{
+	frame(&ServerSentEvent::new(Cow::Borrowed(data)))
+}
+
+#[test]
+fn data_is_framed_as_a_message()
+{
+	assert_eq!(data("{\"x\":1}"), b"data: {\"x\":1}\n\n".to_vec());
+}
+
+#[test]
+fn empty_data_is_framed_as_an_empty_data_field()
+{
+	assert_eq!(data(""), b"data: \n\n".to_vec());
+}
+
+#[test]
+fn data_is_split_on_line_feeds()
+{
+	assert_eq!(data("a\nb"), b"data: a\ndata: b\n\n".to_vec());
+}
+
+#[test]
+fn data_is_split_on_carriage_returns()
+{
+	assert_eq!(data("a\rb"), b"data: a\ndata: b\n\n".to_vec());
+}
+
+#[test]
+fn carriage_return_line_feed_is_one_line_break()
+{
+	assert_eq!(data("a\r\nb"), b"data: a\ndata: b\n\n".to_vec());
+}
+
+#[test]
+fn line_feed_carriage_return_is_two_line_breaks()
+{
+	assert_eq!(data("a\n\rb"), b"data: a\ndata: \ndata: b\n\n".to_vec());
+}
+
+#[test]
+fn leading_trailing_and_consecutive_line_breaks_are_empty_lines()
+{
+	assert_eq!(data("\na\r\n\r\nb\r"), b"data: \ndata: a\ndata: \ndata: b\ndata: \n\n".to_vec());
+}
+
+#[test]
+fn all_fields_are_framed()
+{
+	let server_sent_event = ServerSentEvent::new(Cow::Borrowed("x")).event_type(Cow::Borrowed("update")).unwrap().identifier(Cow::Borrowed("42")).unwrap().reconnection_time(5000);
+	assert_eq!(frame(&server_sent_event), b"event: update\ndata: x\nid: 42\nretry: 5000\n\n".to_vec());
+}
+
+#[test]
+fn event_type_with_a_line_break_is_rejected()
+{
+	assert_eq!(ServerSentEvent::new(Cow::Borrowed("x")).event_type(Cow::Borrowed("a\nb")), Err(ServerSentEventError::InvalidEventType));
+	assert_eq!(ServerSentEvent::new(Cow::Borrowed("x")).event_type(Cow::Borrowed("a\rb")), Err(ServerSentEventError::InvalidEventType));
+}
+
+#[test]
+fn identifier_with_a_line_break_or_nul_is_rejected()
+{
+	assert_eq!(ServerSentEvent::new(Cow::Borrowed("x")).identifier(Cow::Borrowed("a\nb")), Err(ServerSentEventError::InvalidIdentifier));
+	assert_eq!(ServerSentEvent::new(Cow::Borrowed("x")).identifier(Cow::Borrowed("a\rb")), Err(ServerSentEventError::InvalidIdentifier));
+	assert_eq!(ServerSentEvent::new(Cow::Borrowed("x")).identifier(Cow::Borrowed("a\0b")), Err(ServerSentEventError::InvalidIdentifier));
+}
@@ -0,0 +1,331 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+use super::*;
+
+
+#[derive(Debug, Clone)]
+struct UnusedRequestHandler;
+
+impl RequestUser for UnusedRequestHandler
+{
+	fn method(&mut self, _request_method: RequestMethod) -> Result<(), InvalidReason>
+	{
+		unreachable!()
+	}
+
+	fn target_uri_segment(&mut self, _segment_starts_at_inclusive: VectoredBufferOffset, _segment_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		unreachable!()
+	}
+
+	fn target_uri_query(&mut self, _query_starts_at_inclusive: VectoredBufferOffset, _query_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		unreachable!()
+	}
+
+	fn target_uri_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		unreachable!()
+	}
+
+	fn status_line_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		unreachable!()
+	}
+
+	fn header_field(&mut self, _known_header: KnownHeader, _name_starts_at_inclusive: VectoredBufferOffset, _name_ends_at_exclusive: VectoredBufferOffset, _value_starts_at_inclusive: VectoredBufferOffset, _value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		unreachable!()
+	}
+
+	fn headers_finished(&mut self, _vectored_buffer: &VectoredBuffer, _request_body_length: RequestBodyLength, _expects_continue: bool) -> Result<(), InvalidReason>
+	{
+		unreachable!()
+	}
+
+	fn body_chunk(&mut self, _vectored_buffer: &VectoredBuffer, _chunk_starts_at_inclusive: VectoredBufferOffset, _chunk_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		unreachable!()
+	}
+
+	fn trailer_field(&mut self, _name_starts_at_inclusive: VectoredBufferOffset, _name_ends_at_exclusive: VectoredBufferOffset, _value_starts_at_inclusive: VectoredBufferOffset, _value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		unreachable!()
+	}
+
+	fn body_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		unreachable!()
+	}
+}
+
+impl RequestHandler for UnusedRequestHandler
+{
+	fn request_finished(&mut self, _vectored_buffer: &VectoredBuffer) -> Box<Response>
+	{
+		unreachable!()
+	}
+}
+
+#[derive(Debug, Clone)]
+struct EmptyResponseRequestHandler;
+
+impl RequestUser for EmptyResponseRequestHandler
+{
+	fn method(&mut self, _request_method: RequestMethod) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn target_uri_segment(&mut self, _segment_starts_at_inclusive: VectoredBufferOffset, _segment_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn target_uri_query(&mut self, _query_starts_at_inclusive: VectoredBufferOffset, _query_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn target_uri_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn status_line_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn header_field(&mut self, _known_header: KnownHeader, _name_starts_at_inclusive: VectoredBufferOffset, _name_ends_at_exclusive: VectoredBufferOffset, _value_starts_at_inclusive: VectoredBufferOffset, _value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn headers_finished(&mut self, _vectored_buffer: &VectoredBuffer, _request_body_length: RequestBodyLength, _expects_continue: bool) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn body_chunk(&mut self, _vectored_buffer: &VectoredBuffer, _chunk_starts_at_inclusive: VectoredBufferOffset, _chunk_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn trailer_field(&mut self, _name_starts_at_inclusive: VectoredBufferOffset, _name_ends_at_exclusive: VectoredBufferOffset, _value_starts_at_inclusive: VectoredBufferOffset, _value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn body_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+}
+
+impl RequestHandler for EmptyResponseRequestHandler
+{
+	fn request_finished(&mut self, _vectored_buffer: &VectoredBuffer) -> Box<Response>
+	{
+		Box::new(ResponseBuilder::new(StatusLineHeaderResponseBuffer::Http11Ok).header(ContentLengthHeaderResponseBuffer::new(0)).build())
+	}
+}
+
+/// The bytes of each response queued after parsing the pipelined requests in `input`.
+fn queued_responses(maximum_requests_per_connection: usize, input: &[u8]) -> Vec<Vec<u8>>
+{
+	let mut virtual_hosts = VirtualHosts::default();
+	virtual_hosts.add(VirtualHost::new("example.com", 443, EmptyResponseRequestHandler), true);
+	let virtual_hosts = Rc::new(virtual_hosts);
+	let settings = Rc::new(SimpleHttpServedClientConnectionUserSettings::new(maximum_requests_per_connection, SecurityHeaderPolicy::empty()));
+
+	let mut connection_user = SimpleHttpServedClientConnectionUser::new(&settings, &virtual_hosts, received(input), RequestParserLimits::default());
+	connection_user.request.request_user().select_virtual_host(virtual_hosts.find(None).expect("No default virtual host"));
+	connection_user.parse_requests();
+
+	connection_user.responses.iter_mut().map(|response|
+	{
+		let mut data_buffers = ResponseDataBuffers::new();
+		response.provide_vectored_data(&mut data_buffers).expect("Response data is not available");
+		let bytes: Vec<u8> = data_buffers.iter().flat_map(|data_buffer| data_buffer.iter().cloned()).collect();
+		bytes
+	}).collect()
+}
+
+fn has_close_connection_header(response: &[u8]) -> bool
+{
+	const CloseConnectionHeader: &'static [u8] = b"\r\nConnection:close\r\n";
+
+	response.windows(CloseConnectionHeader.len()).any(|window| window == CloseConnectionHeader)
+}
+
+#[test]
+fn final_response_to_a_request_with_connection_close_has_connection_close_header()
+{
+	let responses = queued_responses(100, b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\nGET / HTTP/1.1\r\nHost: example.com\r\nConnection: keep-alive, close\r\n\r\nGET / HTTP/1.1\r\nHost: example.com\r\n\r\n");
+
+	assert_eq!(responses.len(), 2);
+	assert!(!has_close_connection_header(&responses[0]));
+	assert!(has_close_connection_header(&responses[1]));
+}
+
+#[test]
+fn final_response_at_maximum_requests_per_connection_has_connection_close_header()
+{
+	let responses = queued_responses(2, b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\nGET / HTTP/1.1\r\nHost: example.com\r\n\r\nGET / HTTP/1.1\r\nHost: example.com\r\n\r\n");
+
+	assert_eq!(responses.len(), 2);
+	assert!(!has_close_connection_header(&responses[0]));
+	assert!(has_close_connection_header(&responses[1]));
+}
+
+fn validate_host_header(hostname: &str, port: u16, host_header: &str) -> Result<(), SimpleHttpServedClientConnectionUserError>
+{
+	VirtualHost::new(hostname, port, UnusedRequestHandler).validate_host_header_bytes(host_header.bytes())
+}
+
+#[test]
+fn host_header_without_port_is_valid()
+{
+	assert!(validate_host_header("example.com", 443, "example.com").is_ok());
+}
+
+#[test]
+fn host_header_with_our_port_is_valid()
+{
+	assert!(validate_host_header("example.com", 443, "example.com:443").is_ok());
+}
+
+#[test]
+fn host_header_with_empty_port_is_valid()
+{
+	assert!(validate_host_header("example.com", 443, "example.com:").is_ok());
+}
+
+#[test]
+fn host_header_hostname_is_case_insensitive()
+{
+	assert!(validate_host_header("Example.COM", 443, "EXAMPLE.com:443").is_ok());
+}
+
+#[test]
+fn host_header_with_another_port_is_rejected()
+{
+	match validate_host_header("example.com", 443, "example.com:8443")
+	{
+		Err(SimpleHttpServedClientConnectionUserError::HostHeaderHasIncorrectPort) => (),
+		unexpected => panic!("Unexpected {:?}", unexpected),
+	}
+}
+
+#[test]
+fn host_header_with_a_prefix_of_our_port_is_rejected()
+{
+	match validate_host_header("example.com", 443, "example.com:44")
+	{
+		Err(SimpleHttpServedClientConnectionUserError::HostHeaderHasIncorrectPort) => (),
+		unexpected => panic!("Unexpected {:?}", unexpected),
+	}
+}
+
+#[test]
+fn host_header_with_another_hostname_is_rejected()
+{
+	match validate_host_header("example.com", 443, "example.org")
+	{
+		Err(SimpleHttpServedClientConnectionUserError::HostHeaderHostnameMismatch) => (),
+		unexpected => panic!("Unexpected {:?}", unexpected),
+	}
+}
+
+#[test]
+fn host_header_with_a_prefix_of_our_hostname_is_rejected()
+{
+	match validate_host_header("example.com", 443, "example.co:443")
+	{
+		Err(SimpleHttpServedClientConnectionUserError::HostHeaderHostnameMismatch) => (),
+		unexpected => panic!("Unexpected {:?}", unexpected),
+	}
+}
+
+#[test]
+fn host_header_with_a_longer_hostname_is_rejected()
+{
+	match validate_host_header("example.com", 443, "example.com.evil")
+	{
+		Err(SimpleHttpServedClientConnectionUserError::HostHeaderHostnameMismatch) => (),
+		unexpected => panic!("Unexpected {:?}", unexpected),
+	}
+}
+
+#[test]
+fn empty_host_header_is_rejected()
+{
+	match validate_host_header("example.com", 443, "")
+	{
+		Err(SimpleHttpServedClientConnectionUserError::HostHeaderHostnameMismatch) => (),
+		unexpected => panic!("Unexpected {:?}", unexpected),
+	}
+}
+
+#[test]
+fn static_file_is_read_by_a_static_file_reader_thread()
+{
+	let content: Vec<u8> = (0 .. 100_000u32).map(|index| (index % 251) as u8).collect();
+	let path = ::std::env::temp_dir().join(format!("simple-http-server-static-file-{}", ::std::process::id()));
+	File::create(&path).and_then(|mut file| file.write_all(&content[..])).expect("Could not create file");
+	let static_file = StaticFile::open(&path).expect("Could not open file");
+
+	let static_file_reader = StaticFileReader::start(1).expect("Could not start thread");
+	let mut response = ResponseBuilder::new(StatusLineHeaderResponseBuffer::Http11Ok).static_file(static_file, &static_file_reader);
+
+	let poll = Poll::new().expect("Could not create poll");
+	let mut events = Events::with_capacity(1);
+	let mut written = Vec::new();
+	loop
+	{
+		if let Some(wake_up_source) = response.wake_up_source_to_register()
+		{
+			poll.register(wake_up_source, Token(0), Ready::readable(), PollOpt::edge()).expect("Could not register");
+		}
+
+		let provided =
+		{
+			let mut data_buffers = ResponseDataBuffers::new();
+			match response.provide_vectored_data(&mut data_buffers)
+			{
+				Err(()) => None,
+
+				Ok(()) =>
+				{
+					let bytes: Vec<u8> = data_buffers.iter().flat_map(|data_buffer| data_buffer.iter().cloned()).collect();
+					Some(bytes)
+				}
+			}
+		};
+
+		match provided
+		{
+			None =>
+			{
+				poll.poll(&mut events, Some(Duration::from_secs(10))).expect("Could not poll");
+				assert!(!events.is_empty(), "Not woken once the file had been read");
+			}
+
+			Some(ref bytes) if bytes.is_empty() => break,
+
+			Some(bytes) =>
+			{
+				written.extend_from_slice(&bytes[..]);
+				response.vectored_data_written(bytes.len());
+			}
+		}
+	}
+	let _ = ::std::fs::remove_file(&path);
+
+	assert!(!response.has_failed());
+	assert!(written.ends_with(&content[..]));
+	assert_eq!(written.len() - content.len(), written.windows(4).position(|window| window == b"\r\n\r\n").expect("No end of headers") + 4);
+}
@@ -0,0 +1,123 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A request target-uri's path, percent-decoded and with `.` and `..` segments removed (RFC 3986, Section 5.2.4, Remove Dot Segments).
+///
+/// Built up by calling `push_segment()` from `RequestUser::target_uri_segment()`; because dot segments are removed, a path can never refer to anything above its root.
+///
+/// Can be re-used for subsequent requests by calling `clear()`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NormalizedTargetUriPath
+{
+	decoded: String,
+	segment_ends_at_exclusive: Vec<usize>,
+	ends_with_dot_segment: bool,
+}
+
+impl NormalizedTargetUriPath
+{
+	/// Adds the next segment of the path.
+	///
+	/// `segment` is as reported to `RequestUser::target_uri_segment()`, ie excludes any `/`.
+	pub fn push_segment(&mut self, segment: &ImmutableVectoredBufferSlice) -> Result<(), TargetUriPathError>
+	{
+		let segment_starts_at_inclusive = self.decoded.len();
+		Self::percent_decode_segment_into(segment, &mut self.decoded)?;
+
+		// Percent-decoding happens first, so `%2E%2E` is also treated as `..`.
+		match &self.decoded[segment_starts_at_inclusive .. ]
+		{
+			"." =>
+			{
+				self.decoded.truncate(segment_starts_at_inclusive);
+				self.ends_with_dot_segment = true;
+			}
+
+			".." =>
+			{
+				self.segment_ends_at_exclusive.pop();
+				let previous_segment_ends_at_exclusive = self.segment_ends_at_exclusive.last().cloned().unwrap_or(0);
+				self.decoded.truncate(previous_segment_ends_at_exclusive);
+				self.ends_with_dot_segment = true;
+			}
+
+			_ =>
+			{
+				self.segment_ends_at_exclusive.push(self.decoded.len());
+				self.ends_with_dot_segment = false;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// The normalized path, always starting with `/`.
+	///
+	/// A path ending in a dot segment is treated as a directory, ie ends with `/`, as RFC 3986 requires.
+	pub fn path(&self) -> String
+	{
+		let mut path = String::with_capacity(self.decoded.len() + self.segment_ends_at_exclusive.len() + 2);
+		for segment in self.segments()
+		{
+			path.push('/');
+			path.push_str(segment);
+		}
+		if self.ends_with_dot_segment || path.is_empty()
+		{
+			path.push('/');
+		}
+		path
+	}
+
+	/// The normalized, percent-decoded segments; there are none for a path of `/`.
+	///
+	/// An empty segment occurs for `//` or a trailing `/`.
+	#[inline(always)]
+	pub fn segments<'a>(&'a self) -> impl Iterator<Item=&'a str> + 'a
+	{
+		let mut segment_starts_at_inclusive = 0;
+		self.segment_ends_at_exclusive.iter().map(move |&segment_ends_at_exclusive|
+		{
+			let segment = &self.decoded[segment_starts_at_inclusive .. segment_ends_at_exclusive];
+			segment_starts_at_inclusive = segment_ends_at_exclusive;
+			segment
+		})
+	}
+
+	/// Allows re-use of allocated memory for a subsequent request.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.decoded.clear();
+		self.segment_ends_at_exclusive.clear();
+		self.ends_with_dot_segment = false;
+	}
+
+	/// Percent-decodes `segment`, appending it to `decoded`.
+	///
+	/// Percent-encodings may span buffers within `segment`.
+	/// Rejects encoded `/` and NUL, and decoded bytes which are not UTF-8; on error, `decoded` is unchanged.
+	pub fn percent_decode_segment_into(segment: &ImmutableVectoredBufferSlice, decoded: &mut String) -> Result<(), TargetUriPathError>
+	{
+		use self::TargetUriPathError::*;
+
+		let mut decoded_bytes = Vec::with_capacity(64);
+		for decoded_byte in PercentDecoder::new(segment.bytes(), false)
+		{
+			// A literal `/` or NUL can not occur in a segment, so these must have been percent-encoded.
+			match decoded_byte.map_err(|()| InvalidPercentEncoding)?
+			{
+				b'/' => return Err(EncodedSolidus),
+
+				0x00 => return Err(EncodedNul),
+
+				decoded_byte => decoded_bytes.push(decoded_byte),
+			}
+		}
+
+		let decoded_segment = from_utf8(&decoded_bytes).map_err(|error| InvalidUtf8(error))?;
+		decoded.push_str(decoded_segment);
+		Ok(())
+	}
+}
@@ -0,0 +1,101 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Percent-decodes bytes, without allocating.
+///
+/// Yields `Err(())` once, and then `None`, if a `%` is not followed by two hexadecimal digits.
+///
+/// RFC 3986, Section 2.1, Percent-Encoding:-
+/// ```
+/// pct-encoded = "%" HEXDIG HEXDIG
+/// ```
+#[derive(Debug, Clone)]
+pub struct PercentDecoder<I: Iterator<Item=u8>>
+{
+	bytes: I,
+	plus_is_space: bool,
+	failed: bool,
+}
+
+impl<I: Iterator<Item=u8>> Iterator for PercentDecoder<I>
+{
+	type Item = Result<u8, ()>;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if unlikely!(self.failed)
+		{
+			return None
+		}
+
+		let decoded_byte = match self.bytes.next()?
+		{
+			b'%' => match (Self::hexadecimal_digit(self.bytes.next()), Self::hexadecimal_digit(self.bytes.next()))
+			{
+				(Some(high), Some(low)) => (high << 4) | low,
+
+				_ =>
+				{
+					self.failed = true;
+					return Some(Err(()))
+				}
+			},
+
+			b'+' if self.plus_is_space => b' ',
+
+			byte => byte,
+		};
+
+		Some(Ok(decoded_byte))
+	}
+}
+
+impl<I: Iterator<Item=u8>> PercentDecoder<I>
+{
+	/// Creates a new instance.
+	///
+	/// `plus_is_space` should be true when decoding `application/x-www-form-urlencoded` data, such as a query string.
+	#[inline(always)]
+	pub fn new(bytes: I, plus_is_space: bool) -> Self
+	{
+		Self
+		{
+			bytes,
+			plus_is_space,
+			failed: false,
+		}
+	}
+
+	/// Are the decoded bytes exactly `other`?
+	///
+	/// Invalid percent-encoding is never equal.
+	#[inline(always)]
+	pub fn is(self, other: &[u8]) -> bool
+	{
+		let mut other = other.iter();
+		for decoded_byte in self
+		{
+			match (decoded_byte, other.next())
+			{
+				(Ok(decoded_byte), Some(&other_byte)) if decoded_byte == other_byte => continue,
+
+				_ => return false,
+			}
+		}
+		other.next().is_none()
+	}
+
+	#[inline(always)]
+	fn hexadecimal_digit(byte: Option<u8>) -> Option<u8>
+	{
+		match byte
+		{
+			Some(byte @ b'0' ... b'9') => Some(byte - b'0'),
+			Some(byte @ b'A' ... b'F') => Some(byte - b'A' + 10),
+			Some(byte @ b'a' ... b'f') => Some(byte - b'a' + 10),
+			_ => None,
+		}
+	}
+}
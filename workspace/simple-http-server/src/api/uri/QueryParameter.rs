@@ -0,0 +1,84 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// A `name=value` pair in a query string, as ranges in a `VectoredBuffer`.
+///
+/// Names and values are not decoded until asked for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QueryParameter
+{
+	/// Start of the name (inclusive).
+	pub name_starts_at_inclusive: VectoredBufferOffset,
+
+	/// End of the name (exclusive).
+	pub name_ends_at_exclusive: VectoredBufferOffset,
+
+	/// Start (inclusive) and end (exclusive) of the value.
+	///
+	/// `None` if there was no `=`, eg `?flag`; an empty range if the value was empty, eg `?flag=`.
+	pub value: Option<(VectoredBufferOffset, VectoredBufferOffset)>,
+}
+
+impl QueryParameter
+{
+	/// Raw (still percent-encoded) name.
+	#[inline(always)]
+	pub fn name<'vectored_buffer>(&self, vectored_buffer: &'vectored_buffer VectoredBuffer) -> ImmutableVectoredBufferSlice<'vectored_buffer>
+	{
+		ImmutableVectoredBufferSlice::new(vectored_buffer, self.name_starts_at_inclusive, self.name_ends_at_exclusive)
+	}
+
+	/// Raw (still percent-encoded) value.
+	#[inline(always)]
+	pub fn value<'vectored_buffer>(&self, vectored_buffer: &'vectored_buffer VectoredBuffer) -> Option<ImmutableVectoredBufferSlice<'vectored_buffer>>
+	{
+		self.value.map(|(value_starts_at_inclusive, value_ends_at_exclusive)| ImmutableVectoredBufferSlice::new(vectored_buffer, value_starts_at_inclusive, value_ends_at_exclusive))
+	}
+
+	/// Is the decoded name exactly `name`?
+	///
+	/// Does not allocate.
+	#[inline(always)]
+	pub fn name_is(&self, vectored_buffer: &VectoredBuffer, name: &[u8]) -> bool
+	{
+		PercentDecoder::new(self.name(vectored_buffer).bytes(), true).is(name)
+	}
+
+	/// Percent-decodes, and `+`-decodes, the name, appending it to `decoded`.
+	#[inline(always)]
+	pub fn decode_name_into(&self, vectored_buffer: &VectoredBuffer, decoded: &mut String) -> Result<(), QueryStringError>
+	{
+		Self::decode_into(&self.name(vectored_buffer), decoded)
+	}
+
+	/// Percent-decodes, and `+`-decodes, the value, if any, appending it to `decoded`.
+	///
+	/// Returns `Ok(false)` if there was no value.
+	#[inline(always)]
+	pub fn decode_value_into(&self, vectored_buffer: &VectoredBuffer, decoded: &mut String) -> Result<bool, QueryStringError>
+	{
+		match self.value(vectored_buffer)
+		{
+			None => Ok(false),
+
+			Some(value) => Self::decode_into(&value, decoded).map(|()| true),
+		}
+	}
+
+	/// On error, `decoded` is unchanged.
+	fn decode_into(slice: &ImmutableVectoredBufferSlice, decoded: &mut String) -> Result<(), QueryStringError>
+	{
+		use self::QueryStringError::*;
+
+		let mut decoded_bytes = Vec::with_capacity(64);
+		for decoded_byte in PercentDecoder::new(slice.bytes(), true)
+		{
+			decoded_bytes.push(decoded_byte.map_err(|()| InvalidPercentEncoding)?);
+		}
+
+		let decoded_slice = from_utf8(&decoded_bytes).map_err(|error| InvalidUtf8(error))?;
+		decoded.push_str(decoded_slice);
+		Ok(())
+	}
+}
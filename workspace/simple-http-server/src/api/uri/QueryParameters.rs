@@ -0,0 +1,140 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// The parameters of a query string, eg `a=1&b=2&flag`, as reported to `RequestUser::target_uri_query()`.
+///
+/// Parsing does not allocate; empty parameters, eg `a=1&&b=2`, are ignored.
+/// A parameter name may occur more than once.
+#[derive(Debug, Clone)]
+pub struct QueryParameters
+{
+	parameters: ArrayVec<[QueryParameter; 32]>,
+}
+
+impl QueryParameters
+{
+	/// The largest value `maximum_number_of_parameters` can have in `parse()`.
+	pub const MaximumNumberOfParameters: usize = 32;
+
+	/// Splits a query string into parameters.
+	///
+	/// `maximum_number_of_parameters` is reduced to `MaximumNumberOfParameters` if it is larger.
+	pub fn parse(query: &ImmutableVectoredBufferSlice, maximum_number_of_parameters: usize) -> Result<Self, QueryStringError>
+	{
+		let maximum_number_of_parameters = min(maximum_number_of_parameters, Self::MaximumNumberOfParameters);
+
+		let mut this = Self
+		{
+			parameters: ArrayVec::new(),
+		};
+
+		let mut parameter_starts_at_inclusive = None;
+		let mut name_ends_at_exclusive = None;
+		let mut value_starts_at_inclusive = None;
+
+		for (offset, byte) in query.offsets_and_bytes()
+		{
+			match byte
+			{
+				b'&' => this.push(maximum_number_of_parameters, parameter_starts_at_inclusive.take(), name_ends_at_exclusive.take(), value_starts_at_inclusive.take(), offset)?,
+
+				b'=' if name_ends_at_exclusive.is_none() =>
+				{
+					if parameter_starts_at_inclusive.is_none()
+					{
+						parameter_starts_at_inclusive = Some(offset);
+					}
+					name_ends_at_exclusive = Some(offset);
+				}
+
+				_ =>
+				{
+					if parameter_starts_at_inclusive.is_none()
+					{
+						parameter_starts_at_inclusive = Some(offset);
+					}
+					if name_ends_at_exclusive.is_some() && value_starts_at_inclusive.is_none()
+					{
+						value_starts_at_inclusive = Some(offset);
+					}
+				}
+			}
+		}
+
+		this.push(maximum_number_of_parameters, parameter_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, query.ends_at_exclusive())?;
+
+		Ok(this)
+	}
+
+	/// Iterates over parameters in the order they occurred.
+	#[inline(always)]
+	pub fn iter(&self) -> Iter<QueryParameter>
+	{
+		self.parameters.iter()
+	}
+
+	/// Number of parameters.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.parameters.len()
+	}
+
+	/// No parameters?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.parameters.is_empty()
+	}
+
+	/// Finds the first parameter whose decoded name is exactly `name`.
+	#[inline(always)]
+	pub fn get(&self, vectored_buffer: &VectoredBuffer, name: &[u8]) -> Option<&QueryParameter>
+	{
+		self.parameters.iter().find(|parameter| parameter.name_is(vectored_buffer, name))
+	}
+
+	/// Finds all parameters whose decoded name is exactly `name`, eg for `?tag=a&tag=b`.
+	#[inline(always)]
+	pub fn get_all<'a>(&'a self, vectored_buffer: &'a VectoredBuffer, name: &'a [u8]) -> impl Iterator<Item=&'a QueryParameter> + 'a
+	{
+		self.parameters.iter().filter(move |parameter| parameter.name_is(vectored_buffer, name))
+	}
+
+	#[inline(always)]
+	fn push(&mut self, maximum_number_of_parameters: usize, parameter_starts_at_inclusive: Option<VectoredBufferOffset>, name_ends_at_exclusive: Option<VectoredBufferOffset>, value_starts_at_inclusive: Option<VectoredBufferOffset>, parameter_ends_at_exclusive: VectoredBufferOffset) -> Result<(), QueryStringError>
+	{
+		let name_starts_at_inclusive = match parameter_starts_at_inclusive
+		{
+			None => return Ok(()),
+
+			Some(parameter_starts_at_inclusive) => parameter_starts_at_inclusive,
+		};
+
+		if unlikely!(self.parameters.len() == maximum_number_of_parameters)
+		{
+			return Err(QueryStringError::TooManyParameters)
+		}
+
+		let parameter = match name_ends_at_exclusive
+		{
+			None => QueryParameter
+			{
+				name_starts_at_inclusive,
+				name_ends_at_exclusive: parameter_ends_at_exclusive,
+				value: None,
+			},
+
+			Some(name_ends_at_exclusive) => QueryParameter
+			{
+				name_starts_at_inclusive,
+				name_ends_at_exclusive,
+				value: Some((value_starts_at_inclusive.unwrap_or(parameter_ends_at_exclusive), parameter_ends_at_exclusive)),
+			},
+		};
+
+		self.parameters.push(parameter);
+		Ok(())
+	}
+}
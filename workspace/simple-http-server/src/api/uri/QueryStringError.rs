@@ -0,0 +1,63 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Reasons a request target-uri's query string could not be parsed or decoded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum QueryStringError
+{
+	/// There were more parameters than permitted.
+	TooManyParameters,
+
+	/// A `%` was not followed by two hexadecimal digits.
+	InvalidPercentEncoding,
+
+	/// The percent-decoded name or value was not UTF-8.
+	InvalidUtf8(Utf8Error),
+}
+
+impl Display for QueryStringError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl error::Error for QueryStringError
+{
+	#[inline(always)]
+	fn source(&self) -> Option<&(error::Error + 'static)>
+	{
+		use self::QueryStringError::*;
+
+		match self
+		{
+			&TooManyParameters => None,
+
+			&InvalidPercentEncoding => None,
+
+			&InvalidUtf8(ref error) => Some(error),
+		}
+	}
+}
+
+impl QueryStringError
+{
+	/// Converts to an `InvalidReason`, eg for returning from `RequestUser::target_uri_query()`.
+	#[inline(always)]
+	pub fn invalid_reason(&self) -> InvalidReason
+	{
+		use self::QueryStringError::*;
+
+		match self
+		{
+			&TooManyParameters => BadRequest("Too many parameters in request target-uri query"),
+
+			&InvalidPercentEncoding => BadRequest("Invalid percent-encoding in request target-uri query"),
+
+			&InvalidUtf8(_) => BadRequest("Request target-uri query is not UTF-8"),
+		}
+	}
+}
@@ -0,0 +1,70 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Reasons a request target-uri's path could not be percent-decoded or normalized.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TargetUriPathError
+{
+	/// A `%` was not followed by two hexadecimal digits.
+	InvalidPercentEncoding,
+
+	/// `%2F` (an encoded `/`) was present; allowing it would let a segment be confused with two segments.
+	EncodedSolidus,
+
+	/// `%00` (an encoded NUL) was present; NUL is never valid in a file path and truncates C strings.
+	EncodedNul,
+
+	/// The percent-decoded segment was not UTF-8.
+	InvalidUtf8(Utf8Error),
+}
+
+impl Display for TargetUriPathError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl error::Error for TargetUriPathError
+{
+	#[inline(always)]
+	fn source(&self) -> Option<&(error::Error + 'static)>
+	{
+		use self::TargetUriPathError::*;
+
+		match self
+		{
+			&InvalidPercentEncoding => None,
+
+			&EncodedSolidus => None,
+
+			&EncodedNul => None,
+
+			&InvalidUtf8(ref error) => Some(error),
+		}
+	}
+}
+
+impl TargetUriPathError
+{
+	/// Converts to an `InvalidReason`, eg for returning from `RequestUser::target_uri_segment()`.
+	#[inline(always)]
+	pub fn invalid_reason(&self) -> InvalidReason
+	{
+		use self::TargetUriPathError::*;
+
+		match self
+		{
+			&InvalidPercentEncoding => BadRequest("Invalid percent-encoding in request target-uri path"),
+
+			&EncodedSolidus => BadRequest("Percent-encoded '/' in request target-uri path"),
+
+			&EncodedNul => BadRequest("Percent-encoded NUL in request target-uri path"),
+
+			&InvalidUtf8(_) => BadRequest("Request target-uri path is not UTF-8"),
+		}
+	}
+}
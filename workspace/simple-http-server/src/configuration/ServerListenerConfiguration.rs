@@ -43,13 +43,21 @@ impl<'socket_address, SCCUF: ServedClientConnectionsUserFactory> ServerListenerC
 		self.server_listener_constraints.send_buffer_size
 	}
 
+	#[inline(always)]
+	pub(crate) fn request_parser_limits(&self) -> RequestParserLimits
+	{
+		self.server_listener_constraints.request_parser_limits
+	}
+
 	#[inline(always)]
 	pub(crate) fn new_connection_observer(self, served_client_connection_user_factory: SCCUF) -> Arc<ConnectionObserver<SCCUF>>
 	{
 		let maximum_connections = self.maximum_connections();
 
+		let request_parser_limits = self.request_parser_limits();
+
 		let server_listener_constraints = self.server_listener_constraints;
 
-		Arc::new(ConnectionObserver::new(self.maximum_connections(), &server_listener_constraints.permitted_internet_protocol_version_4_addresses, &server_listener_constraints.permitted_internet_protocol_version_6_addresses, served_client_connection_user_factory))
+		Arc::new(ConnectionObserver::new(self.maximum_connections(), &server_listener_constraints.permitted_internet_protocol_version_4_addresses, &server_listener_constraints.permitted_internet_protocol_version_6_addresses, request_parser_limits, served_client_connection_user_factory))
 	}
 }
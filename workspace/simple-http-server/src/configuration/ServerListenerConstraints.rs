@@ -33,7 +33,12 @@ pub struct ServerListenerConstraints
 	/// Defaults to 16,384 bytes (16Kb).
 	pub send_buffer_size: usize,
 
-
+	/// Limits applied whilst parsing requests, such as the maximum length of a target-uri and the maximum number of header fields.
+	///
+	/// Clients of different listeners can have very different needs; for example, an API listener may need far longer target-uris than one serving static files.
+	///
+	/// Defaults to `RequestParserLimits::default()`.
+	pub request_parser_limits: RequestParserLimits,
 
 	/// Buffer limit, in bytes, passed to rustls; controls internal write buffers and unread plain text buffers.
 	///
@@ -55,6 +60,7 @@ impl Default for ServerListenerConstraints
 			permitted_internet_protocol_version_6_addresses: HashSet::default(),
 			receive_buffer_size: 16_384,
 			send_buffer_size: 16_384,
+			request_parser_limits: RequestParserLimits::default(),
 
 			rustls_buffer_limit: 16_384,
 		}
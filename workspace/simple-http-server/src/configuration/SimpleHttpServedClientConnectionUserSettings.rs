@@ -5,24 +5,25 @@
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct SimpleHttpServedClientConnectionUserSettings
 {
-	pub(crate) our_hostname: String,
+	pub(crate) maximum_requests_per_connection: usize,
 
-	pub(crate) our_port_string: String,
-
-	pub(crate) our_url: Url,
+	pub(crate) security_header_policy: SecurityHeaderPolicy,
 }
 
 impl SimpleHttpServedClientConnectionUserSettings
 {
+	/// `maximum_requests_per_connection` limits the number of requests, pipelined or otherwise, served on one persistent connection before it is closed; panics if it is zero.
+	///
+	/// `security_header_policy` is attached to every final response, including those for invalid requests.
 	#[inline(always)]
-	pub fn new(our_hostname: &str, our_port: u16) -> Self
+	pub fn new(maximum_requests_per_connection: usize, security_header_policy: SecurityHeaderPolicy) -> Self
 	{
-		let our_port_string = format!("{:?}", our_port);
+		assert_ne!(maximum_requests_per_connection, 0, "maximum_requests_per_connection must not be zero");
+
 		Self
 		{
-			our_url: Url::parse(&format!("https://{}:{}", our_hostname, &our_port_string)).unwrap(),
-			our_hostname: our_hostname.to_string(),
-			our_port_string,
+			maximum_requests_per_connection,
+			security_header_policy,
 		}
 	}
 }
@@ -14,7 +14,7 @@ impl TmExt for Tm
 	#[inline(always)]
 	fn three_letter_week_day(&self) -> [u8; 3]
 	{
-		match time.tm_wday
+		match self.tm_wday
 		{
 			0 => byte_string_to_array_of_length_three(b"Sun"),
 			1 => byte_string_to_array_of_length_three(b"Mon"),
@@ -23,13 +23,14 @@ impl TmExt for Tm
 			4 => byte_string_to_array_of_length_three(b"Thu"),
 			5 => byte_string_to_array_of_length_three(b"Fri"),
 			6 => byte_string_to_array_of_length_three(b"Sat"),
+			_ => unreachable!(),
 		}
 	}
 
 	#[inline(always)]
 	fn three_letter_month(&self) -> [u8; 3]
 	{
-		match time.tm_mon
+		match self.tm_mon
 		{
 			0 => byte_string_to_array_of_length_three(b"Jan"),
 			1 => byte_string_to_array_of_length_three(b"Feb"),
@@ -42,7 +43,8 @@ impl TmExt for Tm
 			8 => byte_string_to_array_of_length_three(b"Sep"),
 			9 => byte_string_to_array_of_length_three(b"Oct"),
 			10 => byte_string_to_array_of_length_three(b"Nov"),
-			11 => byte_string_to_array_of_length_three(b"Dev"),
+			11 => byte_string_to_array_of_length_three(b"Dec"),
+			_ => unreachable!(),
 		}
 	}
 }
@@ -15,7 +15,9 @@
 
 
 extern crate arrayvec;
+extern crate brotli;
 extern crate cpu_affinity;
+extern crate flate2;
 #[cfg(unix)] extern crate libc;
 #[macro_use] extern crate likely;
 extern crate mio;
@@ -31,19 +33,40 @@ pub extern crate webpki;
 
 
 use self::api::*;
+use self::api::compression::*;
+use self::api::conditional_requests::*;
+use self::api::cookies::*;
+use self::api::header_domain::*;
+use self::api::range_requests::*;
+use self::api::server_sent_events::*;
+use self::api::response_buffers::body_response_buffers::*;
+use self::api::response_buffers::header_response_buffers::*;
+use self::api::uri::*;
 use self::arena::*;
 use self::configuration::*;
 use self::extensions::*;
+use self::request_parser::*;
+use self::request_parser::InvalidReason::*;
 use self::support::*;
 use self::tokens::*;
 use self::workers::*;
 use ::arrayvec::ArrayVec;
+use ::brotli::CompressorWriter;
 use ::cpu_affinity::LogicalCores;
+use ::flate2::Compression;
+use ::flate2::write::GzEncoder;
+use ::flate2::write::ZlibEncoder;
+#[cfg(unix)] use ::libc::close;
+#[cfg(any(target_os = "android", target_os = "linux"))] use ::libc::epoll_ctl;
+#[cfg(any(target_os = "android", target_os = "linux"))] use ::libc::EPOLL_CTL_DEL;
+#[cfg(unix)] use ::libc::F_DUPFD_CLOEXEC;
+#[cfg(unix)] use ::libc::fcntl;
 #[cfg(unix)] use ::libc::pthread_sigmask;
 #[cfg(unix)] use ::libc::SIG_SETMASK;
 #[cfg(unix)] use ::libc::sigfillset;
 use ::mio::*;
 use ::mio::tcp::*;
+use ::mio::unix::EventedFd;
 use ::mio::unix::UnixReady;
 use ::mio_extras::channel::*;
 use ::rustls::*;
@@ -52,12 +75,17 @@ use ::rustls::TLSError::FailedToGetCurrentTime;
 use ::rustls::TLSError::NoCertificatesPresented;
 use ::rustls::TLSError::WebPKIError;
 use ::simple_http_server_vectored_buffers::*;
+#[cfg(target_arch = "x86")] use ::std::arch::x86::*;
+#[cfg(target_arch = "x86_64")] use ::std::arch::x86_64::*;
 use ::std::borrow::Borrow;
 use ::std::borrow::Cow;
+use ::std::cell::Cell;
 use ::std::cell::RefCell;
 use ::std::cmp::max;
 use ::std::cmp::min;
+use ::std::collections::BTreeSet;
 use ::std::collections::HashMap;
+use ::std::collections::VecDeque;
 use ::std::convert::AsMut;
 use ::std::convert::AsRef;
 use ::std::error;
@@ -73,6 +101,7 @@ use ::std::io::Read;
 use ::std::io::Write;
 use ::std::io::ErrorKind::WouldBlock;
 use ::std::mem::ManuallyDrop;
+use ::std::mem::replace;
 use ::std::mem::transmute;
 use ::std::mem::uninitialized;
 use ::std::net::AddrParseError;
@@ -84,6 +113,10 @@ use ::std::ops::Deref;
 use ::std::ops::DerefMut;
 use ::std::ops::Index;
 use ::std::ops::IndexMut;
+use ::std::os::unix::fs::FileExt;
+use ::std::os::unix::io::AsRawFd;
+use ::std::os::unix::io::RawFd;
+use ::std::path::Path;
 use ::std::panic::PanicInfo;
 use ::std::panic::set_hook;
 use ::std::ptr::drop_in_place;
@@ -93,15 +126,28 @@ use ::std::ptr::write;
 use ::std::rc::Rc;
 use ::std::slice::from_raw_parts;
 use ::std::slice::from_raw_parts_mut;
+use ::std::slice::Iter;
+use ::std::str::from_utf8;
+use ::std::str::Utf8Error;
 use ::std::sync::Arc;
+use ::std::sync::Mutex;
+use ::std::sync::PoisonError;
+use ::std::sync::Weak;
 use ::std::sync::atomic::AtomicBool;
 use ::std::sync::atomic::AtomicUsize;
 use ::std::sync::atomic::Ordering::Relaxed;
 use ::std::sync::atomic::Ordering::SeqCst;
+use ::std::sync::mpsc;
+use ::std::sync::mpsc::TryRecvError;
 use ::std::thread::*;
 use ::std::time::Duration;
 use ::std::time::SystemTime;
+use ::std::time::UNIX_EPOCH;
+use ::time::at_utc;
+use ::time::get_time;
 use ::time::now_utc;
+use ::time::precise_time_ns;
+use ::time::Timespec;
 use ::time::Tm;
 use ::treebitmap::IpLookupTable;
 use ::untrusted::Input;
@@ -123,6 +169,10 @@ pub mod configuration;
 pub(crate) mod extensions;
 
 
+/// Request parser.
+pub mod request_parser;
+
+
 pub(crate) mod support;
 
 
@@ -0,0 +1,103 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum BodyReentryPoint
+{
+	/// Resume at the offset with the number of bytes of the body still to be read.
+	ContentLength(VectoredBufferOffset, u64),
+
+	Chunked(ChunkedReentryPoint),
+}
+
+impl BodyReentryPoint
+{
+	// RFC 7230, Section 3.3, Message Body:-
+	// ```
+	// message-body = *OCTET
+	// ```
+	pub(crate) fn begin(request_body_length: RequestBodyLength, bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
+	{
+		use self::RequestBodyLength::*;
+
+		match request_body_length
+		{
+			None => Self::body_finished(request_user),
+
+			ContentLength(remaining) => Self::parse_content_length(bytes, request_user, remaining),
+
+			Chunked => Self::parse_chunked(bytes, request_user, |bytes, request_user| ChunkedReentryPoint::begin(bytes, request_user, request_parser_limits)),
+		}
+	}
+
+	pub(crate) fn re_enter(self, bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
+	{
+		use self::BodyReentryPoint::*;
+
+		match self
+		{
+			ContentLength(starts_at, remaining) =>
+			{
+				bytes.reset(starts_at);
+
+				Self::parse_content_length(bytes, request_user, remaining)
+			}
+
+			Chunked(reentry_point) => Self::parse_chunked(bytes, request_user, |bytes, request_user| reentry_point.re_enter(bytes, request_user, request_parser_limits)),
+		}
+	}
+
+	/// Releases the body bytes already parsed, ie those from `body_starts_at` to this reentry point, so that their space in the `VectoredBuffer` can be re-used; returns this reentry point moved to `body_starts_at`.
+	pub(crate) fn release_parsed_bytes(self, bytes: &mut Bytes, body_starts_at: VectoredBufferOffset) -> Self
+	{
+		use self::BodyReentryPoint::*;
+
+		match self
+		{
+			ContentLength(starts_at, remaining) =>
+			{
+				bytes.release(body_starts_at, starts_at);
+
+				ContentLength(body_starts_at, remaining)
+			}
+
+			Chunked(reentry_point) => Chunked(reentry_point.release_parsed_bytes(bytes, body_starts_at)),
+		}
+	}
+
+	#[inline(always)]
+	fn parse_chunked<RU: RequestUser>(bytes: &mut Bytes, request_user: &mut RU, parse: impl FnOnce(&mut Bytes, &mut RU) -> Result<(), Status<ChunkedReentryPoint>>) -> Result<(), Status<Self>>
+	{
+		parse(bytes, request_user).map_err(|status| match status
+		{
+			Invalid(invalid_reason) => Invalid(invalid_reason),
+			RanOutOfBytes(reentry_point) => RanOutOfBytes(BodyReentryPoint::Chunked(reentry_point)),
+		})
+	}
+
+	/// Body bytes are not copied; each contiguous run of bytes within a buffer of the `VectoredBuffer` is reported to `request_user.body_chunk()`, after which it may be released.
+	#[inline(always)]
+	fn parse_content_length(bytes: &mut Bytes, request_user: &mut impl RequestUser, mut remaining: u64) -> Result<(), Status<Self>>
+	{
+		while remaining != 0
+		{
+			let reentry_point = BodyReentryPoint::ContentLength(bytes.current_position(), remaining);
+
+			let maximum = min(remaining, ::std::usize::MAX as u64) as usize;
+			let (chunk_starts_at_inclusive, chunk_ends_at_exclusive) = bytes.if_has_more_consume_up_to(maximum, reentry_point)?;
+
+			request_user.body_chunk(bytes.vectored_buffer(), chunk_starts_at_inclusive, chunk_ends_at_exclusive).map_err(|invalid_reason| Invalid(invalid_reason))?;
+
+			remaining -= (chunk_ends_at_exclusive.offset - chunk_starts_at_inclusive.offset) as u64;
+		}
+
+		Self::body_finished(request_user)
+	}
+
+	#[inline(always)]
+	fn body_finished(request_user: &mut impl RequestUser) -> Result<(), Status<Self>>
+	{
+		request_user.body_finished().map_err(|invalid_reason| Invalid(invalid_reason))
+	}
+}
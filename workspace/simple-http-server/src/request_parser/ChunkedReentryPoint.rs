@@ -0,0 +1,409 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum ChunkedReentryPoint
+{
+	ChunkSizeLine(VectoredBufferOffset),
+
+	ChunkData(VectoredBufferOffset, u64),
+
+	ChunkDataEnds(VectoredBufferOffset),
+
+	TrailerField(HeaderSection, VectoredBufferOffset),
+}
+
+impl ChunkedReentryPoint
+{
+	// RFC 7230, Section 4.1, Chunked Transfer Coding:-
+	// ```
+	// chunked-body   = *chunk
+	//                  last-chunk
+	//                  trailer-part
+	//                  CRLF
+	//
+	// chunk          = chunk-size [ chunk-ext ] CRLF
+	//                  chunk-data CRLF
+	// chunk-size     = 1*HEXDIG
+	// last-chunk     = 1*("0") [ chunk-ext ] CRLF
+	//
+	// chunk-data     = 1*OCTET ; a sequence of chunk-size octets
+	//
+	// trailer-part   = *( header-field CRLF )
+	// ```
+	//
+	// Chunk size lines are limited to `request_parser_limits.maximum_header_field_length`; the trailer part is limited as if it were a header section.
+	#[inline(always)]
+	pub(crate) fn begin(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
+	{
+		Self::parse_chunk_size_line(bytes, request_user, request_parser_limits)
+	}
+
+	pub(crate) fn re_enter(self, bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
+	{
+		use self::ChunkedReentryPoint::*;
+
+		match self
+		{
+			ChunkSizeLine(starts_at) =>
+			{
+				bytes.reset(starts_at);
+
+				Self::parse_chunk_size_line(bytes, request_user, request_parser_limits)
+			}
+
+			ChunkData(starts_at, remaining) =>
+			{
+				bytes.reset(starts_at);
+
+				Self::parse_chunk_data(bytes, request_user, request_parser_limits, remaining)
+			}
+
+			ChunkDataEnds(starts_at) =>
+			{
+				bytes.reset(starts_at);
+
+				Self::parse_chunk_data_ends(bytes, request_user, request_parser_limits)
+			}
+
+			TrailerField(trailer_section, starts_at) =>
+			{
+				bytes.reset(starts_at);
+
+				Self::parse_trailer_part(bytes, request_user, request_parser_limits, trailer_section)
+			}
+		}
+	}
+
+	/// Releases the body bytes already parsed, ie those from `body_starts_at` to this reentry point, so that their space in the `VectoredBuffer` can be re-used; returns this reentry point moved to `body_starts_at`.
+	///
+	/// Bytes are not released once the trailer part has begun, as the ranges reported to `trailer_field()` must remain valid.
+	pub(crate) fn release_parsed_bytes(self, bytes: &mut Bytes, body_starts_at: VectoredBufferOffset) -> Self
+	{
+		use self::ChunkedReentryPoint::*;
+
+		match self
+		{
+			ChunkSizeLine(starts_at) =>
+			{
+				bytes.release(body_starts_at, starts_at);
+
+				ChunkSizeLine(body_starts_at)
+			}
+
+			ChunkData(starts_at, remaining) =>
+			{
+				bytes.release(body_starts_at, starts_at);
+
+				ChunkData(body_starts_at, remaining)
+			}
+
+			ChunkDataEnds(starts_at) =>
+			{
+				bytes.release(body_starts_at, starts_at);
+
+				ChunkDataEnds(body_starts_at)
+			}
+
+			TrailerField(..) => self,
+		}
+	}
+
+	fn parse_chunk_size_line(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
+	{
+		const TooLong: InvalidReason = BadRequest("Chunk size line too long");
+
+		let reentry_point = ChunkedReentryPoint::ChunkSizeLine(bytes.current_position());
+		let mut remaining_line_length = request_parser_limits.maximum_header_field_length;
+
+		let mut chunk_size: u64 = 0;
+		let mut has_digits = false;
+		let mut byte = loop
+		{
+			let byte = Self::next(bytes, reentry_point, &mut remaining_line_length, TooLong)?;
+
+			let digit = match byte
+			{
+				b'0' ... b'9' => byte - b'0',
+
+				b'A' ... b'F' => byte - b'A' + 10,
+
+				b'a' ... b'f' => byte - b'a' + 10,
+
+				_ => break byte,
+			};
+
+			if unlikely!(chunk_size > (::std::u64::MAX >> 4))
+			{
+				return Err(Invalid(BadRequest("Chunk size overflows")))
+			}
+			chunk_size = (chunk_size << 4) | (digit as u64);
+			has_digits = true;
+		};
+
+		if unlikely!(!has_digits)
+		{
+			return Err(Invalid(BadRequest("Chunk size missing")))
+		}
+
+		// RFC 7230, Section 4.1.1, Chunk Extensions:-
+		// ```
+		// chunk-ext      = *( ";" chunk-ext-name [ "=" chunk-ext-val ] )
+		//
+		// chunk-ext-name = token
+		// chunk-ext-val  = token / quoted-string
+		// ```
+		//
+		// "A recipient MUST ignore unrecognized chunk extensions"; we recognise none, so they are validated and then ignored.
+		while byte == b';'
+		{
+			byte = Self::parse_chunk_extension(bytes, reentry_point, &mut remaining_line_length, TooLong)?;
+		}
+
+		Self::parse_line_ending(byte, bytes, reentry_point, &mut remaining_line_length, TooLong, "Chunk size line CR not followed by LF", "Invalid byte in chunk size line")?;
+
+		if chunk_size == 0
+		{
+			Self::parse_trailer_part(bytes, request_user, request_parser_limits, HeaderSection::new(bytes.current_position()))
+		}
+		else
+		{
+			Self::parse_chunk_data(bytes, request_user, request_parser_limits, chunk_size)
+		}
+	}
+
+	/// Returns the first byte after the chunk extension.
+	fn parse_chunk_extension(bytes: &mut Bytes, reentry_point: Self, remaining_line_length: &mut usize, too_long: InvalidReason) -> Result<u8, Status<Self>>
+	{
+		if unlikely!(!Self::is_token_byte(Self::next(bytes, reentry_point, remaining_line_length, too_long)?))
+		{
+			return Err(Invalid(BadRequest("Invalid byte in chunk extension name token")))
+		}
+		let byte = Self::parse_token(bytes, reentry_point, remaining_line_length, too_long, "Invalid byte in chunk extension name token")?;
+
+		if byte != b'='
+		{
+			return Ok(byte)
+		}
+
+		let byte = Self::next(bytes, reentry_point, remaining_line_length, too_long)?;
+		if byte != b'"'
+		{
+			if unlikely!(!Self::is_token_byte(byte))
+			{
+				return Err(Invalid(BadRequest("Invalid byte in chunk extension value token")))
+			}
+			return Self::parse_token(bytes, reentry_point, remaining_line_length, too_long, "Invalid byte in chunk extension value token")
+		}
+
+		// RFC 7230, Section 3.2.6, Field Value Components:-
+		// ```
+		// quoted-string  = DQUOTE *( qdtext / quoted-pair ) DQUOTE
+		// qdtext         = HTAB / SP /%x21 / %x23-5B / %x5D-7E / obs-text
+		// quoted-pair    = "\" ( HTAB / SP / VCHAR / obs-text )
+		// ```
+		loop
+		{
+			match Self::next(bytes, reentry_point, remaining_line_length, too_long)?
+			{
+				b'"' => return Self::next(bytes, reentry_point, remaining_line_length, too_long),
+
+				b'\\' => match Self::next(bytes, reentry_point, remaining_line_length, too_long)?
+				{
+					0x00 ... 0x08 | 0x0A ... 0x1F | 0x7F => return Err(Invalid(BadRequest("Invalid byte in chunk extension quoted-pair"))),
+
+					_ => (),
+				},
+
+				0x00 ... 0x08 | 0x0A ... 0x1F | 0x7F => return Err(Invalid(BadRequest("Invalid byte in chunk extension quoted-string"))),
+
+				_ => (),
+			}
+		}
+	}
+
+	/// Body bytes are not copied; each contiguous run of de-chunked bytes within a buffer of the `VectoredBuffer` is reported to `request_user.body_chunk()`.
+	#[inline(always)]
+	fn parse_chunk_data(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, mut remaining: u64) -> Result<(), Status<Self>>
+	{
+		while remaining != 0
+		{
+			let reentry_point = ChunkedReentryPoint::ChunkData(bytes.current_position(), remaining);
+
+			let maximum = min(remaining, ::std::usize::MAX as u64) as usize;
+			let (chunk_starts_at_inclusive, chunk_ends_at_exclusive) = bytes.if_has_more_consume_up_to(maximum, reentry_point)?;
+
+			request_user.body_chunk(bytes.vectored_buffer(), chunk_starts_at_inclusive, chunk_ends_at_exclusive).map_err(|invalid_reason| Invalid(invalid_reason))?;
+
+			remaining -= (chunk_ends_at_exclusive.offset - chunk_starts_at_inclusive.offset) as u64;
+		}
+
+		Self::parse_chunk_data_ends(bytes, request_user, request_parser_limits)
+	}
+
+	#[inline(always)]
+	fn parse_chunk_data_ends(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
+	{
+		const TooLong: InvalidReason = BadRequest("Chunk data not terminated");
+
+		let reentry_point = ChunkedReentryPoint::ChunkDataEnds(bytes.current_position());
+		let mut remaining_line_length = request_parser_limits.maximum_header_field_length;
+
+		let byte = Self::next(bytes, reentry_point, &mut remaining_line_length, TooLong)?;
+		Self::parse_line_ending(byte, bytes, reentry_point, &mut remaining_line_length, TooLong, "Chunk data CR not followed by LF", "Chunk data not terminated by CRLF")?;
+
+		Self::parse_chunk_size_line(bytes, request_user, request_parser_limits)
+	}
+
+	fn parse_trailer_part(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, mut trailer_section: HeaderSection) -> Result<(), Status<Self>>
+	{
+		while Self::parse_trailer_field(bytes, request_user, request_parser_limits, &mut trailer_section)?
+		{
+		}
+
+		request_user.body_finished().map_err(|invalid_reason| Invalid(invalid_reason))
+	}
+
+	/// Returns `false` if the empty line terminating the trailer part was parsed.
+	fn parse_trailer_field(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, trailer_section: &mut HeaderSection) -> Result<bool, Status<Self>>
+	{
+		const TooLong: InvalidReason = RequestHeaderFieldsTooLarge;
+
+		let name_starts_at_inclusive = bytes.current_position();
+		let reentry_point = ChunkedReentryPoint::TrailerField(*trailer_section, name_starts_at_inclusive);
+		let mut remaining_line_length = request_parser_limits.maximum_header_field_length;
+
+		let byte = Self::next(bytes, reentry_point, &mut remaining_line_length, TooLong)?;
+		match byte
+		{
+			b'\r' | b'\n' =>
+			{
+				Self::parse_line_ending(byte, bytes, reentry_point, &mut remaining_line_length, TooLong, "Trailer part CR not followed by LF", "Invalid byte in trailer part")?;
+				trailer_section.check_length_is_not_too_great(request_parser_limits, bytes).map_err(|invalid_reason| Invalid(invalid_reason))?;
+				return Ok(false)
+			}
+
+			_ => if unlikely!(!Self::is_token_byte(byte))
+			{
+				return Err(Invalid(BadRequest("Invalid byte in trailer field name token")))
+			}
+		}
+
+		let name_ends_at_exclusive = match Self::parse_token(bytes, reentry_point, &mut remaining_line_length, TooLong, "Invalid byte in trailer field name token")?
+		{
+			b':' => bytes.previous_position(),
+
+			_ => return Err(Invalid(BadRequest("Invalid byte in trailer field name token"))),
+		};
+
+		// See `HeaderReentryPoint` for the ABNF of a field value.
+		let mut value_starts_at_inclusive = None;
+		let mut value_ends_at_exclusive = bytes.current_position();
+		loop
+		{
+			let byte = Self::next(bytes, reentry_point, &mut remaining_line_length, TooLong)?;
+			match byte
+			{
+				b'\r' | b'\n' =>
+				{
+					Self::parse_line_ending(byte, bytes, reentry_point, &mut remaining_line_length, TooLong, "Trailer field value CR not followed by LF", "Invalid byte in trailer field value token")?;
+					break
+				}
+
+				b' ' | b'\t' => (),
+
+				0x00 ... 0x08 | 0x0A ... 0x1F | 0x7F => return Err(Invalid(BadRequest("Invalid byte in trailer field value token"))),
+
+				_ =>
+				{
+					if value_starts_at_inclusive.is_none()
+					{
+						value_starts_at_inclusive = Some(bytes.previous_position());
+					}
+					value_ends_at_exclusive = bytes.current_position();
+				}
+			}
+		}
+
+		trailer_section.check_length_is_not_too_great(request_parser_limits, bytes).map_err(|invalid_reason| Invalid(invalid_reason))?;
+		trailer_section.increment_number_of_header_fields(request_parser_limits).map_err(|invalid_reason| Invalid(invalid_reason))?;
+
+		let value_starts_at_inclusive = value_starts_at_inclusive.unwrap_or(value_ends_at_exclusive);
+		request_user.trailer_field(name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, value_ends_at_exclusive).map_err(|invalid_reason| Invalid(invalid_reason))?;
+
+		Ok(true)
+	}
+
+	/// `byte` is the byte already read that should start a line ending.
+	///
+	/// NOTE: A bare LF is accepted as a concession to a robustness concern from RFC 7230.
+	#[inline(always)]
+	fn parse_line_ending(byte: u8, bytes: &mut Bytes, reentry_point: Self, remaining_line_length: &mut usize, too_long: InvalidReason, cr_not_followed_by_lf: &'static str, invalid_byte: &'static str) -> Result<(), Status<Self>>
+	{
+		match byte
+		{
+			b'\r' => match Self::next(bytes, reentry_point, remaining_line_length, too_long)?
+			{
+				b'\n' => Ok(()),
+
+				_ => Err(Invalid(BadRequest(cr_not_followed_by_lf))),
+			},
+
+			b'\n' => Ok(()),
+
+			_ => Err(Invalid(BadRequest(invalid_byte))),
+		}
+	}
+
+	/// Parses the remainder of a token whose first byte has already been read and validated; returns the first byte after the token.
+	#[inline(always)]
+	fn parse_token(bytes: &mut Bytes, reentry_point: Self, remaining_line_length: &mut usize, too_long: InvalidReason, invalid_byte: &'static str) -> Result<u8, Status<Self>>
+	{
+		loop
+		{
+			let byte = Self::next(bytes, reentry_point, remaining_line_length, too_long)?;
+			if !Self::is_token_byte(byte)
+			{
+				return match byte
+				{
+					0x00 ... 0x08 | 0x0B ... 0x0C | 0x0E ... 0x1F | 0x7F ... 0xFF => Err(Invalid(BadRequest(invalid_byte))),
+
+					_ => Ok(byte),
+				}
+			}
+		}
+	}
+
+	// RFC 7230, Section 3.2.6, Field Value Components:-
+	// ```
+	// token          = 1*tchar
+	//
+	// tchar          = "!" / "#" / "$" / "%" / "&" / "'" / "*"
+	//                / "+" / "-" / "." / "^" / "_" / "`" / "|" / "~"
+	//                / DIGIT / ALPHA
+	//                ; any VCHAR, except delimiters
+	// ```
+	#[inline(always)]
+	fn is_token_byte(byte: u8) -> bool
+	{
+		match byte
+		{
+			b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'.' | b'^' | b'_' | b'`' | b'|' | b'~' | b'0' ... b'9' | b'A' ... b'Z' | b'a' ... b'z' => true,
+
+			_ => false,
+		}
+	}
+
+	#[inline(always)]
+	fn next(bytes: &mut Bytes, reentry_point: Self, remaining_line_length: &mut usize, too_long: InvalidReason) -> Result<u8, Status<Self>>
+	{
+		if unlikely!(*remaining_line_length == 0)
+		{
+			return Err(Invalid(too_long))
+		}
+		*remaining_line_length -= 1;
+
+		bytes.if_has_more_return_current_value_and_increment(reentry_point)
+	}
+}
@@ -5,11 +5,11 @@
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 enum HeaderReentryPoint
 {
-	Begin(VectoredBufferOffset),
+	Begin(HeaderSection, VectoredBufferOffset),
 
-	HeaderNameEnds(VectoredBufferOffset, VectoredBufferOffset),
+	HeaderNameEnds(HeaderSection, VectoredBufferOffset, VectoredBufferOffset),
 
-	HeaderValueStarts(VectoredBufferOffset, VectoredBufferOffset, VectoredBufferOffset),
+	HeaderValueStarts(HeaderSection, VectoredBufferOffset, VectoredBufferOffset, VectoredBufferOffset),
 }
 
 impl HeaderReentryPoint
@@ -39,77 +39,108 @@ impl HeaderReentryPoint
 	// ```
 	// OWS            = *( SP / HTAB )
 	// ```
-	fn parse(bytes: &mut Bytes, request_user: &mut impl RequestUser) -> Result<(), Status<Self>>
+	fn parse(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, mut header_section: HeaderSection) -> Result<RequestBodyLength, Status<Self>>
 	{
 		while
 		{
-			Self::parse_header_field(bytes, request_user)?
+			Self::parse_header_field(bytes, request_user, request_parser_limits, &mut header_section)?
 		}
 		{
 		}
 
-		request_user.headers_finished()
+		Self::headers_finished(bytes, request_user, &header_section)
 	}
 
-	pub(crate) fn re_enter(self, bytes: &mut Bytes, request_user: &mut impl RequestUser) -> Result<(), Status<Self>>
+	/// Starts parsing the header fields of a request.
+	#[inline(always)]
+	pub(crate) fn begin(starts_at_inclusive: VectoredBufferOffset) -> Self
+	{
+		HeaderReentryPoint::Begin(HeaderSection::new(starts_at_inclusive), starts_at_inclusive)
+	}
+
+	/// Returns how the length of any request body that follows is to be determined.
+	pub(crate) fn re_enter(self, bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<RequestBodyLength, Status<Self>>
 	{
 		use self::HeaderReentryPoint::*;
 
-		match self
+		let header_section = match self
 		{
-			Begin(starts_at) =>
+			Begin(header_section, starts_at) =>
 			{
 				bytes.reset(starts_at);
 
-				if Self::parse_header_field(bytes, request_user)?
-				{
-					return Ok(())
-				}
+				header_section
 			}
 
-			HeaderNameEnds(name_starts_at_inclusive, name_ends_at_exclusive) =>
+			HeaderNameEnds(mut header_section, name_starts_at_inclusive, name_ends_at_exclusive) =>
 			{
 				bytes.reset(name_ends_at_exclusive);
 
-				if Self::parse_after_header_name(bytes, request_user, name_starts_at_inclusive, name_ends_at_exclusive)?
+				if !Self::parse_after_header_name(bytes, request_user, request_parser_limits, &mut header_section, name_starts_at_inclusive, name_ends_at_exclusive)?
 				{
-					return Ok(())
+					return Self::headers_finished(bytes, request_user, &header_section)
 				}
+
+				header_section
 			}
 
-			HeaderValueStarts(name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive) =>
+			HeaderValueStarts(mut header_section, name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive) =>
 			{
 				bytes.reset(value_starts_at_inclusive);
 
-				if Self::parse_header_value(bytes, request_user, name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive)?
+				if !Self::parse_header_value(bytes, request_user, request_parser_limits, &mut header_section, name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive)?
 				{
-					return Ok(())
+					return Self::headers_finished(bytes, request_user, &header_section)
 				}
+
+				header_section
 			}
-		}
+		};
 
-		Self::parse(bytes, request_user)
+		Self::parse(bytes, request_user, request_parser_limits, header_section)
 	}
 
 	#[inline(always)]
-	fn check_amount_parsed_is_not_too_great(name_starts_at_inclusive: VectoredBufferOffset, bytes: &Bytes) -> Result<(), Status<Self>>
+	fn headers_finished(bytes: &Bytes, request_user: &mut impl RequestUser, header_section: &HeaderSection) -> Result<RequestBodyLength, Status<Self>>
 	{
-		const MaximumAmountToParse: usize = 1024;
+		let request_body_length = header_section.request_body_length();
 
-		if bytes.current_pointer.difference(name_starts_at_inclusive) > MaximumAmountToParse
-		{
-			Err(Invalid(RequestHeaderFieldsTooLarge))
-		}
-		else
+		request_user.headers_finished(bytes.vectored_buffer(), request_body_length, header_section.expects_continue()).map_err(|invalid_reason| Invalid(invalid_reason))?;
+
+		Ok(request_body_length)
+	}
+
+	#[inline(always)]
+	fn check_amount_parsed_is_not_too_great(request_parser_limits: &RequestParserLimits, header_section: &HeaderSection, name_starts_at_inclusive: VectoredBufferOffset, bytes: &Bytes) -> Result<(), Status<Self>>
+	{
+		if bytes.number_of_bytes_parsed_since(name_starts_at_inclusive) > request_parser_limits.maximum_header_field_length
 		{
-			Ok(())
+			return Err(Invalid(RequestHeaderFieldsTooLarge))
 		}
+
+		header_section.check_length_is_not_too_great(request_parser_limits, bytes).map_err(|invalid_reason| Invalid(invalid_reason))
+	}
+
+	#[inline(always)]
+	fn header_field(bytes: &Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, header_section: &mut HeaderSection, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<bool, Status<Self>>
+	{
+		header_section.increment_number_of_header_fields(request_parser_limits).map_err(|invalid_reason| Invalid(invalid_reason))?;
+
+		let vectored_buffer = bytes.vectored_buffer();
+
+		let known_header = KnownHeader::classify_vectored(vectored_buffer, name_starts_at_inclusive, name_ends_at_exclusive);
+
+		header_section.framing_header_field(known_header, &ImmutableVectoredBufferSlice::new(vectored_buffer, value_starts_at_inclusive, value_ends_at_exclusive)).map_err(|invalid_reason| Invalid(invalid_reason))?;
+
+		request_user.header_field(known_header, name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, value_ends_at_exclusive).map_err(|invalid_reason| Invalid(invalid_reason))?;
+
+		Ok(true)
 	}
 
-	fn parse_header_field(bytes: &mut Bytes, request_user: &mut impl RequestUser) -> Result<bool, Status<Self>>
+	fn parse_header_field(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, header_section: &mut HeaderSection) -> Result<bool, Status<Self>>
 	{
-		let name_starts_at_inclusive = bytes.parsing_starts_at;
-		let reentry_point = HeaderReentryPoint::Begin(name_starts_at_inclusive);
+		let name_starts_at_inclusive = bytes.current_position();
+		let reentry_point = HeaderReentryPoint::Begin(*header_section, name_starts_at_inclusive);
 		let name_ends_at_exclusive = loop
 		{
 			match bytes.if_has_more_return_current_value_and_increment(reentry_point)?
@@ -122,84 +153,82 @@ impl HeaderReentryPoint
 				}
 
 				// NOTE: A concession to a robustness concern from RFC 7230.
-				b"\n" => return Ok(false),
+				b'\n' => return Ok(false),
+
+				b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'.' | b'^' | b'_' | b'`' | b'|' | b'~' | b'0' ... b'9' | b'A' ... b'Z' | b'a' ... b'z' => (),
+
+				b':' => break bytes.previous_position(),
 
-				'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'.' | b'^' | b'_' | b'`' | b'|' | b'~' | b'0' ... b'9' | b'A' ... b'Z' | b'a' ... b'z' => (),
+				// RFC 7230, Section 3.2.4, Field Parsing: a line starting with whitespace is an `obs-fold` continuation of the previous line and a server MUST either reject the request or replace the `obs-fold` with spaces.
+				// Replacing is not possible without copying, and rejecting prevents a proxy in front of us from seeing different header fields to us.
+				b' ' | b'\t' if bytes.previous_position() == name_starts_at_inclusive => return Err(Invalid(ObsoleteLineFolding)),
 
-				b':' => break bytes.current_pointer,
+				// RFC 7230, Section 3.2.4, Field Parsing: a server MUST reject any request with whitespace between a header field-name and colon.
+				b' ' | b'\t' => return Err(Invalid(WhitespaceBeforeHeaderFieldColon)),
 
 				_ => return Err(Invalid(BadRequest("Invalid byte in header name token"))),
 			}
 
-			Self::check_amount_parsed_is_not_too_great(name_starts_at_inclusive, bytes)?
+			Self::check_amount_parsed_is_not_too_great(request_parser_limits, header_section, name_starts_at_inclusive, bytes)?
 		};
 
-		Self::parse_after_header_name(bytes, request_user, name_starts_at_inclusive, name_ends_at_exclusive)
+		Self::parse_after_header_name(bytes, request_user, request_parser_limits, header_section, name_starts_at_inclusive, name_ends_at_exclusive)
 	}
 
-	fn parse_after_header_name(bytes: &mut Bytes, request_user: &mut impl RequestUser, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset) -> Result<bool, Status<Self>>
+	fn parse_after_header_name(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, header_section: &mut HeaderSection, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset) -> Result<bool, Status<Self>>
 	{
-		let name_starts_at_inclusive = bytes.current_pointer;
-
-		let reentry_point = HeaderReentryPoint::HeaderNameEnds(name_starts_at_inclusive, name_ends_at_exclusive);
+		let reentry_point = HeaderReentryPoint::HeaderNameEnds(*header_section, name_starts_at_inclusive, name_ends_at_exclusive);
 		let value_starts_at_inclusive = loop
 		{
 			match bytes.if_has_more_return_current_value_and_increment(reentry_point)?
 			{
-				b' ' | b'\t' => continue,
+				b' ' | b'\t' => (),
 
 				0x00 ... 0x08 | 0x0A ... 0x1F | 0x7F => return Err(Invalid(BadRequest("Invalid byte in header value token"))),
 
 				_ => break bytes.previous_position(),
 			}
 
-			Self::check_amount_parsed_is_not_too_great(name_starts_at_inclusive)?
+			Self::check_amount_parsed_is_not_too_great(request_parser_limits, header_section, name_starts_at_inclusive, bytes)?
 		};
 
 		bytes.reset(value_starts_at_inclusive);
 
-		Self::parse_header_value(bytes, request_user, name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive)
+		Self::parse_header_value(bytes, request_user, request_parser_limits, header_section, name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive)
 	}
 
-	fn parse_header_value(bytes: &mut Bytes, request_user: &mut impl RequestUser, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset, value_starts_at_inclusive: VectoredBufferOffset) -> Result<bool, Status<Self>>
+	fn parse_header_value(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, header_section: &mut HeaderSection, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset, value_starts_at_inclusive: VectoredBufferOffset) -> Result<bool, Status<Self>>
 	{
-		let reentry_point = HeaderReentryPoint::HeaderValueStarts(name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive);
-		let mut last_whitespace_started_at_exclusive = value_starts_at_inclusive.current_pointer.next();
+		let reentry_point = HeaderReentryPoint::HeaderValueStarts(*header_section, name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive);
+		let mut value_ends_at_exclusive = value_starts_at_inclusive;
 		loop
 		{
+			if bytes.skip(|slice| StopBytes::HeaderFieldValue.number_of_bytes_before_stop_byte(slice)) != 0
+			{
+				value_ends_at_exclusive = bytes.current_position();
+			}
+
 			match bytes.if_has_more_return_current_value_and_increment(reentry_point)?
 			{
 				b'\r' => match bytes.if_has_more_return_current_value_and_increment(reentry_point)?
 				{
-					b'\n' =>
-					{
-						last_whitespace_started_at_exclusive;
-						request_user.header_field(name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, last_whitespace_started_at_exclusive).map_err(|invalid_reason| Invalid(invalid_reason))?;
-
-						return Ok(true)
-					}
+					b'\n' => return Self::header_field(bytes, request_user, request_parser_limits, header_section, name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, value_ends_at_exclusive),
 
 					_ => return Err(Invalid(BadRequest("Header field value CR not followed by LF"))),
 				},
 
 				// NOTE: A concession to a robustness concern from RFC 7230.
-				b"\n" =>
-				{
-					request_user.finished();
-					Ok(false)
-				},
+				b'\n' => return Self::header_field(bytes, request_user, request_parser_limits, header_section, name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, value_ends_at_exclusive),
 
-				b' ' | b'\t' =>
-				{
-					last_whitespace_started_at_exclusive = bytes.current_pointer;
-				}
+				// Trailing whitespace is not part of the value, so does not move `value_ends_at_exclusive`.
+				b' ' | b'\t' => (),
 
 				0x00 ... 0x08 | 0x0A ... 0x1F | 0x7F => return Err(Invalid(BadRequest("Invalid byte in header value token"))),
 
-				_ => (),
+				_ => value_ends_at_exclusive = bytes.current_position(),
 			}
 
-			Self::check_amount_parsed_is_not_too_great(name_starts_at_inclusive)?
+			Self::check_amount_parsed_is_not_too_great(request_parser_limits, header_section, name_starts_at_inclusive, bytes)?
 		}
 	}
 }
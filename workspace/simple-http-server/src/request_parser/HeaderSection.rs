@@ -0,0 +1,233 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Progress through the header fields (or the trailer fields of a `chunked` request body) of a request, so that `RequestParserLimits` can be enforced across re-entries.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct HeaderSection
+{
+	starts_at_inclusive: VectoredBufferOffset,
+	number_of_header_fields: usize,
+	content_length: Option<u64>,
+	transfer_encoding_is_chunked: bool,
+	expects_continue: bool,
+}
+
+impl HeaderSection
+{
+	#[inline(always)]
+	fn new(starts_at_inclusive: VectoredBufferOffset) -> Self
+	{
+		Self
+		{
+			starts_at_inclusive,
+			number_of_header_fields: 0,
+			content_length: None,
+			transfer_encoding_is_chunked: false,
+			expects_continue: false,
+		}
+	}
+
+	/// Only valid once all header fields have been parsed.
+	#[inline(always)]
+	fn expects_continue(&self) -> bool
+	{
+		self.expects_continue
+	}
+
+	/// Only valid once all header fields have been parsed.
+	#[inline(always)]
+	fn request_body_length(&self) -> RequestBodyLength
+	{
+		if self.transfer_encoding_is_chunked
+		{
+			RequestBodyLength::Chunked
+		}
+		else
+		{
+			match self.content_length
+			{
+				None => RequestBodyLength::None,
+
+				Some(content_length) => RequestBodyLength::ContentLength(content_length),
+			}
+		}
+	}
+
+	/// Enforces RFC 7230, Section 3.3.3, Message Body Length, so that we can never disagree with a proxy in front of us about where a request ends.
+	///
+	/// Unlike RFC 7230, does not accept several identical `Content-Length` values; no legitimate client sends them.
+	#[inline(always)]
+	fn framing_header_field(&mut self, known_header: KnownHeader, value: &ImmutableVectoredBufferSlice) -> Result<(), InvalidReason>
+	{
+		match known_header
+		{
+			KnownHeader::ContentLength =>
+			{
+				if self.content_length.is_some()
+				{
+					return Err(MultipleContentLength)
+				}
+
+				if self.transfer_encoding_is_chunked
+				{
+					return Err(ContentLengthAndTransferEncoding)
+				}
+
+				self.content_length = Some(Self::parse_content_length(value)?);
+				Ok(())
+			}
+
+			KnownHeader::TransferEncoding =>
+			{
+				if self.content_length.is_some()
+				{
+					return Err(ContentLengthAndTransferEncoding)
+				}
+
+				if self.transfer_encoding_is_chunked
+				{
+					return Err(TransferEncodingNotFinallyChunked)
+				}
+
+				Self::validate_transfer_encoding(value)?;
+				self.transfer_encoding_is_chunked = true;
+				Ok(())
+			}
+
+			// RFC 7231, Section 5.1.1, Expect:-
+			// ```
+			// Expect  = "100-continue"
+			// ```
+			//
+			// A server that receives an Expect field-value other than `100-continue` MAY respond with a `417 Expectation Failed`.
+			KnownHeader::Expect => if value.eq_ignore_ascii_case(b"100-continue")
+			{
+				self.expects_continue = true;
+				Ok(())
+			}
+			else
+			{
+				Err(ExpectationFailed)
+			},
+
+			_ => Ok(()),
+		}
+	}
+
+	// RFC 7230, Section 3.3.2, Content-Length:-
+	// ```
+	// Content-Length = 1*DIGIT
+	// ```
+	#[inline(always)]
+	fn parse_content_length(value: &ImmutableVectoredBufferSlice) -> Result<u64, InvalidReason>
+	{
+		let mut content_length: u64 = 0;
+		let mut has_digits = false;
+		for byte in value.bytes()
+		{
+			let digit = match byte
+			{
+				b'0' ... b'9' => (byte - b'0') as u64,
+
+				b',' => return Err(MultipleContentLength),
+
+				_ => return Err(InvalidContentLength),
+			};
+
+			content_length = content_length.checked_mul(10).and_then(|content_length| content_length.checked_add(digit)).ok_or(InvalidContentLength)?;
+			has_digits = true;
+		}
+
+		if has_digits
+		{
+			Ok(content_length)
+		}
+		else
+		{
+			Err(InvalidContentLength)
+		}
+	}
+
+	// RFC 7230, Section 3.3.1, Transfer-Encoding:-
+	// ```
+	// Transfer-Encoding = 1#transfer-coding
+	// ```
+	//
+	// Only a sole `chunked` is supported; if other transfer-codings precede it, the request is `501 Not Implemented`.
+	#[inline(always)]
+	fn validate_transfer_encoding(value: &ImmutableVectoredBufferSlice) -> Result<(), InvalidReason>
+	{
+		if value.eq_ignore_ascii_case(b"chunked")
+		{
+			return Ok(())
+		}
+
+		let mut earlier_transfer_coding_was_chunked = false;
+		let mut final_transfer_coding_is_chunked = false;
+		let mut matched = 0;
+		for byte in value.bytes()
+		{
+			const Chunked: &'static [u8] = b"chunked";
+
+			match byte
+			{
+				b',' =>
+				{
+					earlier_transfer_coding_was_chunked |= final_transfer_coding_is_chunked;
+					final_transfer_coding_is_chunked = false;
+					matched = 0;
+				}
+
+				b' ' | b'\t' => (),
+
+				_ => if matched < Chunked.len() && byte.eq_ignore_ascii_case(&Chunked[matched])
+				{
+					matched += 1;
+					final_transfer_coding_is_chunked = matched == Chunked.len();
+				}
+				else
+				{
+					matched = Chunked.len() + 1;
+					final_transfer_coding_is_chunked = false;
+				},
+			}
+		}
+
+		if final_transfer_coding_is_chunked && !earlier_transfer_coding_was_chunked
+		{
+			Err(UnsupportedTransferCoding)
+		}
+		else
+		{
+			Err(TransferEncodingNotFinallyChunked)
+		}
+	}
+
+	#[inline(always)]
+	fn check_length_is_not_too_great(&self, request_parser_limits: &RequestParserLimits, bytes: &Bytes) -> Result<(), InvalidReason>
+	{
+		if bytes.number_of_bytes_parsed_since(self.starts_at_inclusive) > request_parser_limits.maximum_header_section_length
+		{
+			Err(RequestHeaderFieldsTooLarge)
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+
+	#[inline(always)]
+	fn increment_number_of_header_fields(&mut self, request_parser_limits: &RequestParserLimits) -> Result<(), InvalidReason>
+	{
+		if self.number_of_header_fields == request_parser_limits.maximum_number_of_header_fields
+		{
+			Err(RequestHeaderFieldsTooLarge)
+		}
+		else
+		{
+			self.number_of_header_fields += 1;
+			Ok(())
+		}
+	}
+}
@@ -2,29 +2,77 @@
 // Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
 
 
+/// Why a request is invalid, and the status code of the response to it.
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub enum InvalidReason
 {
 	/// 400 Bad Request
 	BadRequest(&'static str),
 
+	/// 400 Bad Request
+	///
+	/// A header field line started with whitespace (RFC 7230, Section 3.2.4).
+	ObsoleteLineFolding,
+
+	/// 400 Bad Request
+	///
+	/// There was whitespace between a header field name and its colon (RFC 7230, Section 3.2.4).
+	WhitespaceBeforeHeaderFieldColon,
+
+	/// 400 Bad Request
+	///
+	/// A `Content-Length` header value was not a decimal number or was too large.
+	InvalidContentLength,
+
+	/// 400 Bad Request
+	///
+	/// There was more than one `Content-Length` header, or a `Content-Length` header with a list of values.
+	MultipleContentLength,
+
+	/// 400 Bad Request
+	///
+	/// There was both a `Content-Length` and a `Transfer-Encoding` header (RFC 7230, Section 3.3.3, point 3).
+	ContentLengthAndTransferEncoding,
+
+	/// 400 Bad Request
+	///
+	/// The final transfer-coding was not `chunked`, `chunked` was applied more than once or there was more than one `Transfer-Encoding` header (RFC 7230, Section 3.3.3, point 3).
+	TransferEncodingNotFinallyChunked,
+
 	/// 405 Method Not Allowed
 	MethodNotAllowed,
 
+	/// 413 Payload Too Large
+	///
+	/// Usually returned by a `RequestUser` from `headers_finished()` to reject a request body without reading it.
+	PayloadTooLarge,
+
 	/// 414 URI Too Long
 	UriTooLong,
 
+	/// 417 Expectation Failed
+	///
+	/// The `Expect` header was not `100-continue`, or a `RequestUser` rejected a request body from `headers_finished()` without reading it.
+	ExpectationFailed,
+
+	/// 421 Misdirected Request
+	///
+	/// The `Host` header did not match the virtual host selected for the connection.
+	MisdirectedRequest,
+
 	/// 431 Request Header Fields Too Large
 	RequestHeaderFieldsTooLarge,
 
+	/// 501 Not Implemented
+	///
+	/// A transfer-coding other than `chunked` was applied (RFC 7230, Section 3.3.1).
+	UnsupportedTransferCoding,
+
 	/// 505 HTTP Version Not Supported
 	HttpVersionNotSupported,
 
 
 // 408 Request Timeout
 // 411 Length Required
-// 413 Payload Too Large
 // 415 Unsupported Media Type
-
-// ?421 Misdirected Request
 }
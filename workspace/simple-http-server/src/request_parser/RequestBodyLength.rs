@@ -0,0 +1,23 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// How the length of a request body is determined, as decided once all headers have been parsed.
+///
+/// See RFC 7230, Section 3.3.3, Message Body Length.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum RequestBodyLength
+{
+	/// There is no request body (neither `Content-Length` nor `Transfer-Encoding` were present).
+	None,
+
+	/// The request body is exactly this number of bytes long, as specified by a `Content-Length` header.
+	///
+	/// A value of zero is equivalent to `None`.
+	ContentLength(u64),
+
+	/// The request body uses the `chunked` transfer-coding, as specified by a `Transfer-Encoding` header whose final transfer-coding is `chunked`.
+	///
+	/// Chunk-size lines and chunk extensions are consumed by the parser; only the de-chunked data is reported.
+	Chunked,
+}
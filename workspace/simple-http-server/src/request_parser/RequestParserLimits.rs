@@ -0,0 +1,59 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Limits applied whilst parsing a request, to prevent over-use of server resources by a client.
+///
+/// All lengths are in bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequestParserLimits
+{
+	/// Maximum length of a request's target-uri.
+	///
+	/// Exceeding this results in a `414 URI Too Long`.
+	///
+	/// Defaults to 8,192 bytes (8Kb).
+	pub maximum_target_uri_length: usize,
+
+	/// Maximum length of an individual header field, including its name, colon, any whitespace and its value, but excluding the terminating line ending.
+	///
+	/// Also limits each chunk size line and each trailer field of a `chunked` request body.
+	///
+	/// Exceeding this results in a `431 Request Header Fields Too Large`.
+	///
+	/// Defaults to 1,024 bytes (1Kb).
+	pub maximum_header_field_length: usize,
+
+	/// Maximum length of all the header fields of a request, including their line endings.
+	///
+	/// Also limits, separately, the trailer part of a `chunked` request body.
+	///
+	/// Exceeding this results in a `431 Request Header Fields Too Large`.
+	///
+	/// Defaults to 16,384 bytes (16Kb).
+	pub maximum_header_section_length: usize,
+
+	/// Maximum number of header fields in a request.
+	///
+	/// Also limits, separately, the number of trailer fields of a `chunked` request body.
+	///
+	/// Exceeding this results in a `431 Request Header Fields Too Large`.
+	///
+	/// Defaults to 64.
+	pub maximum_number_of_header_fields: usize,
+}
+
+impl Default for RequestParserLimits
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			maximum_target_uri_length: 8_192,
+			maximum_header_field_length: 1_024,
+			maximum_header_section_length: 16_384,
+			maximum_number_of_header_fields: 64,
+		}
+	}
+}
@@ -2,34 +2,119 @@
 // Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
 
 
+/// A request being parsed.
+///
+/// Requests may be pipelined (RFC 7230, Section 6.3.2); once a request has been completely parsed, parsing of the next request starts with the byte immediately after it.
 pub struct Request<RU: RequestUser>
 {
 	bytes: Bytes,
 	state: RequestState,
 	request_user: RU,
+	request_parser_limits: RequestParserLimits,
 }
 
 impl<RU: RequestUser> Request<RU>
 {
-	pub fn re_enter(&mut self)
+	/// Creates a new instance.
+	#[inline(always)]
+	pub fn new(bytes: Bytes, request_user: RU, request_parser_limits: RequestParserLimits) -> Self
+	{
+		Self
+		{
+			state: RequestState::RequestMethod(bytes.current_position()),
+			bytes,
+			request_user,
+			request_parser_limits,
+		}
+	}
+
+	/// Reads more plain text from `read`.
+	///
+	/// Returns the number of bytes read; this will be zero if the underlying `VectoredBuffer` is full, in which case the next call to `re_enter()` fails if the current request is still incomplete.
+	///
+	/// Request body bytes are released once they have been passed to `RequestUser::body_chunk()`, so a request body may be larger than the underlying `VectoredBuffer`.
+	#[inline(always)]
+	pub fn read_into(&mut self, read: &mut impl Read) -> io::Result<usize>
+	{
+		self.bytes.read_into(read)
+	}
+
+	/// Parses as much as possible of the current request.
+	///
+	/// Returns `Ok(true)` if the current request has been completely parsed (ie `body_finished()` has been called), and `Ok(false)` if more bytes need to be read.
+	///
+	/// If more bytes need to be read but the underlying `VectoredBuffer` is full, even after releasing the request body bytes already passed to `RequestUser::body_chunk()`, the current request is too large to parse and an `Err` of `UriTooLong`, `RequestHeaderFieldsTooLarge` or `PayloadTooLarge` is returned.
+	pub fn re_enter(&mut self) -> Result<bool, InvalidReason>
 	{
-		match self.state.re_enter(&mut self.bytes, &mut self.request_user)
+		match self.state.re_enter(&mut self.bytes, &mut self.request_user, &self.request_parser_limits)
 		{
-			Ok(()) => (),
+			Ok(()) =>
+			{
+				self.state = RequestState::RequestMethod(self.bytes.current_position());
+				Ok(true)
+			}
 
 			Err(status) => match status
 			{
+				RanOutOfBytes(reentry_point) =>
+				{
+					let reentry_point = reentry_point.release_parsed_body_bytes(&mut self.bytes);
 
+					if unlikely!(self.bytes.is_full())
+					{
+						return Err(reentry_point.too_large())
+					}
+
+					self.state = reentry_point;
+					Ok(false)
+				}
+
+				Invalid(invalid_reason) => Err(invalid_reason),
 			}
 		}
 	}
 
-}
+	/// Call once a completely parsed request is no longer needed.
+	///
+	/// The space it took in the underlying `VectoredBuffer` is re-used; any bytes of a subsequent, pipelined request which have already been received are moved to the start of the `VectoredBuffer`.
+	#[inline(always)]
+	pub fn request_no_longer_needed(&mut self)
+	{
+		if likely!(!self.bytes.has_unparsed_bytes())
+		{
+			self.bytes.empty();
+		}
+		else
+		{
+			self.bytes.compact();
+		}
+		self.state = RequestState::RequestMethod(self.bytes.current_position());
+	}
 
+	/// The request user.
+	#[inline(always)]
+	pub fn request_user(&mut self) -> &mut RU
+	{
+		&mut self.request_user
+	}
 
+	/// The underlying `VectoredBuffer`, eg to examine ranges reported to the request user.
+	#[inline(always)]
+	pub fn vectored_buffer(&self) -> &VectoredBuffer
+	{
+		self.bytes.vectored_buffer()
+	}
 
+	/// The underlying `VectoredBuffer` and the request user, eg so that the request user can examine ranges reported to it.
+	#[inline(always)]
+	pub fn vectored_buffer_and_request_user(&mut self) -> (&VectoredBuffer, &mut RU)
+	{
+		(self.bytes.vectored_buffer(), &mut self.request_user)
+	}
+}
 
 
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 enum RequestState
 {
 	RequestMethod(VectoredBufferOffset),
@@ -39,42 +124,78 @@ enum RequestState
 	HttpVersion(VectoredBufferOffset),
 
 	Headers(HeaderReentryPoint),
+
+	/// The position at which the request body starts and where to resume parsing it.
+	Body(VectoredBufferOffset, BodyReentryPoint),
 }
 
 impl RequestState
 {
-	pub(crate) fn re_enter(&self, bytes: &mut Bytes, request_user: &mut impl RequestUser) -> Result<(), Status<Self>>
+	pub(crate) fn re_enter(self, bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
 	{
 		use self::RequestState::*;
-		use self::Status::*;
 
-		match *self
+		match self
 		{
 			RequestMethod(starts_at) =>
 			{
 				bytes.reset(starts_at);
 
-				Self::parse_request_method(bytes, request_user)
+				Self::parse_request_method(bytes, request_user, request_parser_limits)
 			}
 
-			TargetUri(reentry_point) => Self::parse_target_uri(bytes, request_user, reentry_point),
+			TargetUri(reentry_point) => Self::parse_target_uri(bytes, request_user, request_parser_limits, reentry_point),
 
 			HttpVersion(starts_at) =>
 			{
 				bytes.reset(starts_at);
 
-				Self::parse_http_version(bytes, request_user)
+				Self::parse_http_version(bytes, request_user, request_parser_limits)
 			}
 
-			Headers(reentry_point) => Self::parse_headers(bytes, request_user, reentry_point),
+			Headers(reentry_point) => Self::parse_headers(bytes, request_user, request_parser_limits, reentry_point),
+
+			Body(body_starts_at, reentry_point) => Self::parse_body(bytes, request_user, request_parser_limits, body_starts_at, reentry_point),
 		}
 	}
 
-	fn parse_request_method(bytes: &mut Bytes, request_user: &mut impl RequestUser) -> Result<(), Status<Self>>
+	/// The reason a request is invalid if its incomplete parsing state has been reached and the underlying `VectoredBuffer` is full.
+	#[inline(always)]
+	fn too_large(self) -> InvalidReason
+	{
+		use self::RequestState::*;
+
+		match self
+		{
+			RequestMethod(_) | TargetUri(_) => UriTooLong,
+
+			HttpVersion(_) | Headers(_) => RequestHeaderFieldsTooLarge,
+
+			Body(..) => PayloadTooLarge,
+		}
+	}
+
+	/// Body bytes before the reentry point have already been passed to `RequestUser::body_chunk()`; releasing them means a request body can be larger than the underlying `VectoredBuffer`.
+	///
+	/// Bytes before the body are kept, as the ranges reported to `header_field()` must remain valid until the request is no longer needed.
+	#[inline(always)]
+	fn release_parsed_body_bytes(self, bytes: &mut Bytes) -> Self
+	{
+		use self::RequestState::*;
+
+		match self
+		{
+			Body(body_starts_at, reentry_point) => Body(body_starts_at, reentry_point.release_parsed_bytes(bytes, body_starts_at)),
+
+			_ => self,
+		}
+	}
+
+	fn parse_request_method(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
 	{
 		use self::RequestMethod::*;
 
-		let reentry_point = RequestState::RequestMethod(bytes.current_pointer);
+		let reentry_point = RequestState::RequestMethod(bytes.current_position());
 
 		let request_method = match bytes.if_has_more_return_current_value_and_increment(reentry_point)?
 		{
@@ -84,11 +205,11 @@ impl RequestState
 
 			b'P' => match bytes.if_has_more_return_current_value_and_increment(reentry_point)?
 			{
-				'A' => bytes.is_slice(b"TCH ", reentry_point, MethodNotAllowed, PATCH),
+				b'A' => bytes.is_slice(b"TCH ", reentry_point, MethodNotAllowed, PATCH),
 
-				'O' => bytes.is_slice(b"ST ", reentry_point, MethodNotAllowed, POST),
+				b'O' => bytes.is_slice(b"ST ", reentry_point, MethodNotAllowed, POST),
 
-				'U' => bytes.is_slice(b"T ", reentry_point, MethodNotAllowed, PUT),
+				b'U' => bytes.is_slice(b"T ", reentry_point, MethodNotAllowed, PUT),
 
 				_ => return Err(Invalid(MethodNotAllowed)),
 			}
@@ -102,19 +223,19 @@ impl RequestState
 
 		request_user.method(request_method).map_err(|invalid_reason| Invalid(invalid_reason))?;
 
-		Self::parse_target_uri(bytes, request_user, TargetUriReentryPoint::Begin(bytes.current_pointer))
+		Self::parse_target_uri(bytes, request_user, request_parser_limits, TargetUriReentryPoint::Begin(bytes.current_position()))
 	}
 
 	#[inline(always)]
-	fn parse_target_uri(bytes: &mut Bytes, request_user: &mut impl RequestUser, reentry_point: TargetUriReentryPoint) -> Result<(), Status<Self>>
+	fn parse_target_uri(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, reentry_point: TargetUriReentryPoint) -> Result<(), Status<Self>>
 	{
-		reentry_point.re_enter(bytes, request_user).map_err(|status| match status
+		reentry_point.re_enter(bytes, request_user, request_parser_limits).map_err(|status| match status
 		{
 			Invalid(invalid_reason) => Invalid(invalid_reason),
 			RanOutOfBytes(reentry_point) => RanOutOfBytes(RequestState::TargetUri(reentry_point)),
 		})?;
 
-		Self::parse_http_version(bytes, request_user)
+		Self::parse_http_version(bytes, request_user, request_parser_limits)
 	}
 
 	/// Only supports parsing of HTTP/1.1.
@@ -123,9 +244,9 @@ impl RequestState
 	///
 	/// * HTTP/1.0 is obsolescent and complicates Host and header handling for very little gain.
 	/// * HTTP/0.9 is extremely obsolete.
-	fn parse_http_version(bytes: &mut Bytes, request_user: &mut impl RequestUser) -> Result<(), Status<Self>>
+	fn parse_http_version(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
 	{
-		let reentry_point = RequestState::HttpVersion(bytes.current_pointer);
+		let reentry_point = RequestState::HttpVersion(bytes.current_position());
 
 		bytes.is_slice(b"HTTP/1.1", reentry_point, HttpVersionNotSupported, ())?;
 
@@ -139,25 +260,42 @@ impl RequestState
 			},
 
 			// NOTE: A concession to a robustness concern from RFC 7230.
-			b"\n" => (),
+			b'\n' => (),
 
-			_ => return Err(Invalid(BadRequest(b"Status line not terminated correctly"))),
+			_ => return Err(Invalid(BadRequest("Status line not terminated correctly"))),
 		}
 
 		request_user.status_line_finished().map_err(|invalid_reason| Invalid(invalid_reason))?;
 
-		Self::parse_headers(bytes, request_user, HeaderReentryPoint::Begin(bytes.current_pointer))
+		Self::parse_headers(bytes, request_user, request_parser_limits, HeaderReentryPoint::begin(bytes.current_position()))
 	}
 
-	fn parse_headers(bytes: &mut Bytes, request_user: &mut impl RequestUser, reentry_point: HeaderReentryPoint) -> Result<(), Status<Self>>
+	fn parse_headers(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, reentry_point: HeaderReentryPoint) -> Result<(), Status<Self>>
 	{
-		reentry_point.re_enter(bytes, request_user).map_err(|status| match status
+		let request_body_length = reentry_point.re_enter(bytes, request_user, request_parser_limits).map_err(|status| match status
 		{
 			Invalid(invalid_reason) => Invalid(invalid_reason),
 			RanOutOfBytes(reentry_point) => RanOutOfBytes(RequestState::Headers(reentry_point)),
 		})?;
 
-		Ok(())
+		let body_starts_at = bytes.current_position();
+		BodyReentryPoint::begin(request_body_length, bytes, request_user, request_parser_limits).map_err(|status| Self::map_body_status(status, body_starts_at))
+	}
+
+	#[inline(always)]
+	fn parse_body(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits, body_starts_at: VectoredBufferOffset, reentry_point: BodyReentryPoint) -> Result<(), Status<Self>>
+	{
+		reentry_point.re_enter(bytes, request_user, request_parser_limits).map_err(|status| Self::map_body_status(status, body_starts_at))
+	}
+
+	#[inline(always)]
+	fn map_body_status(status: Status<BodyReentryPoint>, body_starts_at: VectoredBufferOffset) -> Status<Self>
+	{
+		match status
+		{
+			Invalid(invalid_reason) => Invalid(invalid_reason),
+			RanOutOfBytes(reentry_point) => RanOutOfBytes(RequestState::Body(body_starts_at, reentry_point)),
+		}
 	}
 }
 
@@ -173,6 +311,9 @@ impl RequestState
 /// * `status_line_finished()`
 /// * `header_field()` (may be called once, many times or not at all (although in that event, the HTTP/1.1 request is invalid as a `Host:` header should have been provided).
 /// * `headers_finished()`
+/// * `body_chunk()` (may be called once, many times or not at all).
+/// * `trailer_field()` (only for a `chunked` request body; may be called once, many times or not at all).
+/// * `body_finished()`
 pub trait RequestUser
 {
 	/// Called exactly once.
@@ -224,10 +365,42 @@ pub trait RequestUser
 	/// May never be called.
 	///
 	/// Header field names and values are checked for token validity.
-	fn header_field(&mut self, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), ()>;
+	///
+	/// `known_header` classifies the header field's name; it is `KnownHeader::Other` if the name is not well-known.
+	fn header_field(&mut self, known_header: KnownHeader, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>;
 
 	/// Will be called once headers have been parsed and before any request body is examined.
 	///
+	/// `request_body_length` is determined by the parser from the `Content-Length` and `Transfer-Encoding` headers seen in `header_field()`, which have already been checked for conflicts (RFC 7230, Section 3.3.3).
+	///
+	/// `vectored_buffer` can be used to examine the header fields reported to `header_field()`.
+	///
+	/// If `expects_continue` is true, the client sent `Expect: 100-continue` and may be waiting for a `100 Continue` interim response before sending the request body.
+	/// Return `Ok(())` to accept the request body, or `Err(ExpectationFailed)` or `Err(PayloadTooLarge)` to reject it without it being read.
+	///
 	/// There may then follow a request body.
-	fn headers_finished(&mut self) -> Result<(), ()>;
+	fn headers_finished(&mut self, vectored_buffer: &VectoredBuffer, request_body_length: RequestBodyLength, expects_continue: bool) -> Result<(), InvalidReason>;
+
+	/// Will be called for each contiguous run of request body bytes.
+	///
+	/// May never be called (eg if there is no request body or it is of zero length).
+	///
+	/// Bytes are not copied; a run never crosses a buffer boundary in the underlying `VectoredBuffer`, so a body may be delivered in several calls.
+	///
+	/// `vectored_buffer` can be used to examine the run; it is only valid until this call returns, as the bytes are then released so that a request body larger than the `VectoredBuffer` can be streamed.
+	///
+	/// For a `chunked` request body, the bytes are de-chunked data; chunk boundaries are not preserved.
+	fn body_chunk(&mut self, vectored_buffer: &VectoredBuffer, chunk_starts_at_inclusive: VectoredBufferOffset, chunk_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>;
+
+	/// Will be called for each trailer field encountered after the last chunk of a `chunked` request body.
+	///
+	/// May never be called.
+	///
+	/// Trailer field names and values are checked for token validity; the value excludes leading and trailing whitespace.
+	fn trailer_field(&mut self, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>;
+
+	/// Will be called exactly once.
+	///
+	/// Called after the final call to `body_chunk()` or `trailer_field()`, or after `headers_finished()` if there is no request body.
+	fn body_finished(&mut self) -> Result<(), InvalidReason>;
 }
@@ -0,0 +1,210 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Inclusive ranges of bytes at which a SIMD scan stops, so that the parser's byte-by-byte state machine can examine them.
+///
+/// The ranges may be a superset of the bytes the state machine treats specially; a valid byte in a range just takes the slower path.
+///
+/// Uses AVX2 if available (along with SSE4.2), otherwise SSE4.2; if neither is available, no bytes are skipped and the byte-by-byte state machine does all the work.
+/// Which is available is detected once, on first use.
+/// Whichever is used, only whole 32 or 16 byte blocks are scanned; any remainder at the end of a slice is left to the state machine.
+#[derive(Debug, Copy, Clone)]
+struct StopBytes
+{
+	/// Pairs of inclusive `(low, high)` bytes, in the layout that `_mm_cmpestri()` expects for `_SIDD_CMP_RANGES`.
+	ranges: [u8; 16],
+
+	/// Number of bytes used in `ranges`; always even.
+	ranges_length: i32,
+}
+
+impl StopBytes
+{
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	const Undetected: usize = 0;
+
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	const NoSimd: usize = 1;
+
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	const Sse4_2: usize = 2;
+
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	const Avx2: usize = 3;
+
+	/// Stops at whitespace (so trailing `OWS` can be excluded from a value), control characters, `CR`, `LF` and `DEL`.
+	///
+	/// See `HeaderReentryPoint::parse_header_value()`.
+	const HeaderFieldValue: Self = StopBytes
+	{
+		ranges:
+		[
+			0x00, 0x20,
+			0x7F, 0x7F,
+			0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+		],
+		ranges_length: 4,
+	};
+
+	/// Stops at any byte which is not a `pchar` (and so also at `/`, `?`, `#` and `SP`).
+	///
+	/// `_mm_cmpestri()` supports at most 8 ranges, so `=` is covered by the `<` to `?` range and takes the slower path.
+	///
+	/// See `TargetUriReentryPoint::parse_segments()`.
+	const TargetUriSegment: Self = StopBytes
+	{
+		ranges:
+		[
+			0x00, 0x20,
+			b'"', b'#',
+			b'/', b'/',
+			b'<', b'?',
+			b'[', b'^',
+			b'`', b'`',
+			b'{', b'}',
+			0x7F, 0xFF,
+		],
+		ranges_length: 16,
+	};
+
+	/// Stops at any byte which is not a `pchar`, `/` or `?` (and so also at `#` and `SP`).
+	///
+	/// See `TargetUriReentryPoint::parse_query()`.
+	const TargetUriQuery: Self = StopBytes
+	{
+		ranges:
+		[
+			0x00, 0x20,
+			b'"', b'#',
+			b'<', b'<',
+			b'>', b'>',
+			b'[', b'^',
+			b'`', b'`',
+			b'{', b'}',
+			0x7F, 0xFF,
+		],
+		ranges_length: 16,
+	};
+
+	/// Returns the number of bytes at the start of `slice` which are not stop bytes.
+	#[inline(always)]
+	fn number_of_bytes_before_stop_byte(&self, slice: &[u8]) -> usize
+	{
+		#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+		{
+			match Self::detected_instruction_set()
+			{
+				Self::Avx2 => return unsafe { self.avx2(slice) },
+
+				Self::Sse4_2 => return unsafe { self.sse4_2(slice) },
+
+				_ => (),
+			}
+		}
+
+		0
+	}
+
+	/// `is_x86_feature_detected!()` is too expensive to use for every slice scanned, so its result is cached.
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	#[inline(always)]
+	fn detected_instruction_set() -> usize
+	{
+		static DetectedInstructionSet: AtomicUsize = AtomicUsize::new(StopBytes::Undetected);
+
+		let detected_instruction_set = DetectedInstructionSet.load(Relaxed);
+		if likely!(detected_instruction_set != Self::Undetected)
+		{
+			return detected_instruction_set
+		}
+
+		let detected_instruction_set = if is_x86_feature_detected!("sse4.2")
+		{
+			// `avx2()` uses `sse4_2()` for any remainder at the end of a slice.
+			if is_x86_feature_detected!("avx2")
+			{
+				Self::Avx2
+			}
+			else
+			{
+				Self::Sse4_2
+			}
+		}
+		else
+		{
+			Self::NoSimd
+		};
+
+		DetectedInstructionSet.store(detected_instruction_set, Relaxed);
+		detected_instruction_set
+	}
+
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	#[target_feature(enable = "avx2,sse4.2")]
+	unsafe fn avx2(&self, slice: &[u8]) -> usize
+	{
+		const BlockSize: usize = 32;
+
+		let length = slice.len();
+		let pointer = slice.as_ptr();
+
+		let mut offset = 0;
+		while offset + BlockSize <= length
+		{
+			let block = _mm256_loadu_si256(pointer.add(offset) as *const __m256i);
+
+			let mut is_stop_byte = _mm256_setzero_si256();
+			let mut range_index = 0;
+			while range_index < self.ranges_length as usize
+			{
+				let low = _mm256_set1_epi8(*self.ranges.get_unchecked(range_index) as i8);
+				let high = _mm256_set1_epi8(*self.ranges.get_unchecked(range_index + 1) as i8);
+
+				// AVX2 only has signed byte comparisons; clamping with unsigned minimum and maximum and then checking for equality is an unsigned `low <= byte <= high`.
+				let is_in_range = _mm256_cmpeq_epi8(_mm256_min_epu8(_mm256_max_epu8(block, low), high), block);
+				is_stop_byte = _mm256_or_si256(is_stop_byte, is_in_range);
+
+				range_index += 2;
+			}
+
+			let mask = _mm256_movemask_epi8(is_stop_byte) as u32;
+			if mask != 0
+			{
+				return offset + (mask.trailing_zeros() as usize)
+			}
+
+			offset += BlockSize;
+		}
+
+		offset + self.sse4_2(slice.get_unchecked(offset .. ))
+	}
+
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	#[target_feature(enable = "sse4.2")]
+	unsafe fn sse4_2(&self, slice: &[u8]) -> usize
+	{
+		const BlockSize: usize = 16;
+
+		let length = slice.len();
+		let pointer = slice.as_ptr();
+
+		let ranges = _mm_loadu_si128(self.ranges.as_ptr() as *const __m128i);
+
+		let mut offset = 0;
+		while offset + BlockSize <= length
+		{
+			let block = _mm_loadu_si128(pointer.add(offset) as *const __m128i);
+
+			let index = _mm_cmpestri(ranges, self.ranges_length, block, BlockSize as i32, _SIDD_UBYTE_OPS | _SIDD_CMP_RANGES | _SIDD_LEAST_SIGNIFICANT);
+			if index != BlockSize as i32
+			{
+				return offset + (index as usize)
+			}
+
+			offset += BlockSize;
+		}
+
+		offset
+	}
+}
@@ -20,9 +20,8 @@ impl TargetUriReentryPoint
 	///
 	/// Support for RFC 7230 5.3.2 absolute-form target-uris is not implemented because we are not a proxy and we do not support clients connecting to us as if we were a proxy.
 	/// Additionally, despite being a MUST requirement, the cost of additional processing and validation (checking that the `Host` header, our server details and the authority in the absolute-uri all match) isn't worthwhile.
-	pub(crate) fn re_enter(self, bytes: &mut Bytes, request_user: &mut impl RequestUser) -> Result<(), Status<Self>>
+	pub(crate) fn re_enter(self, bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
 	{
-		use self::Status::*;
 		use self::TargetUriReentryPoint::*;
 
 		match self
@@ -31,31 +30,29 @@ impl TargetUriReentryPoint
 			{
 				bytes.reset(uri_starts_at_inclusive);
 
-				Self::parse_start(bytes, request_user)
+				Self::parse_start(bytes, request_user, request_parser_limits)
 			}
 
 			SegmentStartsFrom(uri_starts_at_inclusive, start_at) =>
 			{
 				bytes.reset(start_at);
 
-				Self::parse_segments(uri_starts_at_inclusive, bytes, request_user)
+				Self::parse_segments(uri_starts_at_inclusive, bytes, request_user, request_parser_limits)
 			}
 
 			QueryStartsFrom(uri_starts_at_inclusive, start_at) =>
 			{
 				bytes.reset(start_at);
 
-				Self::parse_query(uri_starts_at_inclusive, bytes, request_user)
+				Self::parse_query(uri_starts_at_inclusive, bytes, request_user, request_parser_limits)
 			}
 		}
 	}
 
 	#[inline(always)]
-	fn check_amount_parsed_is_not_too_great(uri_starts_at_inclusive: VectoredBufferOffset, bytes: &Bytes) -> Result<(), Status<Self>>
+	fn check_amount_parsed_is_not_too_great(request_parser_limits: &RequestParserLimits, uri_starts_at_inclusive: VectoredBufferOffset, bytes: &Bytes) -> Result<(), Status<Self>>
 	{
-		const MaximumAmountToParse: usize = 8 * 1024;
-
-		if bytes.current_pointer.difference(uri_starts_at_inclusive) > MaximumAmountToParse
+		if bytes.number_of_bytes_parsed_since(uri_starts_at_inclusive) > request_parser_limits.maximum_target_uri_length
 		{
 			Err(Invalid(UriTooLong))
 		}
@@ -66,47 +63,49 @@ impl TargetUriReentryPoint
 	}
 
 	#[inline(always)]
-	fn parse_start(bytes: &mut Bytes, request_user: &mut impl RequestUser) -> Result<(), Status<Self>>
+	fn parse_start(bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
 	{
-		let uri_starts_at_inclusive = bytes.current_pointer;
-		let mut reentry_point = Begin(uri_starts_at_inclusive);
+		let uri_starts_at_inclusive = bytes.current_position();
+		let reentry_point = TargetUriReentryPoint::Begin(uri_starts_at_inclusive);
 
 		match bytes.if_has_more_return_current_value_and_increment(reentry_point)?
 		{
-			b'/' => Self::parse_segments(uri_starts_at_inclusive, bytes, request_user),
+			b'/' => Self::parse_segments(uri_starts_at_inclusive, bytes, request_user, request_parser_limits),
 
 			_ => Err(Invalid(BadRequest("Only absolute request target-uris are supported"))),
 		}
 	}
 
 	#[inline(always)]
-	fn parse_segments(uri_starts_at_inclusive: VectoredBufferOffset, bytes: &mut Bytes, request_user: &mut impl RequestUser) -> Result<(), Status<Self>>
+	fn parse_segments(uri_starts_at_inclusive: VectoredBufferOffset, bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
 	{
-		use self::Status::Invalid;
-
-		let mut reentry_point = TargetUriReentryPoint::SegmentStartsFrom(uri_starts_at_inclusive, bytes.current_pointer);
+		let mut segment_starts_at_inclusive = bytes.current_position();
+		let mut reentry_point = TargetUriReentryPoint::SegmentStartsFrom(uri_starts_at_inclusive, segment_starts_at_inclusive);
 		loop
 		{
+			bytes.skip(|slice| StopBytes::TargetUriSegment.number_of_bytes_before_stop_byte(slice));
+
 			match bytes.if_has_more_return_current_value_and_increment(reentry_point)?
 			{
 				b' ' =>
 				{
-					request_user.use_segment(reentry_point.0, bytes.current_position()).map_err(|invalid_reason| Invalid(invalid_reason))?;
-					return request_user.finished().map_err(|invalid_reason| Invalid(invalid_reason))
+					request_user.target_uri_segment(segment_starts_at_inclusive, bytes.previous_position()).map_err(|invalid_reason| Invalid(invalid_reason))?;
+					return request_user.target_uri_finished().map_err(|invalid_reason| Invalid(invalid_reason))
 				}
 
 				b'#' => return Err(Invalid(BadRequest("Fragment in request target-uri"))),
 
 				b'/' =>
 				{
-					request_user.use_segment(reentry_point.0, bytes.current_position()).map_err(|invalid_reason| Invalid(invalid_reason))?;
-					reentry_point = SegmentStartsFrom(uri_starts_at_inclusive, bytes.current_pointer);
+					request_user.target_uri_segment(segment_starts_at_inclusive, bytes.previous_position()).map_err(|invalid_reason| Invalid(invalid_reason))?;
+					segment_starts_at_inclusive = bytes.current_position();
+					reentry_point = TargetUriReentryPoint::SegmentStartsFrom(uri_starts_at_inclusive, segment_starts_at_inclusive);
 				}
 
 				b'?' =>
 				{
-					request_user.use_segment(reentry_point.0, bytes.current_position()).map_err(|invalid_reason| Invalid(invalid_reason))?;
-					return Self::parse_query(uri_starts_at_inclusive, bytes, request_user)
+					request_user.target_uri_segment(segment_starts_at_inclusive, bytes.previous_position()).map_err(|invalid_reason| Invalid(invalid_reason))?;
+					return Self::parse_query(uri_starts_at_inclusive, bytes, request_user, request_parser_limits)
 				}
 
 				// RFC 3986, Section 3.3, Path:-
@@ -138,27 +137,30 @@ impl TargetUriReentryPoint
 				// DIGIT          =  %x30-39
 				// ```
 				// Note that `HEXDIG` can be upper or lower cased and in subsumed by the `ALPHA` and `DIGIT` rules.
-				b'A' ... b'Z' | b'a' ... b'z' | b'0' ... b'9' | '-' | b'.' | b'_' | b'~' | b'%' | b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' | b':' | b'@' => (),
+				b'A' ... b'Z' | b'a' ... b'z' | b'0' ... b'9' | b'-' | b'.' | b'_' | b'~' | b'%' | b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' | b':' | b'@' => (),
 
-				_ => return Err(Invalid(BadRequest("Invalid byte in request target-uri query token")))
+				_ => return Err(Invalid(BadRequest("Invalid byte in request target-uri segment")))
 			}
 
-			Self::check_amount_parsed_is_not_too_great(uri_starts_at_inclusive)?
+			Self::check_amount_parsed_is_not_too_great(request_parser_limits, uri_starts_at_inclusive, bytes)?
 		}
 	}
 
 	#[inline(always)]
-	fn parse_query(uri_starts_at_inclusive: VectoredBufferOffset, bytes: &mut Bytes, request_user: &mut impl RequestUser) -> Result<(), Status<Self>>
+	fn parse_query(uri_starts_at_inclusive: VectoredBufferOffset, bytes: &mut Bytes, request_user: &mut impl RequestUser, request_parser_limits: &RequestParserLimits) -> Result<(), Status<Self>>
 	{
-		let reentry_point = TargetUriReentryPoint::QueryStartsFrom(uri_starts_at_inclusive, bytes.current_pointer);
+		let query_starts_at_inclusive = bytes.current_position();
+		let reentry_point = TargetUriReentryPoint::QueryStartsFrom(uri_starts_at_inclusive, query_starts_at_inclusive);
 		loop
 		{
+			bytes.skip(|slice| StopBytes::TargetUriQuery.number_of_bytes_before_stop_byte(slice));
+
 			match bytes.if_has_more_return_current_value_and_increment(reentry_point)?
 			{
 				b' ' =>
 				{
-					request_user.use_query(reentry_point.0, bytes.current_position()).map_err(|invalid_reason| Invalid(invalid_reason))?;
-					return request_user.finished().map_err(|invalid_reason| Invalid(invalid_reason))
+					request_user.target_uri_query(query_starts_at_inclusive, bytes.previous_position()).map_err(|invalid_reason| Invalid(invalid_reason))?;
+					return request_user.target_uri_finished().map_err(|invalid_reason| Invalid(invalid_reason))
 				},
 
 				b'#' => return Err(Invalid(BadRequest("Fragment in request target-uri"))),
@@ -167,10 +169,12 @@ impl TargetUriReentryPoint
 				// ```
 				// query       = *( pchar / "/" / "?" )
 				// ```
-				b'A' ... b'Z' | b'a' ... b'z' | b'0' ... b'9' | '-' | b'.' | b'_' | b'~' | b'%' | b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' | b':' | b'@' | b'/' | b'?' => (),
+				b'A' ... b'Z' | b'a' ... b'z' | b'0' ... b'9' | b'-' | b'.' | b'_' | b'~' | b'%' | b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' | b':' | b'@' | b'/' | b'?' => (),
+
+				_ => return Err(Invalid(BadRequest("Invalid byte in request target-uri query"))),
 			}
 
-			Self::check_amount_parsed_is_not_too_great(uri_starts_at_inclusive)?
+			Self::check_amount_parsed_is_not_too_great(request_parser_limits, uri_starts_at_inclusive, bytes)?
 		}
 	}
 }
@@ -3,11 +3,23 @@
 
 
 use super::*;
-use self::Status::*;
 use self::InvalidReason::*;
+use ::simple_http_server_vectored_buffers::Status::*;
 
 
+type Status<ReentryPoint> = ::simple_http_server_vectored_buffers::Status<ReentryPoint, InvalidReason>;
+
+
+#[cfg(test)] mod tests;
+
+
+include!("BodyReentryPoint.rs");
+include!("ChunkedReentryPoint.rs");
 include!("HeaderReentryPoint.rs");
+include!("HeaderSection.rs");
 include!("InvalidReason.rs");
+include!("RequestBodyLength.rs");
+include!("RequestParserLimits.rs");
 include!("RequestUser.rs");
+include!("StopBytes.rs");
 include!("TargetUriReentryPoint.rs");
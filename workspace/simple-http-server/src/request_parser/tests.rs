@@ -0,0 +1,333 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+use super::*;
+
+
+#[derive(Default)]
+struct RecordingRequestUser
+{
+	header_fields: Vec<(KnownHeader, VectoredBufferOffset, VectoredBufferOffset)>,
+	request_body_length: Option<RequestBodyLength>,
+	body: Vec<u8>,
+	trailer_fields: Vec<(VectoredBufferOffset, VectoredBufferOffset, VectoredBufferOffset, VectoredBufferOffset)>,
+	body_finished: bool,
+}
+
+impl RequestUser for RecordingRequestUser
+{
+	fn method(&mut self, _request_method: RequestMethod) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn target_uri_segment(&mut self, _segment_starts_at_inclusive: VectoredBufferOffset, _segment_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn target_uri_query(&mut self, _query_starts_at_inclusive: VectoredBufferOffset, _query_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn target_uri_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn status_line_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		Ok(())
+	}
+
+	fn header_field(&mut self, known_header: KnownHeader, _name_starts_at_inclusive: VectoredBufferOffset, _name_ends_at_exclusive: VectoredBufferOffset, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		self.header_fields.push((known_header, value_starts_at_inclusive, value_ends_at_exclusive));
+		Ok(())
+	}
+
+	fn headers_finished(&mut self, _vectored_buffer: &VectoredBuffer, request_body_length: RequestBodyLength, _expects_continue: bool) -> Result<(), InvalidReason>
+	{
+		self.request_body_length = Some(request_body_length);
+		Ok(())
+	}
+
+	fn body_chunk(&mut self, vectored_buffer: &VectoredBuffer, chunk_starts_at_inclusive: VectoredBufferOffset, chunk_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		self.body.extend(ImmutableVectoredBufferSlice::new(vectored_buffer, chunk_starts_at_inclusive, chunk_ends_at_exclusive).bytes());
+		Ok(())
+	}
+
+	fn trailer_field(&mut self, name_starts_at_inclusive: VectoredBufferOffset, name_ends_at_exclusive: VectoredBufferOffset, value_starts_at_inclusive: VectoredBufferOffset, value_ends_at_exclusive: VectoredBufferOffset) -> Result<(), InvalidReason>
+	{
+		self.trailer_fields.push((name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, value_ends_at_exclusive));
+		Ok(())
+	}
+
+	fn body_finished(&mut self) -> Result<(), InvalidReason>
+	{
+		self.body_finished = true;
+		Ok(())
+	}
+}
+
+fn parse(input: &[u8]) -> (Request<RecordingRequestUser>, Result<bool, InvalidReason>)
+{
+	parse_with_limits(input, RequestParserLimits::default())
+}
+
+fn parse_with_limits(input: &[u8], request_parser_limits: RequestParserLimits) -> (Request<RecordingRequestUser>, Result<bool, InvalidReason>)
+{
+	let mut request = Request::new(received(input), RecordingRequestUser::default(), request_parser_limits);
+	let outcome = request.re_enter();
+	(request, outcome)
+}
+
+/// Parses `head` then reads `body` in at most as many bytes as the buffer has space for before each re-entry, as if `body` were arriving from a connection.
+fn stream(head: &[u8], body: &[u8]) -> (Request<RecordingRequestUser>, Result<bool, InvalidReason>)
+{
+	let mut request = Request::new(received(head), RecordingRequestUser::default(), RequestParserLimits::default());
+	let mut outcome = request.re_enter();
+
+	let mut unsent = body;
+	while outcome == Ok(false) && !unsent.is_empty()
+	{
+		let bytes_read = request.read_into(&mut unsent).expect("Reading from a slice can not fail");
+		assert_ne!(bytes_read, 0, "buffer is full");
+		outcome = request.re_enter();
+	}
+
+	(request, outcome)
+}
+
+fn bytes_of(request: &Request<RecordingRequestUser>, starts_at_inclusive: VectoredBufferOffset, ends_at_exclusive: VectoredBufferOffset) -> Vec<u8>
+{
+	ImmutableVectoredBufferSlice::new(request.vectored_buffer(), starts_at_inclusive, ends_at_exclusive).bytes().collect()
+}
+
+#[test]
+fn chunked_body_is_dechunked_and_trailer_fields_are_reported()
+{
+	let (mut request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n5;name=\"quoted \\\" value\"\r\nhello\r\n6\r\n world\r\n0\r\nExpires: never \r\nX-Empty:\r\n\r\n");
+	assert_eq!(outcome, Ok(true));
+
+	let trailer_fields =
+	{
+		let request_user = request.request_user();
+		assert_eq!(request_user.request_body_length, Some(RequestBodyLength::Chunked));
+		assert!(request_user.body_finished);
+		assert_eq!(&request_user.body[..], b"hello world");
+		request_user.trailer_fields.clone()
+	};
+
+	assert_eq!(trailer_fields.len(), 2);
+	let (name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, value_ends_at_exclusive) = trailer_fields[0];
+	assert_eq!(&bytes_of(&request, name_starts_at_inclusive, name_ends_at_exclusive)[..], b"Expires");
+	assert_eq!(&bytes_of(&request, value_starts_at_inclusive, value_ends_at_exclusive)[..], b"never");
+	let (name_starts_at_inclusive, name_ends_at_exclusive, value_starts_at_inclusive, value_ends_at_exclusive) = trailer_fields[1];
+	assert_eq!(&bytes_of(&request, name_starts_at_inclusive, name_ends_at_exclusive)[..], b"X-Empty");
+	assert_eq!(&bytes_of(&request, value_starts_at_inclusive, value_ends_at_exclusive)[..], b"");
+}
+
+#[test]
+fn chunked_body_larger_than_the_buffer_is_streamed()
+{
+	let body: Vec<u8> = (0 .. 65_536u32).map(|index| (index % 251) as u8).collect();
+
+	let mut chunked = Vec::new();
+	for chunk in body.chunks(1_000)
+	{
+		chunked.extend_from_slice(format!("{:x};name=value\r\n", chunk.len()).as_bytes());
+		chunked.extend_from_slice(chunk);
+		chunked.extend_from_slice(b"\r\n");
+	}
+	chunked.extend_from_slice(b"0\r\nExpires: never\r\n\r\n");
+
+	let (mut request, outcome) = stream(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n", &chunked[..]);
+	assert_eq!(outcome, Ok(true));
+
+	let (value_starts_at_inclusive, value_ends_at_exclusive) =
+	{
+		let request_user = request.request_user();
+		assert!(request_user.body_finished);
+		assert_eq!(request_user.body, body);
+		assert_eq!(request_user.trailer_fields.len(), 1);
+		let (_, _, value_starts_at_inclusive, value_ends_at_exclusive) = request_user.trailer_fields[0];
+		(value_starts_at_inclusive, value_ends_at_exclusive)
+	};
+	assert_eq!(&bytes_of(&request, value_starts_at_inclusive, value_ends_at_exclusive)[..], b"never");
+}
+
+#[test]
+fn incomplete_chunked_body_needs_more_bytes()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhel");
+	assert_eq!(outcome, Ok(false));
+}
+
+#[test]
+fn chunk_size_with_leading_zeros_is_not_an_overflow()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n000000000000000000000001\r\na\r\n0\r\n\r\n");
+	assert_eq!(outcome, Ok(true));
+}
+
+#[test]
+fn largest_chunk_size_does_not_overflow()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\nffffffffffffffff\r\n");
+	assert_eq!(outcome, Ok(false));
+}
+
+#[test]
+fn chunk_size_overflow_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n10000000000000000\r\n");
+	assert_eq!(outcome, Err(BadRequest("Chunk size overflows")));
+}
+
+#[test]
+fn missing_chunk_size_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n;name\r\n");
+	assert_eq!(outcome, Err(BadRequest("Chunk size missing")));
+}
+
+#[test]
+fn chunk_data_not_followed_by_line_ending_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n1\r\nab\r\n0\r\n\r\n");
+	assert!(outcome.is_err());
+}
+
+#[test]
+fn too_many_trailer_fields_are_rejected()
+{
+	let request_parser_limits = RequestParserLimits
+	{
+		maximum_number_of_header_fields: 2,
+		.. RequestParserLimits::default()
+	};
+
+	let (_request, outcome) = parse_with_limits(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n0\r\nA: 1\r\nB: 2\r\nC: 3\r\n\r\n", request_parser_limits);
+	assert_eq!(outcome, Err(RequestHeaderFieldsTooLarge));
+}
+
+#[test]
+fn trailer_field_longer_than_a_header_field_is_rejected()
+{
+	let request_parser_limits = RequestParserLimits
+	{
+		maximum_header_field_length: 30,
+		.. RequestParserLimits::default()
+	};
+
+	let (_request, outcome) = parse_with_limits(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n0\r\nX-Trailer: a value longer than thirty bytes\r\n\r\n", request_parser_limits);
+	assert_eq!(outcome, Err(RequestHeaderFieldsTooLarge));
+}
+
+#[test]
+fn obsolete_line_folding_is_rejected()
+{
+	let (_request, outcome) = parse(b"GET / HTTP/1.1\r\nHost: example.com\r\nX-Folded: first\r\n second\r\n\r\n");
+	assert_eq!(outcome, Err(ObsoleteLineFolding));
+}
+
+#[test]
+fn obsolete_line_folding_with_a_tab_is_rejected()
+{
+	let (_request, outcome) = parse(b"GET / HTTP/1.1\r\nHost: example.com\r\nX-Folded: first\r\n\tsecond\r\n\r\n");
+	assert_eq!(outcome, Err(ObsoleteLineFolding));
+}
+
+#[test]
+fn whitespace_before_header_field_colon_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nContent-Length : 5\r\n\r\nhello");
+	assert_eq!(outcome, Err(WhitespaceBeforeHeaderFieldColon));
+}
+
+#[test]
+fn content_length_then_transfer_encoding_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nContent-Length: 5\r\nTransfer-Encoding: chunked\r\n\r\n0\r\n\r\n");
+	assert_eq!(outcome, Err(ContentLengthAndTransferEncoding));
+}
+
+#[test]
+fn transfer_encoding_then_content_length_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\nContent-Length: 5\r\n\r\n0\r\n\r\n");
+	assert_eq!(outcome, Err(ContentLengthAndTransferEncoding));
+}
+
+#[test]
+fn multiple_content_length_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nContent-Length: 5\r\nContent-Length: 5\r\n\r\nhello");
+	assert_eq!(outcome, Err(MultipleContentLength));
+}
+
+#[test]
+fn content_length_list_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nContent-Length: 5, 5\r\n\r\nhello");
+	assert_eq!(outcome, Err(MultipleContentLength));
+}
+
+#[test]
+fn invalid_content_length_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nContent-Length: +5\r\n\r\nhello");
+	assert_eq!(outcome, Err(InvalidContentLength));
+}
+
+#[test]
+fn transfer_encoding_not_finally_chunked_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked, gzip\r\n\r\n");
+	assert_eq!(outcome, Err(TransferEncodingNotFinallyChunked));
+}
+
+#[test]
+fn repeated_transfer_encoding_is_rejected()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\nTransfer-Encoding: chunked\r\n\r\n0\r\n\r\n");
+	assert_eq!(outcome, Err(TransferEncodingNotFinallyChunked));
+}
+
+#[test]
+fn transfer_coding_before_chunked_is_not_implemented()
+{
+	let (_request, outcome) = parse(b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: gzip, chunked\r\n\r\n");
+	assert_eq!(outcome, Err(UnsupportedTransferCoding));
+}
+
+#[test]
+fn whitespace_before_header_field_value_is_rejected_once_longer_than_a_header_field()
+{
+	let request_parser_limits = RequestParserLimits
+	{
+		maximum_header_field_length: 30,
+		.. RequestParserLimits::default()
+	};
+
+	let (_request, outcome) = parse_with_limits(b"GET / HTTP/1.1\r\nHost: example.com\r\nX-Padded:                                        ", request_parser_limits);
+	assert_eq!(outcome, Err(RequestHeaderFieldsTooLarge));
+}
+
+#[test]
+fn content_length_body_larger_than_the_buffer_is_streamed()
+{
+	let body: Vec<u8> = (0 .. 65_536u32).map(|index| (index % 251) as u8).collect();
+	let head = format!("POST / HTTP/1.1\r\nHost: example.com\r\nContent-Length: {}\r\n\r\n", body.len());
+
+	let (mut request, outcome) = stream(head.as_bytes(), &body[..]);
+	assert_eq!(outcome, Ok(true));
+
+	let request_user = request.request_user();
+	assert!(request_user.body_finished);
+	assert_eq!(request_user.body, body);
+}
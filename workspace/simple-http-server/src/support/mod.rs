@@ -6,4 +6,6 @@ use super::*;
 
 
 include!("block_all_signals.rs");
+#[cfg(test)] include!("received.rs");
+#[cfg(test)] include!("with_immutable_vectored_buffer_slice.rs");
 include!("WriteVAdapter.rs");
@@ -0,0 +1,34 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Bytes received as if read from a connection, for unit tests.
+///
+/// `input` must fit into one buffer.
+pub(crate) fn received(input: &[u8]) -> Bytes
+{
+	struct NeverVetoes;
+
+	impl AllocationObserver for NeverVetoes
+	{
+		#[inline(always)]
+		fn observe_and_veto_forthcoming_allocation(&self, _allocation_observer_identifier: AllocationObserverIdentifier) -> bool
+		{
+			false
+		}
+
+		#[inline(always)]
+		fn observe_forthcoming_deallocation(&self, _allocation_observer_identifier: AllocationObserverIdentifier)
+		{
+		}
+	}
+
+	let buffer_arena = BufferArena::with_capacity(NeverVetoes, 1);
+	let (vectored_buffer, _) = VectoredBuffer::new(0, &buffer_arena).expect("Could not allocate a buffer");
+
+	let mut bytes = Bytes::new(vectored_buffer);
+	let bytes_read = bytes.read_into(&mut &input[..]).expect("Reading from a slice can not fail");
+	assert_eq!(bytes_read, input.len(), "input does not fit into one buffer");
+
+	bytes
+}
@@ -0,0 +1,11 @@
+// This file is part of simple-http-server. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT. No part of simple-http-server, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2018 The developers of simple-http-server. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/simple-http-server/master/COPYRIGHT.
+
+
+/// Passes `input`, as received, to `user` as an `ImmutableVectoredBufferSlice`, for unit tests of parsers of field values.
+pub(crate) fn with_immutable_vectored_buffer_slice<R>(input: &[u8], user: impl FnOnce(&ImmutableVectoredBufferSlice) -> R) -> R
+{
+	let bytes = received(input);
+	let slice = ImmutableVectoredBufferSlice::new(bytes.vectored_buffer(), VectoredBufferOffset::new(BufferIndex::Minimum, 0), VectoredBufferOffset::new(BufferIndex::Minimum, input.len()));
+	user(&slice)
+}
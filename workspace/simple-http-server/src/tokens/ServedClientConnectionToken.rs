@@ -80,7 +80,7 @@ impl<SCCUF: ServedClientConnectionUserFactory> ServedClientConnectionToken<SCCUF
 				}
 				else
 				{
-					Self::DoNotDrop
+					self.register_wake_up_sources(poll, our_token)
 				}
 			}
 		}
@@ -105,13 +105,27 @@ impl<SCCUF: ServedClientConnectionUserFactory> ServedClientConnectionToken<SCCUF
 				}
 				else
 				{
-					Self::DoNotDrop
+					self.register_wake_up_sources(poll, our_token)
 				}
 			}
 			else
 			{
-				Self::DoNotDrop
+				self.register_wake_up_sources(poll, our_token)
 			}
 		}
 	}
+
+	// Each wake up source must deregister itself from `poll` when dropped, eg when the response it belongs to has been written, as a `Registration` or an `EventFileDescriptorWakeUp` does.
+	fn register_wake_up_sources(&mut self, poll: &Poll, our_token: Token) -> bool
+	{
+		while let Some(wake_up_source) = self.served_client_connection_user.wake_up_source_to_register()
+		{
+			if poll.register(wake_up_source, our_token, Ready::readable(), PollOpt::edge()).is_err()
+			{
+				return Self::Drop
+			}
+		}
+
+		Self::DoNotDrop
+	}
 }
@@ -23,6 +23,8 @@ pub(crate) fn worker_thread_loop(terminate: Terminate, poll: &Poll, mut events:
 			}
 		}
 
+		DateHeaderResponseBuffer::refresh_cached();
+
 		for event in events.iter()
 		{
 			debug_assert!(event.token() == OurToken, "token of event is not ours");